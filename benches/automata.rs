@@ -0,0 +1,155 @@
+//! Mediciones de rendimiento de la simulación y las construcciones principales.
+//!
+//! Se ejecutan con `cargo bench`; Criterion guarda los resultados en `target/criterion`
+//! y los compara con la ejecución anterior.
+
+use std::hint::black_box;
+
+use automata::generator::{generate, GeneratorOptions};
+use automata::nfa::NFA;
+use automata::random::Rng;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+// Autómata de ejemplo del programa principal.
+const EXAMPLE: &str = "
+    alphabet={0,1}
+    state={q0, q1, q2}
+    start_state=q0
+    F={q2}
+    (q0, 0)->{q0}
+    (q0, 1)->{q1}
+    (q1, 0)->{q1, q2}
+    (q1, 1)->{q1}
+    (q2, 0)->{q2}
+    (q2, 1)->{q1, q2}
+";
+
+/**
+ Describe el NFA que acepta las palabras cuyo `n`-ésimo símbolo desde el final es `1`.
+    Tiene `n + 1` estados y su autómata determinista mínimo tiene `2^n`, el peor caso
+    de la construcción de subconjuntos.
+*/
+fn nth_from_end(n: usize) -> String {
+    let states: Vec<String> = (0..=n).map(|i| format!("q{}", i)).collect();
+    let mut description = format!(
+        "alphabet={{0, 1}}\nstate={{{}}}\nstart_state=q0\nF={{q{}}}\n",
+        states.join(", "),
+        n
+    );
+    description.push_str("(q0, 0)->{q0}\n(q0, 1)->{q0, q1}\n");
+    for i in 1..n {
+        description.push_str(&format!("(q{}, 0)->{{q{}}}\n", i, i + 1));
+        description.push_str(&format!("(q{}, 1)->{{q{}}}\n", i, i + 1));
+    }
+    description
+}
+
+// Palabra pseudoaleatoria de ceros y unos.
+fn word(length: usize) -> Vec<char> {
+    let mut rng = Rng::new(length as u64);
+    (0..length)
+        .map(|_| if rng.chance(0.5) { '1' } else { '0' })
+        .collect()
+}
+
+// Autómata aleatorio grande, reproducible.
+fn random_nfa(states: usize, deterministic: bool) -> NFA<char> {
+    let options = GeneratorOptions {
+        states,
+        alphabet: vec!['0', '1'],
+        density: 4.0 / states as f64,
+        deterministic,
+        complete: deterministic,
+        ..GeneratorOptions::default()
+    };
+    generate(&mut Rng::new(50), &options).unwrap()
+}
+
+fn run(c: &mut Criterion) {
+    let mut group = c.benchmark_group("run");
+    let nfa = NFA::try_from_string(&nth_from_end(10)).unwrap();
+    // Las tablas se preparan una vez; se mide solo la simulación
+    let example = NFA::try_from_string(EXAMPLE).unwrap().runner();
+    let nth = nfa.runner();
+    let dfa = nfa.determinize().runner();
+
+    for length in [1_000, 10_000, 100_000] {
+        let input = word(length);
+        group.throughput(Throughput::Elements(length as u64));
+        group.bench_with_input(BenchmarkId::new("ejemplo", length), &input, |b, input| {
+            b.iter(|| example.run(input.iter().copied()))
+        });
+        group.bench_with_input(BenchmarkId::new("nfa_n10", length), &input, |b, input| {
+            b.iter(|| nth.run(input.iter().copied()))
+        });
+        group.bench_with_input(BenchmarkId::new("dfa_n10", length), &input, |b, input| {
+            b.iter(|| dfa.run(input.iter().copied()))
+        });
+    }
+    group.finish();
+}
+
+fn determinize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("determinize");
+    group.sample_size(20);
+    for n in [4, 8, 10, 12] {
+        let nfa = NFA::try_from_string(&nth_from_end(n)).unwrap();
+        group.bench_with_input(BenchmarkId::new("nth_from_end", n), &nfa, |b, nfa| {
+            b.iter(|| nfa.determinize())
+        });
+    }
+    group.finish();
+}
+
+fn minimize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("minimize");
+    group.sample_size(20);
+    for n in [4, 6, 8] {
+        let dfa = NFA::try_from_string(&nth_from_end(n))
+            .unwrap()
+            .determinize();
+        group.bench_with_input(BenchmarkId::new("nth_from_end", n), &dfa, |b, dfa| {
+            b.iter(|| dfa.minimize())
+        });
+    }
+    for states in [16, 64, 128] {
+        let dfa = random_nfa(states, true);
+        group.bench_with_input(BenchmarkId::new("random_dfa", states), &dfa, |b, dfa| {
+            b.iter(|| dfa.minimize())
+        });
+    }
+    group.finish();
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for states in [100, 500, 1_000] {
+        let description = random_nfa(states, false).to_description();
+        group.throughput(Throughput::Bytes(description.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("try_from_string", states),
+            &description,
+            |b, description| b.iter(|| NFA::try_from_string(black_box(description)).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+fn export(c: &mut Criterion) {
+    let mut group = c.benchmark_group("export");
+    for n in [6, 10] {
+        let dfa = NFA::try_from_string(&nth_from_end(n))
+            .unwrap()
+            .determinize();
+        group.bench_with_input(BenchmarkId::new("dot", n), &dfa, |b, dfa| {
+            b.iter(|| dfa.to_dot())
+        });
+        group.bench_with_input(BenchmarkId::new("mermaid", n), &dfa, |b, dfa| {
+            b.iter(|| dfa.to_mermaid())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, run, determinize, minimize, parse, export);
+criterion_main!(benches);
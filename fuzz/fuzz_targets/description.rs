@@ -0,0 +1,18 @@
+#![no_main]
+
+use automata::nfa::NFA;
+use libfuzzer_sys::fuzz_target;
+
+// Ninguna descripción debe detener el programa: solo puede producir errores.
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(nfa) = NFA::try_from_string(text) {
+        // Un autómata válido se puede ejecutar y volver a escribir
+        let word: Vec<char> = nfa.alphabet().iter().copied().cycle().take(8).collect();
+        let _ = nfa.run(word);
+        let _ = nfa.to_description();
+    }
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use automata::regex::Regex;
+use libfuzzer_sys::fuzz_target;
+
+// Ninguna expresión debe detener el programa: solo puede producir errores.
+fuzz_target!(|data: &[u8]| {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(regex) = Regex::parse(text) {
+        // Una expresión válida se puede traducir, ejecutar y volver a escribir
+        let nfa = regex.to_nfa();
+        let word: Vec<char> = text.chars().take(8).collect();
+        assert_eq!(
+            nfa.run(word.iter().copied()),
+            regex.matches(&word.iter().collect::<String>())
+        );
+        assert!(Regex::parse(&regex.to_string()).is_ok());
+    }
+});
@@ -0,0 +1,525 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use crate::description::{
+    classify, missing_start, parse_set, parse_symbol, split_state_symbol, Line, StateNames,
+};
+use crate::error::ParseError;
+use crate::nfa::NFA;
+
+/**
+ Máxima profundidad del árbol de una fórmula.
+    `Formula` se recorre de forma recursiva, así que el analizador rechaza las fórmulas más
+    profundas en vez de agotar la pila. Las cadenas de `&` y `|` también cuentan:
+    `q0 & q1 & q2` tiene profundidad 3. Los paréntesis anidados tienen el mismo límite.
+*/
+pub const MAX_DEPTH: usize = 512;
+
+// Subfórmula ya analizada junto con su profundidad.
+type Parsed = (Formula, usize);
+
+/**
+ Fórmula booleana positiva sobre estados, usada como destino de una transición alternante.
+    Los estados se identifican por su posición en el autómata.
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Formula {
+    True,
+    False,
+    State(usize),
+    And(Box<Formula>, Box<Formula>),
+    Or(Box<Formula>, Box<Formula>),
+}
+
+impl Formula {
+    // Conjunción que simplifica las constantes.
+    pub fn and(left: Formula, right: Formula) -> Formula {
+        match (left, right) {
+            (Formula::False, _) | (_, Formula::False) => Formula::False,
+            (Formula::True, other) | (other, Formula::True) => other,
+            (left, right) => Formula::And(Box::new(left), Box::new(right)),
+        }
+    }
+
+    // Disyunción que simplifica las constantes.
+    pub fn or(left: Formula, right: Formula) -> Formula {
+        match (left, right) {
+            (Formula::True, _) | (_, Formula::True) => Formula::True,
+            (Formula::False, other) | (other, Formula::False) => other,
+            (left, right) => Formula::Or(Box::new(left), Box::new(right)),
+        }
+    }
+
+    // Evalúa la fórmula dando valor verdadero a los estados que cumplen `holds`.
+    pub fn eval<F: Fn(usize) -> bool>(&self, holds: &F) -> bool {
+        match self {
+            Formula::True => true,
+            Formula::False => false,
+            Formula::State(state) => holds(*state),
+            Formula::And(left, right) => left.eval(holds) && right.eval(holds),
+            Formula::Or(left, right) => left.eval(holds) || right.eval(holds),
+        }
+    }
+
+    // Fórmula dual: intercambia `&` con `|` y `true` con `false`.
+    pub fn dual(&self) -> Formula {
+        match self {
+            Formula::True => Formula::False,
+            Formula::False => Formula::True,
+            Formula::State(state) => Formula::State(*state),
+            Formula::And(left, right) => Formula::Or(Box::new(left.dual()), Box::new(right.dual())),
+            Formula::Or(left, right) => Formula::And(Box::new(left.dual()), Box::new(right.dual())),
+        }
+    }
+
+    /**
+     Calcula los modelos minimales de la fórmula.
+        # Returns
+        Retorna los conjuntos de estados minimales (por inclusión) que hacen verdadera la fórmula.
+        `false` no tiene modelos y `true` tiene como único modelo el conjunto vacío.
+    */
+    pub fn minimal_models(&self) -> Vec<BTreeSet<usize>> {
+        let models = match self {
+            Formula::True => vec![BTreeSet::new()],
+            Formula::False => Vec::new(),
+            Formula::State(state) => vec![BTreeSet::from([*state])],
+            Formula::Or(left, right) => {
+                let mut models = left.minimal_models();
+                models.extend(right.minimal_models());
+                models
+            }
+            Formula::And(left, right) => {
+                let right = right.minimal_models();
+                let mut models = Vec::new();
+                for l in left.minimal_models() {
+                    for r in &right {
+                        models.push(l.union(r).copied().collect());
+                    }
+                }
+                models
+            }
+        };
+
+        // Descarta los modelos que contienen estrictamente a otro
+        let mut minimal: Vec<BTreeSet<usize>> = Vec::new();
+        for model in models {
+            if minimal.iter().any(|other| other.is_subset(&model)) {
+                continue;
+            }
+            minimal.retain(|other| !model.is_subset(other));
+            minimal.push(model);
+        }
+        minimal
+    }
+
+    // Escribe la fórmula usando los nombres de los estados.
+    fn render(&self, names: &[String]) -> String {
+        match self {
+            Formula::True => "true".to_string(),
+            Formula::False => "false".to_string(),
+            Formula::State(state) => names[*state].clone(),
+            Formula::And(left, right) => {
+                let side = |f: &Formula| match f {
+                    Formula::Or(..) => format!("({})", f.render(names)),
+                    _ => f.render(names),
+                };
+                format!("{} & {}", side(left), side(right))
+            }
+            Formula::Or(left, right) => {
+                format!("{} | {}", left.render(names), right.render(names))
+            }
+        }
+    }
+}
+
+/**
+ Autómata finito alternante: cada transición lleva a una fórmula booleana positiva
+ sobre los estados, por ejemplo `(q0, a)->q1 & (q2 | q3)`.
+    El formato de descripción es el de `NFA::try_from_string`; el destino puede ser una
+    fórmula con `&`, `|`, paréntesis, `true` y `false`, o un conjunto `{q1, q2}` que se
+    interpreta como disyunción. Varias líneas con la misma parte izquierda se combinan con `|`.
+    Una transición no definida equivale a `false`.
+*/
+pub struct AFA {
+    states: StateNames,
+    accept: Vec<bool>,
+    alphabet: BTreeSet<char>,
+    start_state: usize,
+    transitions: BTreeMap<(usize, char), Formula>,
+}
+
+impl AFA {
+    /**
+     Crea un autómata alternante desde el formato de descripción.
+        # Arguments
+        * `description` - Texto con la descripción del autómata.
+        # Returns
+        Retorna el autómata o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut automaton = AFA {
+            states: StateNames::default(),
+            accept: Vec::new(),
+            alphabet: BTreeSet::new(),
+            start_state: 0,
+            transitions: BTreeMap::new(),
+        };
+        let mut start_state = None;
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+
+            match classify(line.trim()).map_err(error)? {
+                Line::Empty => {}
+                Line::Alphabet(list) => {
+                    for symbol in parse_set(list).map_err(error)? {
+                        automaton
+                            .alphabet
+                            .insert(parse_symbol(symbol).map_err(error)?);
+                    }
+                }
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        if automaton.states.declare(name).is_some() {
+                            automaton.accept.push(false);
+                        }
+                    }
+                }
+                Line::Start(name) => {
+                    start_state = Some(automaton.states.position(name).map_err(with_line)?);
+                }
+                Line::Final(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        let index = automaton.states.position(name).map_err(with_line)?;
+                        automaton.accept[index] = true;
+                    }
+                }
+                Line::Transition(left, right) => {
+                    let (name, symbol) = split_state_symbol(left).map_err(error)?;
+                    if symbol == 'ε' {
+                        return Err(error(
+                            "Los autómatas alternantes no admiten transiciones ε.".to_string(),
+                        ));
+                    }
+                    let from = automaton.states.position(name).map_err(with_line)?;
+                    let formula = automaton.parse_formula(right).map_err(error)?;
+                    automaton.add_transition(from, symbol, formula);
+                }
+            }
+        }
+
+        if automaton.states.is_empty() {
+            return Err(ParseError::new(0, "El autómata no tiene estados."));
+        }
+        automaton.start_state = start_state.ok_or_else(missing_start)?;
+        Ok(automaton)
+    }
+
+    // Agrega una transición; si ya existía una para `(from, symbol)` se combinan con `|`.
+    fn add_transition(&mut self, from: usize, symbol: char, formula: Formula) {
+        self.alphabet.insert(symbol);
+        let current = self
+            .transitions
+            .remove(&(from, symbol))
+            .unwrap_or(Formula::False);
+        self.transitions
+            .insert((from, symbol), Formula::or(current, formula));
+    }
+
+    // Retorna los nombres de los estados
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    // Retorna el alfabeto
+    pub fn alphabet(&self) -> &BTreeSet<char> {
+        &self.alphabet
+    }
+
+    // Retorna el nombre del estado inicial
+    pub fn start_state(&self) -> &str {
+        &self.states[self.start_state]
+    }
+
+    // Retorna la fórmula de `δ(q, a)`, o `None` si equivale a `false`.
+    pub fn transition(&self, state: &str, symbol: char) -> Option<&Formula> {
+        let from = self.states.position(state).ok()?;
+        self.transitions.get(&(from, symbol))
+    }
+
+    /**
+     Valida si una palabra es aceptada evaluando las fórmulas de atrás hacia adelante.
+        # Arguments
+        * `input` - La palabra de entrada.
+        # Returns
+        Retorna `true` si el estado inicial acepta la palabra.
+    */
+    pub fn run<I: IntoIterator<Item = char>>(&self, input: I) -> bool {
+        let word: Vec<char> = input.into_iter().collect();
+
+        // `accepting[q]` indica si desde q se acepta el sufijo ya procesado
+        let mut accepting = self.accept.clone();
+        for symbol in word.iter().rev() {
+            accepting = (0..self.states.len())
+                .map(|state| {
+                    self.transitions
+                        .get(&(state, *symbol))
+                        .is_some_and(|formula| formula.eval(&|q| accepting[q]))
+                })
+                .collect();
+        }
+
+        accepting[self.start_state]
+    }
+
+    /**
+     Construye un `NFA` equivalente cuyos estados son conjuntos de estados del autómata alternante.
+        Un conjunto acepta si todos sus estados aceptan, y con el símbolo `a` pasa a la unión
+        de un modelo minimal de `δ(q, a)` por cada estado `q` del conjunto.
+        # Returns
+        Retorna el `NFA` con los conjuntos alcanzables desde `{inicial}`, nombrados como `{q0, q1}`.
+    */
+    pub fn to_nfa(&self) -> NFA<char> {
+        let start = BTreeSet::from([self.start_state]);
+        let mut subsets = vec![start.clone()];
+        let mut index: HashMap<BTreeSet<usize>, usize> = HashMap::from([(start, 0)]);
+        let mut edges = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            for &symbol in &self.alphabet {
+                // Combina los modelos de cada estado del conjunto
+                let mut successors = vec![BTreeSet::new()];
+                for &state in &subsets[current] {
+                    let models = self
+                        .transitions
+                        .get(&(state, symbol))
+                        .map(Formula::minimal_models)
+                        .unwrap_or_default();
+                    successors = successors
+                        .iter()
+                        .flat_map(|partial| {
+                            models
+                                .iter()
+                                .map(move |model| partial.union(model).copied().collect())
+                        })
+                        .collect();
+                }
+
+                for subset in successors {
+                    let next = *index.entry(subset.clone()).or_insert_with(|| {
+                        subsets.push(subset);
+                        queue.push_back(subsets.len() - 1);
+                        subsets.len() - 1
+                    });
+                    edges.push((current, Some(symbol), next));
+                }
+            }
+        }
+
+        let states = subsets
+            .iter()
+            .map(|subset| {
+                let names: Vec<&str> = subset.iter().map(|&q| self.states[q].as_str()).collect();
+                (
+                    format!("{{{}}}", names.join(", ")),
+                    subset.iter().all(|&q| self.accept[q]),
+                )
+            })
+            .collect();
+
+        let mut nfa = NFA::from_table(states, 0, edges);
+        nfa.alphabet = self.alphabet.clone();
+        nfa
+    }
+
+    /**
+     Construye el complemento dualizando las fórmulas y los estados de aceptación.
+        Las transiciones no definidas (`false`) pasan a ser `true`.
+        # Returns
+        Retorna un autómata alternante con el mismo número de estados.
+    */
+    pub fn complement(&self) -> AFA {
+        let mut transitions = BTreeMap::new();
+        for state in 0..self.states.len() {
+            for &symbol in &self.alphabet {
+                let formula = self
+                    .transitions
+                    .get(&(state, symbol))
+                    .unwrap_or(&Formula::False);
+                transitions.insert((state, symbol), formula.dual());
+            }
+        }
+
+        AFA {
+            states: self.states.clone(),
+            accept: self.accept.iter().map(|accept| !accept).collect(),
+            alphabet: self.alphabet.clone(),
+            start_state: self.start_state,
+            transitions,
+        }
+    }
+
+    // Imprime las transiciones del autómata
+    pub fn print_transitions(&self) {
+        for ((from, symbol), formula) in &self.transitions {
+            println!(
+                "δ({}, {}) = {}",
+                self.states[*from],
+                symbol,
+                formula.render(&self.states)
+            );
+        }
+    }
+
+    // Imprime los estados de aceptación
+    pub fn print_accept_states(&self) {
+        let accept: Vec<&str> = self
+            .states
+            .iter()
+            .zip(&self.accept)
+            .filter(|(_, accept)| **accept)
+            .map(|(state, _)| state.as_str())
+            .collect();
+        println!("F = {{{}}}", accept.join(", "));
+    }
+
+    /**
+     Interpreta el destino de una transición.
+        Gramática: `disyunción := conjunción ('|' conjunción)*`,
+        `conjunción := factor ('&' factor)*` y
+        `factor := estado | true | false | '(' disyunción ')'`.
+        # Returns
+        Retorna la fórmula o un mensaje de error.
+    */
+    fn parse_formula(&self, text: &str) -> Result<Formula, String> {
+        // Conjunto de estados al estilo de `NFA::try_from_string`
+        if text.starts_with('{') {
+            let mut formula = Formula::False;
+            for name in parse_set(text)? {
+                let state = self.states.position(name).map_err(|e| e.message)?;
+                formula = Formula::or(formula, Formula::State(state));
+            }
+            return Ok(formula);
+        }
+
+        let tokens = tokenize(text)?;
+        let mut position = 0;
+        let (formula, _) = self.parse_or(&tokens, &mut position, 0)?;
+        match tokens.get(position) {
+            None => Ok(formula),
+            Some(token) => Err(format!("Símbolo inesperado \"{}\" en la fórmula.", token)),
+        }
+    }
+
+    // `nesting` es la cantidad de paréntesis abiertos alrededor de la subfórmula.
+    fn parse_or(
+        &self,
+        tokens: &[String],
+        position: &mut usize,
+        nesting: usize,
+    ) -> Result<Parsed, String> {
+        let (mut formula, mut depth) = self.parse_and(tokens, position, nesting)?;
+        while tokens.get(*position).is_some_and(|t| t == "|") {
+            *position += 1;
+            let (right, right_depth) = self.parse_and(tokens, position, nesting)?;
+            (formula, depth) = node(Formula::or(formula, right), 1 + depth.max(right_depth))?;
+        }
+        Ok((formula, depth))
+    }
+
+    fn parse_and(
+        &self,
+        tokens: &[String],
+        position: &mut usize,
+        nesting: usize,
+    ) -> Result<Parsed, String> {
+        let (mut formula, mut depth) = self.parse_factor(tokens, position, nesting)?;
+        while tokens.get(*position).is_some_and(|t| t == "&") {
+            *position += 1;
+            let (right, right_depth) = self.parse_factor(tokens, position, nesting)?;
+            (formula, depth) = node(Formula::and(formula, right), 1 + depth.max(right_depth))?;
+        }
+        Ok((formula, depth))
+    }
+
+    fn parse_factor(
+        &self,
+        tokens: &[String],
+        position: &mut usize,
+        nesting: usize,
+    ) -> Result<Parsed, String> {
+        let token = tokens
+            .get(*position)
+            .ok_or_else(|| "La fórmula termina de forma inesperada.".to_string())?;
+        *position += 1;
+
+        match token.as_str() {
+            "(" => {
+                if nesting == MAX_DEPTH {
+                    return Err(format!(
+                        "La fórmula tiene más de {} paréntesis anidados.",
+                        MAX_DEPTH
+                    ));
+                }
+                let parsed = self.parse_or(tokens, position, nesting + 1)?;
+                if tokens.get(*position).is_some_and(|t| t == ")") {
+                    *position += 1;
+                    Ok(parsed)
+                } else {
+                    Err("Falta cerrar un paréntesis en la fórmula.".to_string())
+                }
+            }
+            "true" => Ok((Formula::True, 1)),
+            "false" => Ok((Formula::False, 1)),
+            "&" | "|" | ")" => Err(format!("Símbolo inesperado \"{}\" en la fórmula.", token)),
+            name => self
+                .states
+                .position(name)
+                .map(|state| (Formula::State(state), 1))
+                .map_err(|e| e.message),
+        }
+    }
+}
+
+// Verifica que la fórmula combinada no supere `MAX_DEPTH`.
+fn node(formula: Formula, depth: usize) -> Result<Parsed, String> {
+    if depth > MAX_DEPTH {
+        return Err(format!(
+            "La fórmula tiene más de {} niveles de anidación.",
+            MAX_DEPTH
+        ));
+    }
+    Ok((formula, depth))
+}
+
+// Separa una fórmula en operadores, paréntesis y nombres de estados.
+fn tokenize(text: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut name = String::new();
+
+    for c in text.chars() {
+        if c == '&' || c == '|' || c == '(' || c == ')' || c.is_whitespace() {
+            if !name.is_empty() {
+                tokens.push(std::mem::take(&mut name));
+            }
+            if !c.is_whitespace() {
+                tokens.push(c.to_string());
+            }
+        } else {
+            name.push(c);
+        }
+    }
+    if !name.is_empty() {
+        tokens.push(name);
+    }
+
+    if tokens.is_empty() {
+        return Err("La transición no tiene destino.".to_string());
+    }
+    Ok(tokens)
+}
@@ -0,0 +1,184 @@
+//! Pruebas de palabras en lote desde un archivo de casos.
+//!
+//! Cada línea del archivo es un caso `accept: palabra` o `reject: palabra`. Una palabra
+//! vacía (o `ε`) representa la palabra vacía. Las líneas vacías y las que empiezan con
+//! `#` se ignoran.
+
+use std::fmt::Write;
+
+use crate::error::ParseError;
+use crate::nfa::NFA;
+use crate::xml::escape;
+
+// Caso de prueba: una palabra y si debe ser aceptada.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestCase {
+    pub line: usize,
+    pub word: String,
+    pub expected: bool,
+}
+
+// Resultado de ejecutar un caso.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaseResult {
+    pub case: TestCase,
+    pub accepted: bool,
+}
+
+impl CaseResult {
+    pub fn passed(&self) -> bool {
+        self.case.expected == self.accepted
+    }
+}
+
+/**
+ Interpreta un archivo de casos de prueba.
+    # Arguments
+    * `text` - Contenido del archivo.
+    # Returns
+    Retorna los casos en orden o el primer error con su número de línea.
+*/
+pub fn parse_cases(text: &str) -> Result<Vec<TestCase>, ParseError> {
+    let mut cases = Vec::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let number = number + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (kind, word) = line.split_once(':').ok_or_else(|| {
+            ParseError::new(
+                number,
+                format!(
+                    "Se esperaba \"accept: palabra\" o \"reject: palabra\": \"{}\".",
+                    line
+                ),
+            )
+        })?;
+        let expected = match kind.trim() {
+            "accept" => true,
+            "reject" => false,
+            other => {
+                return Err(ParseError::new(
+                    number,
+                    format!("Tipo de caso \"{}\" inválido, use accept o reject.", other),
+                ))
+            }
+        };
+        let word = match word.trim() {
+            "ε" => "",
+            word => word,
+        };
+
+        cases.push(TestCase {
+            line: number,
+            word: word.to_string(),
+            expected,
+        });
+    }
+
+    Ok(cases)
+}
+
+/**
+ Ejecuta cada caso con el mismo `Runner` del autómata.
+    # Arguments
+    * `nfa` - El autómata a probar.
+    * `cases` - Los casos a ejecutar.
+    # Returns
+    Retorna el resultado de cada caso, en el mismo orden.
+*/
+pub fn run_cases(nfa: &NFA<char>, cases: &[TestCase]) -> Vec<CaseResult> {
+    let runner = nfa.runner();
+    cases
+        .iter()
+        .map(|case| CaseResult {
+            case: case.clone(),
+            accepted: runner.run(case.word.chars()),
+        })
+        .collect()
+}
+
+// Muestra una palabra, usando `ε` para la vacía.
+pub fn display_word(word: &str) -> &str {
+    if word.is_empty() {
+        "ε"
+    } else {
+        word
+    }
+}
+
+/**
+ Escribe los resultados en el formato JUnit XML que leen los servidores de integración continua.
+    # Arguments
+    * `suite` - Nombre de la suite, por ejemplo el archivo de casos.
+    * `results` - Los resultados de `run_cases`.
+    # Returns
+    Retorna el documento XML.
+*/
+pub fn to_junit(suite: &str, results: &[CaseResult]) -> String {
+    let failures = results.iter().filter(|r| !r.passed()).count();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites tests=\"{}\" failures=\"{}\">",
+        results.len(),
+        failures
+    );
+    let _ = writeln!(
+        xml,
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">",
+        escape(suite),
+        results.len(),
+        failures
+    );
+
+    for result in results {
+        let kind = if result.case.expected {
+            "accept"
+        } else {
+            "reject"
+        };
+        let name = format!(
+            "línea {}: {} {}",
+            result.case.line,
+            kind,
+            display_word(&result.case.word)
+        );
+        if result.passed() {
+            let _ = writeln!(
+                xml,
+                "    <testcase classname=\"{}\" name=\"{}\"/>",
+                escape(suite),
+                escape(&name)
+            );
+        } else {
+            let _ = writeln!(
+                xml,
+                "    <testcase classname=\"{}\" name=\"{}\">",
+                escape(suite),
+                escape(&name)
+            );
+            let _ = writeln!(
+                xml,
+                "      <failure message=\"{}\"/>",
+                escape(&failure_message(result))
+            );
+            xml.push_str("    </testcase>\n");
+        }
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+// Describe por qué falló un caso.
+pub fn failure_message(result: &CaseResult) -> String {
+    if result.case.expected {
+        "se esperaba que fuera aceptada y fue rechazada".to_string()
+    } else {
+        "se esperaba que fuera rechazada y fue aceptada".to_string()
+    }
+}
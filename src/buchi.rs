@@ -0,0 +1,354 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use crate::error::ParseError;
+use crate::nfa::{Symbol, NFA};
+
+/**
+ Palabra infinita ultimadamente periódica `u(v)^ω`.
+    `cycle` no debe estar vacío.
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lasso<S> {
+    pub prefix: Vec<S>,
+    pub cycle: Vec<S>,
+}
+
+impl<S: fmt::Display> fmt::Display for Lasso<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for symbol in &self.prefix {
+            write!(f, "{}", symbol)?;
+        }
+        write!(f, "(")?;
+        for symbol in &self.cycle {
+            write!(f, "{}", symbol)?;
+        }
+        write!(f, ")^ω")
+    }
+}
+
+impl Lasso<char> {
+    /**
+     Interpreta la notación `u(v)^ω`, la misma que produce `Display`.
+        Se ignoran los espacios y se acepta `^w` en lugar de `^ω`; `u` puede estar vacío.
+        # Arguments
+        * `text` - La palabra infinita, por ejemplo `ab(ba)^ω`.
+        # Returns
+        Retorna el lazo o un error si el texto no tiene esa forma o `v` está vacío.
+    */
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let body = text
+            .strip_suffix(")^ω")
+            .or_else(|| text.strip_suffix(")^w"))
+            .ok_or_else(|| ParseError::new(0, "La palabra debe terminar en \")^ω\"."))?;
+        let (prefix, cycle) = body
+            .split_once('(')
+            .ok_or_else(|| ParseError::new(0, "Falta \"(\" antes de la parte que se repite."))?;
+
+        if prefix.contains([')', '^']) || cycle.contains(['(', ')', '^']) {
+            return Err(ParseError::new(
+                0,
+                format!("\"{}\" no tiene la forma \"u(v)^ω\".", text),
+            ));
+        }
+        if cycle.is_empty() {
+            return Err(ParseError::new(
+                0,
+                "La parte que se repite no puede ser vacía.",
+            ));
+        }
+        Ok(Lasso {
+            prefix: prefix.chars().collect(),
+            cycle: cycle.chars().collect(),
+        })
+    }
+}
+
+/**
+ Autómata de Büchi: acepta las palabras infinitas que tienen una ejecución que pasa
+ infinitas veces por un estado de aceptación.
+    Reutiliza el modelo de estados y transiciones de `NFA`; las transiciones ε se ignoran.
+*/
+pub struct Buchi<S: Symbol = char> {
+    nfa: NFA<S>,
+}
+
+impl Buchi<char> {
+    // Crea el autómata desde el mismo formato de descripción que `NFA::try_from_string`.
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        NFA::try_from_string(description).map(Buchi::from_nfa)
+    }
+}
+
+impl<S: Symbol> Buchi<S> {
+    // Interpreta un `NFA` como autómata de Büchi.
+    pub fn from_nfa(nfa: NFA<S>) -> Self {
+        Buchi { nfa }
+    }
+
+    // Vista del autómata como `NFA`, útil para imprimirlo.
+    pub fn as_nfa(&self) -> &NFA<S> {
+        &self.nfa
+    }
+
+    /**
+     Valida si el autómata acepta la palabra infinita `prefix(cycle)^ω`.
+        # Arguments
+        * `prefix` - La parte inicial `u`.
+        * `cycle` - La parte que se repite `v`; si está vacía la palabra no es infinita
+          y el resultado es `false`.
+        # Returns
+        Retorna `true` si alguna ejecución visita un estado de aceptación infinitas veces.
+    */
+    pub fn accepts_lasso(&self, prefix: &[S], cycle: &[S]) -> bool {
+        if cycle.is_empty() {
+            return false;
+        }
+
+        // Nodos (estado, posición) en la palabra u·v, donde tras el final de v se vuelve al inicio de v
+        let table = self.nfa.transition_table();
+        let length = prefix.len() + cycle.len();
+        let symbol_at = |pos: usize| {
+            if pos < prefix.len() {
+                &prefix[pos]
+            } else {
+                &cycle[pos - prefix.len()]
+            }
+        };
+        let next_pos = |pos: usize| {
+            if pos + 1 == length {
+                prefix.len()
+            } else {
+                pos + 1
+            }
+        };
+
+        let start = self.nfa.start_index() * length;
+        let found = nested_dfs(
+            start,
+            |node| self.nfa.states[node / length].borrow().is_accept,
+            |node| {
+                let (state, pos) = (node / length, node % length);
+                table[state]
+                    .get(symbol_at(pos))
+                    .into_iter()
+                    .flatten()
+                    .map(|&next| ((), next * length + next_pos(pos)))
+                    .collect()
+            },
+        );
+
+        found.is_some()
+    }
+
+    // Valida si el autómata acepta el lazo, por ejemplo uno leído con `Lasso::parse`.
+    pub fn accepts(&self, lasso: &Lasso<S>) -> bool {
+        self.accepts_lasso(&lasso.prefix, &lasso.cycle)
+    }
+
+    /**
+     Busca una palabra aceptada usando búsqueda en profundidad anidada.
+        # Returns
+        Retorna `None` si el lenguaje es vacío, o un contraejemplo `u(v)^ω` formado por
+        un camino hasta un estado de aceptación y un ciclo que vuelve a él.
+    */
+    pub fn find_accepting_lasso(&self) -> Option<Lasso<S>> {
+        let table = self.nfa.transition_table();
+
+        nested_dfs(
+            self.nfa.start_index(),
+            |state| self.nfa.states[state].borrow().is_accept,
+            |state| {
+                table[state]
+                    .iter()
+                    .flat_map(|(symbol, targets)| {
+                        targets.iter().map(move |&next| (symbol.clone(), next))
+                    })
+                    .collect()
+            },
+        )
+        .map(|(prefix, cycle)| Lasso { prefix, cycle })
+    }
+
+    // Indica si el autómata no acepta ninguna palabra infinita.
+    pub fn is_empty(&self) -> bool {
+        self.find_accepting_lasso().is_none()
+    }
+
+    /**
+     Construye un autómata de Büchi que acepta la intersección de ambos lenguajes.
+        Los estados son ternas `(p, q, i)`: la copia `i` indica de qué autómata se espera
+        el próximo estado de aceptación.
+        # Arguments
+        * `other` - El otro autómata de Büchi.
+        # Returns
+        Retorna el autómata producto.
+    */
+    pub fn intersection(&self, other: &Buchi<S>) -> Buchi<S> {
+        let left = self.nfa.transition_table();
+        let right = other.nfa.transition_table();
+        let accept_left = |p: usize| self.nfa.states[p].borrow().is_accept;
+        let accept_right = |q: usize| other.nfa.states[q].borrow().is_accept;
+
+        let start = (self.nfa.start_index(), other.nfa.start_index(), 1u8);
+        let mut triples = vec![start];
+        let mut index: HashMap<(usize, usize, u8), usize> = HashMap::from([(start, 0)]);
+        let mut edges = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            let (p, q, copy) = triples[current];
+            let next_copy = match copy {
+                1 if accept_left(p) => 2,
+                2 if accept_right(q) => 1,
+                copy => copy,
+            };
+
+            for (symbol, targets_p) in &left[p] {
+                let targets_q = match right[q].get(symbol) {
+                    Some(targets_q) => targets_q,
+                    None => continue,
+                };
+                for &p_next in targets_p {
+                    for &q_next in targets_q {
+                        let triple = (p_next, q_next, next_copy);
+                        let next = *index.entry(triple).or_insert_with(|| {
+                            triples.push(triple);
+                            queue.push_back(triples.len() - 1);
+                            triples.len() - 1
+                        });
+                        edges.push((current, Some(symbol.clone()), next));
+                    }
+                }
+            }
+        }
+
+        let states = triples
+            .iter()
+            .map(|&(p, q, copy)| {
+                (
+                    format!(
+                        "({}, {}, {})",
+                        self.nfa.states[p].borrow().state,
+                        other.nfa.states[q].borrow().state,
+                        copy
+                    ),
+                    copy == 1 && accept_left(p),
+                )
+            })
+            .collect();
+
+        Buchi {
+            nfa: NFA::from_table(states, 0, edges),
+        }
+    }
+}
+
+// Marco de la pila de búsqueda: sucesores del nodo, siguiente a visitar y etiqueta de llegada.
+struct Frame<L> {
+    node: usize,
+    edges: Vec<(L, usize)>,
+    next: usize,
+    label: Option<L>,
+}
+
+/**
+ Búsqueda en profundidad anidada (Courcoubetis, Vardi, Wolper y Yannakakis).
+    # Arguments
+    * `start` - Nodo inicial.
+    * `accepting` - Indica si un nodo es de aceptación.
+    * `successors` - Aristas etiquetadas que salen de un nodo.
+    # Returns
+    Retorna las etiquetas del camino hasta un nodo de aceptación y las de un ciclo
+    que vuelve a él, si existen.
+*/
+fn nested_dfs<L, A, F>(start: usize, accepting: A, successors: F) -> Option<(Vec<L>, Vec<L>)>
+where
+    L: Clone,
+    A: Fn(usize) -> bool,
+    F: Fn(usize) -> Vec<(L, usize)>,
+{
+    let mut outer_seen: HashSet<usize> = HashSet::from([start]);
+    let mut inner_seen: HashSet<usize> = HashSet::new();
+
+    let mut outer = vec![Frame {
+        node: start,
+        edges: successors(start),
+        next: 0,
+        label: None,
+    }];
+
+    while let Some(frame) = outer.last_mut() {
+        if let Some((label, target)) = frame.edges.get(frame.next).cloned() {
+            frame.next += 1;
+            if outer_seen.insert(target) {
+                outer.push(Frame {
+                    node: target,
+                    edges: successors(target),
+                    next: 0,
+                    label: Some(label),
+                });
+            }
+            continue;
+        }
+
+        // El nodo terminó: si es de aceptación se busca un ciclo que vuelva a él
+        let seed = frame.node;
+        if accepting(seed) {
+            if let Some(cycle) = inner_dfs(seed, &mut inner_seen, &successors) {
+                let prefix = outer
+                    .iter()
+                    .filter_map(|frame| frame.label.clone())
+                    .collect();
+                return Some((prefix, cycle));
+            }
+        }
+        outer.pop();
+    }
+
+    None
+}
+
+// Búsqueda interna: un camino desde `seed` que vuelva a `seed`.
+fn inner_dfs<L, F>(seed: usize, seen: &mut HashSet<usize>, successors: &F) -> Option<Vec<L>>
+where
+    L: Clone,
+    F: Fn(usize) -> Vec<(L, usize)>,
+{
+    let mut stack = vec![Frame {
+        node: seed,
+        edges: successors(seed),
+        next: 0,
+        label: None,
+    }];
+
+    while let Some(frame) = stack.last_mut() {
+        match frame.edges.get(frame.next).cloned() {
+            Some((label, target)) => {
+                frame.next += 1;
+                if target == seed {
+                    let mut cycle: Vec<L> = stack
+                        .iter()
+                        .filter_map(|frame| frame.label.clone())
+                        .collect();
+                    cycle.push(label);
+                    return Some(cycle);
+                }
+                if seen.insert(target) {
+                    stack.push(Frame {
+                        node: target,
+                        edges: successors(target),
+                        next: 0,
+                        label: Some(label),
+                    });
+                }
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,796 @@
+use std::collections::{BTreeSet, HashSet};
+use std::process::Command;
+
+use crate::batch::{display_word, failure_message, parse_cases, run_cases, to_junit};
+use crate::generator::{generate, GeneratorOptions};
+use crate::grading::{grade as grade_submission, GradingOptions};
+use crate::layout::Layout;
+use crate::nfa::{Node, StateRef, NFA};
+use crate::random::Rng;
+use crate::svg::SvgOptions;
+
+impl NFA<char> {
+    // Crea un autómata de forma interactiva solicitando cada parte al usuario.
+    // No se implementa `Default` porque la construcción lee de la entrada estándar.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> NFA<char> {
+        let alphabet = create_alphabet();
+        let states = create_states();
+        create_transitions(&states, &alphabet);
+        let start_state = define_start_states(&states);
+
+        NFA::from_parts(states, alphabet, start_state)
+    }
+}
+
+/**
+ Función que crea el alfabeto del autómata.
+    # Returns
+    Retorna un `BTreeSet<char>` el cual representa mi conjunto de símbolos .
+*/
+pub fn create_alphabet() -> BTreeSet<char> {
+    let mut alphabet = BTreeSet::new();
+    let size: usize;
+    let mut input;
+
+    loop {
+        println!("Ingrese la cardinalidad del alfabeto: ");
+        input = String::new();
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue;
+        }
+
+        size = match input.trim().parse() {
+            Ok(size) => size,
+            Err(_) => {
+                println!("Error al leer la entrada.");
+                continue;
+            }
+        };
+        break;
+    }
+
+    let mut i = 0;
+
+    while i < size {
+        println!("Ingrese el símbolo {}: ", i + 1);
+        let mut input = String::new();
+
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue; // No incrementa i si hay error en la lectura
+        }
+
+        let symbol = match input.trim().chars().next() {
+            Some(c) => c,
+            None => {
+                println!("Entrada vacía, por favor ingrese un símbolo.");
+                continue; // No incrementa i si no se ingresa un símbolo
+            }
+        };
+
+        if alphabet.insert(symbol) {
+            i += 1; // Solo incrementa i si el símbolo es válido y no está duplicado
+        } else {
+            println!("El símbolo ya existe en el alfabeto.");
+            // No se incrementa i si el símbolo ya existe en el alfabeto
+        }
+    }
+    alphabet
+}
+
+/**
+ Función que crea mi conjunto de estados del autómata .
+    # Returns
+    Retorna un `Vec<StateRef>` el cual representa mi conjunto de estados.
+*/
+pub fn create_states() -> Vec<StateRef> {
+    let mut states = Vec::new();
+    let mut states_name: HashSet<String> = HashSet::new();
+    let mut input;
+
+    loop {
+        println!("Ingrese la cardinalidad del conjunto de los estados: ");
+        input = String::new();
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue;
+        }
+
+        let size = match input.trim().parse() {
+            Ok(size) => size,
+            Err(_) => {
+                println!("Error al leer la entrada.");
+                continue;
+            }
+        };
+
+        let mut i: usize = 0;
+
+        while i < size {
+            println!("Ingrese el nombre del estado {}: ", i);
+            input = String::new();
+
+            if std::io::stdin().read_line(&mut input).is_err() {
+                println!("Error al leer la entrada.");
+                continue;
+            }
+
+            let state = input.trim().to_string();
+
+            if !states_name.insert(state.clone()) {
+                println!("El estado \"{}\" ya ha sido definido.", state);
+                continue;
+            }
+
+            let is_accept;
+
+            loop {
+                println!("Es estado de aceptacion? (s/n): ");
+                input = String::new();
+
+                if std::io::stdin().read_line(&mut input).is_err() {
+                    println!("Error al leer la entrada.");
+                    continue;
+                }
+
+                match input.trim() {
+                    "s" => {
+                        is_accept = true;
+                        break;
+                    }
+                    "n" => {
+                        is_accept = false;
+                        break;
+                    }
+                    _ => {
+                        println!("Opcion invalida.");
+                    }
+                }
+            }
+
+            states.push(Node::new(&state, is_accept));
+            i += 1;
+        }
+
+        break;
+    }
+
+    states
+}
+
+/**
+ Crea las transiciones entre los nodos (estados) del autómata.
+    # Arguments
+    * `states` - Referencia al vector de nodos.
+    * `alphabet` - Referencia al alfabeto.
+*/
+pub fn create_transitions(states: &[StateRef], alphabet: &BTreeSet<char>) {
+    loop {
+        // Solicitar al usuario el estado-símbolo-estados_destino
+        println!("Ingrese la transición en el formato \"(estado_actual, símbolo)->{{estados_destino}}\" (o escriba \"exit\" para salir):");
+        let mut input = String::new();
+
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue;
+        }
+
+        let input = input.trim();
+
+        // Verificar si el usuario quiere salir
+        if input.to_lowercase() == "exit" {
+            break;
+        }
+
+        // Verificar el formato de la entrada
+        let parts: Vec<&str> = input.split("->").collect();
+        if parts.len() != 2 {
+            println!(
+                "Formato incorrecto. Debe ser \"(estado_actual, símbolo)->{{estados_destino}}\"."
+            );
+            continue;
+        }
+
+        let transition_part = parts[0].trim();
+        let next_states_input = parts[1].trim();
+
+        // Verificar que el formato de la parte de transición sea correcto (debe ser "(estado_actual, símbolo)")
+        if !transition_part.starts_with('(') || !transition_part.ends_with(')') {
+            println!("Formato incorrecto en la parte de transición. Debe ser \"(estado_actual, símbolo)\".");
+            continue;
+        }
+
+        // Remover los paréntesis y dividir por la coma
+        let transition_inner = &transition_part[1..transition_part.len() - 1];
+        let transition_parts: Vec<&str> = transition_inner.split(',').collect();
+
+        if transition_parts.len() != 2 {
+            println!("Formato incorrecto. Debe haber un estado y un símbolo separados por coma.");
+            continue;
+        }
+
+        let state_input = transition_parts[0].trim();
+        let symbol_input = transition_parts[1].trim();
+
+        // Verificar que el símbolo tenga un solo carácter
+        if symbol_input.len() != 1 {
+            println!("El símbolo debe ser un solo carácter.");
+            continue;
+        }
+
+        let symbol = symbol_input.chars().next().unwrap();
+
+        // Verificar que el símbolo pertenezca al alfabeto
+        if !alphabet.contains(&symbol) {
+            println!("El símbolo '{}' no pertenece al alfabeto.", symbol);
+            continue;
+        }
+
+        // Verificar que los estados destino estén entre llaves
+        if !next_states_input.starts_with('{') || !next_states_input.ends_with('}') {
+            println!("Formato incorrecto en los estados destino. Deben estar dentro de llaves \"{{estado1, estado2}}\".");
+            continue;
+        }
+
+        // Remover las llaves y dividir los estados destino por comas
+        let next_states_inner = &next_states_input[1..next_states_input.len() - 1];
+        let next_states: Vec<&str> = next_states_inner.split(',').map(|s| s.trim()).collect();
+
+        // Buscar el estado actual
+        let current_state = states.iter().find(|&x| x.borrow().state == state_input);
+
+        if let Some(current) = current_state {
+            // Buscar y agregar las transiciones para cada estado destino
+            for next_state_name in next_states {
+                let next_state = states.iter().find(|&x| x.borrow().state == next_state_name);
+
+                match next_state {
+                    Some(next) => {
+                        // Agregar la transición a cada estado destino
+                        Node::add_transition(current, symbol, next.clone());
+                        println!(
+                            "Transición agregada: δ({}, {}) = {}",
+                            state_input, symbol, next_state_name
+                        );
+                    }
+                    None => {
+                        println!("El estado destino \"{}\" no existe.", next_state_name);
+                    }
+                }
+            }
+        } else {
+            println!("El estado \"{}\" no existe.", state_input);
+        }
+    }
+}
+
+/**
+ Define mi estado inicial del autómata.
+    # Arguments
+    * `states` - Referencia al vector de nodos.
+    # Returns
+    Retorna un `StateRef` que es el puntero al estado inicial.
+*/
+pub fn define_start_states(states: &[StateRef]) -> StateRef {
+    let mut input;
+
+    if states.is_empty() {
+        return Node::new("Empty", false);
+    }
+
+    loop {
+        println!("Ingrese el estado inicial: ");
+        input = String::new();
+
+        if std::io::stdin().read_line(&mut input).is_err() {
+            println!("Error al leer la entrada.");
+            continue;
+        }
+
+        let start_state = states.iter().find(|&x| x.borrow().state == input.trim());
+
+        match start_state {
+            Some(start) => {
+                return start.clone();
+            }
+            None => {
+                println!("El estado no existe.");
+            }
+        }
+    }
+}
+
+// Menú principal del programa.
+pub fn menu() {
+    clear_console();
+    println!("Cree un autómata finito No determinista.\n");
+    let mut nfa = NFA::new();
+
+    loop {
+        wait_for_keypress();
+        clear_console();
+        println!("Autómata Finito No Determinista");
+        println!("=============================");
+        println!("1. Crear o reemplazar un nuevo autómata.");
+        println!("2. Validar una palabra.");
+        println!("3. Imprimir el conjunto de estados.");
+        println!("4. Imprimir el alfabeto.");
+        println!("5. Imprimir el estado inicial.");
+        println!("6. Imprimir los conjuntos de aceptación.");
+        println!("7. Imprimir las 5-tupla.");
+        println!("8. Imprimir las transiciones.");
+        println!("9. Salir del programa.\n");
+
+        let mut choice = String::new();
+        std::io::stdin().read_line(&mut choice).unwrap();
+
+        match choice.trim() {
+            "1" => {
+                nfa = NFA::new();
+                println!("Nuevo autómata creado.");
+            }
+            "2" => {
+                println!("Ingrese la palabra a validar:");
+                let mut input = String::new();
+                std::io::stdin().read_line(&mut input).unwrap();
+                let input = input.trim();
+                if nfa.run(input.chars()) {
+                    println!("La palabra es aceptada por el autómata.");
+                } else {
+                    println!("La palabra es rechazada por el autómata.");
+                }
+            }
+            "3" => {
+                println!("Conjunto de estados:");
+                nfa.print_states();
+                println!();
+            }
+            "4" => {
+                println!("Alfabeto:");
+                nfa.print_alphabet();
+                println!();
+            }
+            "5" => {
+                println!("Estado inicial:");
+                nfa.print_start_state();
+                println!();
+            }
+            "6" => {
+                println!("Conjuntos de aceptación:");
+                nfa.print_accept_states();
+                println!();
+            }
+            "7" => {
+                println!("Conjuntos de aceptación:");
+                nfa.tupla();
+            }
+            "8" => {
+                println!("Transiciones:");
+                nfa.print_transitions();
+            }
+            "9" => break,
+            _ => println!("Opción no válida, intente de nuevo."),
+        }
+    }
+}
+
+// Función para limpiar la consola
+pub fn clear_console() {
+    if cfg!(target_os = "windows") {
+        Command::new("cmd")
+            .args(["/C", "cls"])
+            .status()
+            .expect("Error al limpiar la consola");
+    } else {
+        Command::new("clear")
+            .status()
+            .expect("Error al limpiar la consola");
+    }
+}
+
+// Función para esperar a que el usuario presione una tecla
+pub fn wait_for_keypress() {
+    let mut input = String::new();
+    println!("Presione enter para continuar...");
+    std::io::stdin().read_line(&mut input).ok();
+}
+
+// Texto de ayuda de los subcomandos.
+pub const USAGE: &str = "Uso:
+    automata                                         Ejemplo incluido
+    automata render <autómata> [-o <salida.svg>] [--layout layered|circular]
+    automata test <autómata> <casos> [--junit <salida.xml>]
+    automata grade (<referencia> | --regex <expresión>) <entrega> [--deterministic]
+                   [--complete] [--length <n>] [--json <salida.json>]
+    automata explain <autómata> [--format text|markdown|latex]
+    automata nerode <autómata> [--format text|latex]
+    automata pump <autómata> <palabra> [--powers 0,1,2,3]
+    automata random [--seed <n>] [--states <n>] [--alphabet <símbolos>] [--density <p>]
+                    [--accept <p>] [--dfa] [--complete] [--minimal] [-o <salida.txt>]";
+
+/**
+ Subcomando `render`: dibuja en SVG un autómata escrito en el formato de descripción.
+    # Arguments
+    * `args` - Argumentos después de `render`.
+    # Returns
+    Retorna un mensaje de error si los argumentos o el archivo no son válidos.
+*/
+pub fn render(args: &[String]) -> Result<(), String> {
+    let mut input = None;
+    let mut output = None;
+    let mut options = SvgOptions::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--output" => {
+                output = Some(args.next().ok_or("Falta el archivo después de \"-o\".")?);
+            }
+            "--layout" => {
+                options.layout = match args.next().map(String::as_str) {
+                    Some("layered") => Layout::Layered,
+                    Some("circular") => Layout::Circular,
+                    other => {
+                        return Err(format!(
+                            "Distribución \"{}\" desconocida, use layered o circular.",
+                            other.unwrap_or("")
+                        ))
+                    }
+                };
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("Argumento inesperado \"{}\".\n{}", arg, USAGE)),
+        }
+    }
+
+    let input = input.ok_or_else(|| format!("Falta el archivo del autómata.\n{}", USAGE))?;
+    let nfa = read_automaton(input)?;
+    let svg = nfa.to_svg_with(&options);
+
+    match output {
+        Some(path) => std::fs::write(path, svg)
+            .map_err(|e| format!("No se pudo escribir \"{}\": {}", path, e)),
+        None => {
+            print!("{}", svg);
+            Ok(())
+        }
+    }
+}
+
+/**
+ Subcomando `test`: valida en lote las palabras de un archivo de casos.
+    Imprime el resultado de cada caso y un resumen, y opcionalmente escribe un reporte JUnit XML.
+    # Arguments
+    * `args` - Argumentos después de `test`.
+    # Returns
+    Retorna un error si algún caso falla, para que el programa termine con código distinto de cero.
+*/
+pub fn test(args: &[String]) -> Result<(), String> {
+    let mut paths = Vec::new();
+    let mut junit = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--junit" => {
+                junit = Some(
+                    args.next()
+                        .ok_or("Falta el archivo después de \"--junit\".")?,
+                );
+            }
+            _ => paths.push(arg),
+        }
+    }
+    let (automaton, cases) = match paths.as_slice() {
+        [automaton, cases] => (automaton.as_str(), cases.as_str()),
+        _ => return Err(format!("Se esperaban el autómata y los casos.\n{}", USAGE)),
+    };
+
+    let nfa = read_automaton(automaton)?;
+    let text = std::fs::read_to_string(cases)
+        .map_err(|e| format!("No se pudo leer \"{}\": {}", cases, e))?;
+    let cases_list = parse_cases(&text).map_err(|e| format!("{}: {}", cases, e))?;
+    let results = run_cases(&nfa, &cases_list);
+
+    for result in &results {
+        let kind = if result.case.expected {
+            "accept"
+        } else {
+            "reject"
+        };
+        if result.passed() {
+            println!("ok     {}: {}", kind, display_word(&result.case.word));
+        } else {
+            println!(
+                "FALLA  {}: {} (línea {}, {})",
+                kind,
+                display_word(&result.case.word),
+                result.case.line,
+                failure_message(result)
+            );
+        }
+    }
+
+    let failed = results.iter().filter(|r| !r.passed()).count();
+    println!(
+        "\n{} casos, {} correctos, {} fallidos.",
+        results.len(),
+        results.len() - failed,
+        failed
+    );
+
+    if let Some(path) = junit {
+        std::fs::write(path, to_junit(cases, &results))
+            .map_err(|e| format!("No se pudo escribir \"{}\": {}", path, e))?;
+    }
+
+    if failed > 0 {
+        Err(format!("{} caso(s) fallaron.", failed))
+    } else {
+        Ok(())
+    }
+}
+
+/**
+ Subcomando `grade`: corrige una entrega comparándola con una referencia.
+    Imprime el reporte y, con `--json`, lo guarda en formato JSON.
+    # Arguments
+    * `args` - Argumentos después de `grade`.
+    # Returns
+    Retorna un mensaje de error si los argumentos o los archivos no son válidos.
+*/
+pub fn grade(args: &[String]) -> Result<(), String> {
+    let mut paths = Vec::new();
+    let mut regex = None;
+    let mut json = None;
+    let mut options = GradingOptions::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--regex" => {
+                regex = Some(
+                    args.next()
+                        .ok_or("Falta la expresión después de \"--regex\".")?,
+                );
+            }
+            "--json" => {
+                json = Some(
+                    args.next()
+                        .ok_or("Falta el archivo después de \"--json\".")?,
+                );
+            }
+            "--length" => {
+                let length = args
+                    .next()
+                    .ok_or("Falta el largo después de \"--length\".")?;
+                options.max_length = length
+                    .parse()
+                    .map_err(|_| format!("\"{}\" no es un largo válido.", length))?;
+            }
+            "--deterministic" => options.require_deterministic = true,
+            "--complete" => options.require_complete = true,
+            _ => paths.push(arg.as_str()),
+        }
+    }
+
+    let (reference, submission) = match (regex, paths.as_slice()) {
+        (Some(regex), [submission]) => (
+            NFA::from_regex(regex).map_err(|e| format!("Expresión de referencia: {}", e))?,
+            *submission,
+        ),
+        (None, [reference, submission]) => (read_automaton(reference)?, *submission),
+        _ => {
+            return Err(format!(
+                "Se esperaban la referencia y la entrega.\n{}",
+                USAGE
+            ))
+        }
+    };
+
+    let text = std::fs::read_to_string(submission)
+        .map_err(|e| format!("No se pudo leer \"{}\": {}", submission, e))?;
+    let report = grade_submission(&reference, &text, &options);
+    println!("{}", report);
+
+    if let Some(path) = json {
+        std::fs::write(path, report.to_json())
+            .map_err(|e| format!("No se pudo escribir \"{}\": {}", path, e))?;
+    }
+    Ok(())
+}
+
+/**
+ Subcomando `explain`: muestra paso a paso la construcción de subconjuntos de un autómata.
+    # Arguments
+    * `args` - Argumentos después de `explain`.
+    # Returns
+    Retorna un mensaje de error si los argumentos o el archivo no son válidos.
+*/
+pub fn explain(args: &[String]) -> Result<(), String> {
+    let mut input = None;
+    let mut format = "text";
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = match args.next().map(String::as_str) {
+                    Some(format @ ("text" | "markdown" | "latex")) => format,
+                    other => {
+                        return Err(format!(
+                            "Formato \"{}\" desconocido, use text, markdown o latex.",
+                            other.unwrap_or("")
+                        ))
+                    }
+                };
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("Argumento inesperado \"{}\".\n{}", arg, USAGE)),
+        }
+    }
+
+    let input = input.ok_or_else(|| format!("Falta el archivo del autómata.\n{}", USAGE))?;
+    let explanation = read_automaton(input)?.explain_determinization();
+    match format {
+        "markdown" => print!("{}", explanation.to_markdown()),
+        "latex" => print!("{}", explanation.to_latex()),
+        _ => print!("{}", explanation.to_text()),
+    }
+    Ok(())
+}
+
+/**
+ Subcomando `nerode`: muestra la tabla de distinguibilidad de un autómata determinista.
+    # Arguments
+    * `args` - Argumentos después de `nerode`.
+    # Returns
+    Retorna un mensaje de error si los argumentos o el archivo no son válidos, o si el
+    autómata no es determinista y completo.
+*/
+pub fn nerode(args: &[String]) -> Result<(), String> {
+    let mut input = None;
+    let mut latex = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                latex = match args.next().map(String::as_str) {
+                    Some("text") => false,
+                    Some("latex") => true,
+                    other => {
+                        return Err(format!(
+                            "Formato \"{}\" desconocido, use text o latex.",
+                            other.unwrap_or("")
+                        ))
+                    }
+                };
+            }
+            _ if input.is_none() => input = Some(arg),
+            _ => return Err(format!("Argumento inesperado \"{}\".\n{}", arg, USAGE)),
+        }
+    }
+
+    let input = input.ok_or_else(|| format!("Falta el archivo del autómata.\n{}", USAGE))?;
+    let table = read_automaton(input)?.table_filling()?;
+    if latex {
+        print!("{}", table.to_latex());
+    } else {
+        print!("{}", table.to_text());
+    }
+    Ok(())
+}
+
+/**
+ Subcomando `pump`: descompone una palabra aceptada según el lema de bombeo.
+    # Arguments
+    * `args` - Argumentos después de `pump`.
+    # Returns
+    Retorna un mensaje de error si los argumentos o el archivo no son válidos, o si la
+    palabra no se puede bombear.
+*/
+pub fn pump(args: &[String]) -> Result<(), String> {
+    let mut paths = Vec::new();
+    let mut powers = vec![0, 1, 2, 3];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--powers" => {
+                let list = args
+                    .next()
+                    .ok_or("Falta la lista después de \"--powers\".")?;
+                powers = list
+                    .split(',')
+                    .map(|power| {
+                        power
+                            .trim()
+                            .parse()
+                            .map_err(|_| format!("\"{}\" no es una potencia válida.", power))
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            _ => paths.push(arg.as_str()),
+        }
+    }
+
+    let [input, word] = paths.as_slice() else {
+        return Err(format!("Se esperaban el autómata y la palabra.\n{}", USAGE));
+    };
+    let nfa = read_automaton(input)?;
+    let word: Vec<char> = word.chars().collect();
+    print!("{}", nfa.pump(&word, &powers)?);
+    Ok(())
+}
+
+/**
+ Subcomando `random`: genera un autómata aleatorio en el formato de descripción.
+    La misma semilla y opciones producen siempre el mismo autómata.
+    # Arguments
+    * `args` - Argumentos después de `random`.
+    # Returns
+    Retorna un mensaje de error si los argumentos no son válidos o no se pudo generar el autómata.
+*/
+pub fn random(args: &[String]) -> Result<(), String> {
+    let mut seed = 0;
+    let mut output = None;
+    let mut options = GeneratorOptions::default();
+
+    fn number<T: std::str::FromStr>(flag: &str, value: Option<&String>) -> Result<T, String> {
+        let value = value.ok_or_else(|| format!("Falta el valor después de \"{}\".", flag))?;
+        value
+            .parse()
+            .map_err(|_| format!("\"{}\" no es un valor válido para \"{}\".", value, flag))
+    }
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = number(arg, args.next())?,
+            "--states" => options.states = number(arg, args.next())?,
+            "--density" => options.density = number(arg, args.next())?,
+            "--accept" => options.accept_ratio = number(arg, args.next())?,
+            "--alphabet" => {
+                options.alphabet = args
+                    .next()
+                    .ok_or("Falta el alfabeto después de \"--alphabet\".")?
+                    .chars()
+                    .collect();
+            }
+            "--dfa" => options.deterministic = true,
+            "--complete" => {
+                options.deterministic = true;
+                options.complete = true;
+            }
+            "--minimal" => {
+                options.deterministic = true;
+                options.minimal = true;
+            }
+            "-o" | "--output" => {
+                output = Some(args.next().ok_or("Falta el archivo después de \"-o\".")?);
+            }
+            _ => return Err(format!("Argumento inesperado \"{}\".\n{}", arg, USAGE)),
+        }
+    }
+
+    let nfa = generate(&mut Rng::new(seed), &options)?;
+    let description = nfa.to_description();
+    match output {
+        Some(path) => std::fs::write(path, description)
+            .map_err(|e| format!("No se pudo escribir \"{}\": {}", path, e)),
+        None => {
+            print!("{}", description);
+            Ok(())
+        }
+    }
+}
+
+// Lee un autómata en el formato de descripción desde un archivo.
+pub fn read_automaton(path: &str) -> Result<NFA<char>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("No se pudo leer \"{}\": {}", path, e))?;
+    NFA::try_from_string(&text).map_err(|e| format!("{}: {}", path, e))
+}
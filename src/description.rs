@@ -0,0 +1,170 @@
+//! Utilidades compartidas para leer el formato de descripción usado por
+//! `NFA::try_from_string` y sus extensiones.
+
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use crate::error::ParseError;
+
+/**
+ Clasificación de una línea del formato de descripción.
+    Las transiciones se entregan separadas en la parte izquierda (sin paréntesis)
+    y la parte derecha de la flecha `->`, para que cada tipo de autómata las interprete.
+*/
+pub(crate) enum Line<'a> {
+    Empty,
+    Alphabet(&'a str),
+    States(&'a str),
+    Start(&'a str),
+    Final(&'a str),
+    Transition(&'a str, &'a str),
+}
+
+// Clasifica una línea ya recortada.
+pub(crate) fn classify(line: &str) -> Result<Line<'_>, String> {
+    if line.is_empty() {
+        Ok(Line::Empty)
+    } else if let Some(rest) = line.strip_prefix("alphabet=") {
+        Ok(Line::Alphabet(rest.trim()))
+    } else if let Some(rest) = line.strip_prefix("state=") {
+        Ok(Line::States(rest.trim()))
+    } else if let Some(rest) = line.strip_prefix("start_state=") {
+        Ok(Line::Start(rest.trim()))
+    } else if let Some(rest) = line.strip_prefix("F=") {
+        Ok(Line::Final(rest.trim()))
+    } else if line.starts_with('(') {
+        let (left, right) = split_transition(line)?;
+        Ok(Line::Transition(left, right))
+    } else {
+        Err(format!("Línea no reconocida: \"{}\".", line))
+    }
+}
+
+/**
+ Separa una transición `(izquierda)->derecha`.
+    # Returns
+    Retorna el contenido de los paréntesis y la parte derecha, ambos recortados.
+*/
+pub(crate) fn split_transition(line: &str) -> Result<(&str, &str), String> {
+    let arrow = line
+        .rfind("->")
+        .ok_or_else(|| "Formato incorrecto. Falta \"->\" en la transición.".to_string())?;
+    let left = line[..arrow].trim();
+    let right = line[arrow + 2..].trim();
+
+    let inner = left
+        .strip_prefix('(')
+        .and_then(|l| l.strip_suffix(')'))
+        .ok_or_else(|| {
+            "Formato incorrecto en la parte de transición. Debe ir entre paréntesis.".to_string()
+        })?;
+
+    Ok((inner.trim(), right))
+}
+
+/**
+ Interpreta un conjunto entre llaves como `{q0, q1}`.
+    # Returns
+    Retorna los elementos recortados, omitiendo los vacíos.
+*/
+pub(crate) fn parse_set(text: &str) -> Result<Vec<&str>, String> {
+    let inner = text
+        .trim()
+        .strip_prefix('{')
+        .and_then(|t| t.strip_suffix('}'))
+        .ok_or_else(|| {
+            format!(
+                "Se esperaba un conjunto entre llaves y se encontró \"{}\".",
+                text
+            )
+        })?;
+
+    Ok(inner
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect())
+}
+
+/**
+ Interpreta un símbolo del alfabeto, que debe ser un único carácter.
+    # Returns
+    Retorna el carácter o un mensaje de error.
+*/
+pub(crate) fn parse_symbol(text: &str) -> Result<char, String> {
+    let mut chars = text.trim().chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(format!(
+            "El símbolo \"{}\" debe ser un solo carácter.",
+            text.trim()
+        )),
+    }
+}
+
+/**
+ Separa la parte izquierda de una transición `estado, símbolo`.
+    # Returns
+    Retorna el nombre del estado y el símbolo.
+*/
+pub(crate) fn split_state_symbol(inner: &str) -> Result<(&str, char), String> {
+    let (state, symbol) = inner
+        .split_once(',')
+        .ok_or_else(|| "Debe haber un estado y un símbolo separados por coma.".to_string())?;
+    Ok((state.trim(), parse_symbol(symbol)?))
+}
+
+// Retorna el autómata en construcción, o un error si aún no se declararon estados.
+pub(crate) fn declared<T>(automaton: &mut Option<T>) -> Result<&mut T, String> {
+    automaton
+        .as_mut()
+        .ok_or_else(|| "Los estados deben declararse antes con \"state=\".".to_string())
+}
+
+// Error para un estado que no fue declarado.
+pub(crate) fn unknown_state(name: &str) -> ParseError {
+    ParseError::new(0, format!("El estado \"{}\" no existe.", name))
+}
+
+// Error para una descripción sin `start_state=`.
+pub(crate) fn missing_start() -> ParseError {
+    ParseError::new(0, "Falta el estado inicial (\"start_state=\").")
+}
+
+/**
+ Nombres de los estados en orden de declaración, con un índice para buscarlos por nombre.
+    Lo usan los autómatas que guardan sus estados por posición; se comporta como `&[String]`.
+*/
+#[derive(Clone, Debug, Default)]
+pub(crate) struct StateNames {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl StateNames {
+    // Declara un estado y retorna su posición, o `None` si ya estaba declarado.
+    pub(crate) fn declare(&mut self, name: &str) -> Option<usize> {
+        if self.index.contains_key(name) {
+            return None;
+        }
+        self.names.push(name.to_string());
+        self.index.insert(name.to_string(), self.names.len() - 1);
+        Some(self.names.len() - 1)
+    }
+
+    // Posición de un estado declarado, o el error de `unknown_state`.
+    pub(crate) fn position(&self, name: &str) -> Result<usize, ParseError> {
+        self.index
+            .get(name)
+            .copied()
+            .ok_or_else(|| unknown_state(name))
+    }
+}
+
+impl Deref for StateNames {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.names
+    }
+}
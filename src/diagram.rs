@@ -0,0 +1,170 @@
+//! Exportación a diagramas de estados de Mermaid, PlantUML y Graphviz (DOT).
+
+use std::fmt::{Display, Write};
+
+use crate::layout::{edge_label, merged_edges};
+use crate::nfa::{Symbol, NFA};
+
+impl<S: Symbol + Display> NFA<S> {
+    /**
+     Exporta el autómata como `stateDiagram-v2` de Mermaid.
+        Los estados se identifican como `s0`, `s1`, ... y su nombre se muestra como
+        descripción, así los nombres de la construcción de subconjuntos (`{q0, q1}`) no
+        rompen la sintaxis. El inicial recibe la flecha desde `[*]`, los de aceptación
+        una flecha hacia `[*]` y la clase `accepting`. Las transiciones paralelas se agrupan.
+        # Returns
+        Retorna el código del diagrama.
+    */
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("stateDiagram-v2\n    direction LR\n");
+        mermaid.push_str("    classDef accepting stroke-width:4px\n");
+
+        for (index, state) in self.states.iter().enumerate() {
+            let _ = writeln!(
+                mermaid,
+                "    state \"{}\" as s{}",
+                escape_mermaid(&state.borrow().state),
+                index
+            );
+        }
+
+        let _ = writeln!(mermaid, "    [*] --> s{}", self.start_index());
+        for ((from, to), symbols) in merged_edges(self) {
+            let _ = writeln!(
+                mermaid,
+                "    s{} --> s{} : {}",
+                from,
+                to,
+                escape_mermaid(&edge_label(&symbols))
+            );
+        }
+
+        for (index, state) in self.states.iter().enumerate() {
+            if state.borrow().is_accept {
+                let _ = writeln!(mermaid, "    s{} --> [*]", index);
+                let _ = writeln!(mermaid, "    class s{} accepting", index);
+            }
+        }
+        mermaid
+    }
+
+    /**
+     Exporta el autómata como diagrama de estados de PlantUML.
+        Sigue las mismas convenciones que `to_mermaid`; los estados de aceptación se
+        dibujan además con borde grueso.
+        # Returns
+        Retorna el código entre `@startuml` y `@enduml`.
+    */
+    pub fn to_plantuml(&self) -> String {
+        let mut plantuml =
+            String::from("@startuml\nhide empty description\nleft to right direction\n");
+
+        for (index, state) in self.states.iter().enumerate() {
+            let state = state.borrow();
+            let style = if state.is_accept { " ##[bold]" } else { "" };
+            let _ = writeln!(
+                plantuml,
+                "state \"{}\" as s{}{}",
+                escape_plantuml(&state.state),
+                index,
+                style
+            );
+        }
+
+        let _ = writeln!(plantuml, "[*] --> s{}", self.start_index());
+        for ((from, to), symbols) in merged_edges(self) {
+            let _ = writeln!(
+                plantuml,
+                "s{} --> s{} : {}",
+                from,
+                to,
+                escape_plantuml(&edge_label(&symbols))
+            );
+        }
+        for (index, state) in self.states.iter().enumerate() {
+            if state.borrow().is_accept {
+                let _ = writeln!(plantuml, "s{} --> [*]", index);
+            }
+        }
+
+        plantuml.push_str("@enduml\n");
+        plantuml
+    }
+
+    /**
+     Exporta el autómata en el lenguaje DOT de Graphviz.
+        Sigue las mismas convenciones que `to_mermaid`: los estados se identifican como
+        `s0`, `s1`, ... con su nombre como etiqueta, los de aceptación usan doble círculo
+        y el inicial recibe una flecha desde un nodo invisible.
+        # Returns
+        Retorna el código del grafo, listo para `dot -Tsvg`.
+    */
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph automata {\n    rankdir=LR;\n");
+        dot.push_str("    start [shape=point, style=invis];\n");
+
+        for (index, state) in self.states.iter().enumerate() {
+            let state = state.borrow();
+            let shape = if state.is_accept {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            let _ = writeln!(
+                dot,
+                "    s{} [shape={}, label=\"{}\"];",
+                index,
+                shape,
+                escape_dot(&state.state)
+            );
+        }
+
+        let _ = writeln!(dot, "    start -> s{};", self.start_index());
+        for ((from, to), symbols) in merged_edges(self) {
+            let _ = writeln!(
+                dot,
+                "    s{} -> s{} [label=\"{}\"];",
+                from,
+                to,
+                escape_dot(&edge_label(&symbols))
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// Mermaid usa códigos `#n;` para los caracteres que tienen significado en su sintaxis.
+fn escape_mermaid(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("#quot;"),
+            '#' | ':' | ';' | '<' | '>' | '{' | '}' => {
+                let _ = write!(escaped, "#{};", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// PlantUML admite caracteres Unicode escritos como `<U+XXXX>`.
+fn escape_plantuml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' | '\\' | ':' | '<' | '>' => {
+                let _ = write!(escaped, "<U+{:04X}>", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// En DOT solo las comillas y la barra invertida necesitan escaparse dentro de una etiqueta.
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fmt;
+
+/**
+ Error producido al interpretar la descripción textual de un autómata.
+    `line` es el número de línea (empezando en 1) donde se encontró el problema,
+    o 0 cuando el error no corresponde a una línea concreta.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "línea {}: {}", self.line, self.message)
+        }
+    }
+}
+
+impl Error for ParseError {}
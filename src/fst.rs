@@ -0,0 +1,499 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fmt;
+
+use crate::description::{classify, missing_start, parse_set, Line, StateNames};
+use crate::error::ParseError;
+use crate::nfa::{Symbol, NFA};
+
+/**
+ Etiqueta de una transición de un transductor: el par `entrada:salida`.
+    Cualquiera de las dos cadenas puede ser vacía (ε).
+*/
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Label<I, O> {
+    pub input: Vec<I>,
+    pub output: Vec<O>,
+}
+
+impl<I, O> Label<I, O> {
+    pub fn new(input: Vec<I>, output: Vec<O>) -> Self {
+        Label { input, output }
+    }
+}
+
+impl<I: fmt::Display, O: fmt::Display> fmt::Display for Label<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_word(f, &self.input)?;
+        write!(f, ":")?;
+        write_word(f, &self.output)
+    }
+}
+
+// Escribe una cadena de símbolos, o `ε` si está vacía.
+fn write_word<T: fmt::Display>(f: &mut fmt::Formatter<'_>, word: &[T]) -> fmt::Result {
+    if word.is_empty() {
+        return write!(f, "ε");
+    }
+    for symbol in word {
+        write!(f, "{}", symbol)?;
+    }
+    Ok(())
+}
+
+/**
+ Transductor finito no determinista (NFST).
+    Reutiliza el modelo de `Node` usando como símbolo el par `Label` de cadenas de entrada
+    y salida, así que un `NFA<Label<I, O>>` describe exactamente un transductor.
+*/
+pub struct NFST<I: Symbol = char, O: Symbol = char> {
+    nfa: NFA<Label<I, O>>,
+}
+
+// Transición `(entrada, salida, destino)` con a lo más un símbolo de cada lado.
+type UnitEdge<I, O> = (Option<I>, Option<O>, usize);
+
+// Transductor con etiquetas de a lo más un símbolo de cada lado, usado por los algoritmos.
+struct Unit<I, O> {
+    states: Vec<(String, bool)>,
+    start: usize,
+    edges: Vec<Vec<UnitEdge<I, O>>>,
+}
+
+impl<I: Symbol, O: Symbol> NFST<I, O> {
+    // Crea el transductor a partir de un autómata cuyas etiquetas son pares entrada/salida.
+    pub fn from_nfa(nfa: NFA<Label<I, O>>) -> Self {
+        NFST { nfa }
+    }
+
+    // Vista del transductor como autómata sobre pares `Label`.
+    pub fn as_nfa(&self) -> &NFA<Label<I, O>> {
+        &self.nfa
+    }
+
+    /**
+     Calcula todas las salidas posibles para una entrada.
+        # Arguments
+        * `input` - La secuencia de símbolos de entrada.
+        # Returns
+        Retorna el conjunto de salidas de los caminos de aceptación, o `None` si es infinito
+        (un ciclo que no consume entrada pero sí produce salida).
+    */
+    pub fn transduce<It: IntoIterator<Item = I>>(&self, input: It) -> Option<BTreeSet<Vec<O>>> {
+        let word: Vec<I> = input.into_iter().collect();
+        let unit = self.unit();
+        let n = word.len();
+        let node = |state: usize, pos: usize| state * (n + 1) + pos;
+        let total = unit.states.len() * (n + 1);
+
+        // Grafo de configuraciones (estado, posición) y su inverso
+        let mut forward: Vec<Vec<(usize, Option<O>)>> = vec![Vec::new(); total];
+        let mut backward: Vec<Vec<usize>> = vec![Vec::new(); total];
+        for (state, edges) in unit.edges.iter().enumerate() {
+            for pos in 0..=n {
+                for (input, output, next) in edges {
+                    let target = match input {
+                        None => node(*next, pos),
+                        Some(symbol) if pos < n && *symbol == word[pos] => node(*next, pos + 1),
+                        _ => continue,
+                    };
+                    forward[node(state, pos)].push((target, output.clone()));
+                    backward[target].push(node(state, pos));
+                }
+            }
+        }
+
+        let reachable = search(total, [node(unit.start, 0)], |u| {
+            forward[u].iter().map(|(v, _)| *v).collect()
+        });
+        let finals = (0..unit.states.len())
+            .filter(|&state| unit.states[state].1)
+            .map(|state| node(state, n));
+        let productive = search(total, finals, |u| backward[u].clone());
+        let useful = |u: usize| reachable[u] && productive[u];
+
+        // Un ciclo sin consumir entrada que produce salida genera infinitas salidas
+        for u in (0..total).filter(|&u| useful(u)) {
+            for (v, output) in &forward[u] {
+                if output.is_some() && u % (n + 1) == v % (n + 1) && useful(*v) {
+                    let same_position = search(total, [*v], |x| {
+                        forward[x]
+                            .iter()
+                            .filter(|(y, _)| x % (n + 1) == y % (n + 1) && useful(*y))
+                            .map(|(y, _)| *y)
+                            .collect()
+                    });
+                    if same_position[u] {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        let mut outputs = BTreeSet::new();
+        if !useful(node(unit.start, 0)) {
+            return Some(outputs);
+        }
+
+        let mut visited: BTreeSet<(usize, Vec<O>)> = BTreeSet::new();
+        let mut pending = vec![(node(unit.start, 0), Vec::new())];
+        while let Some((u, output)) = pending.pop() {
+            if !visited.insert((u, output.clone())) {
+                continue;
+            }
+            if u % (n + 1) == n && unit.states[u / (n + 1)].1 {
+                outputs.insert(output.clone());
+            }
+            for (v, symbol) in &forward[u] {
+                if useful(*v) {
+                    let mut next = output.clone();
+                    next.extend(symbol.iter().cloned());
+                    pending.push((*v, next));
+                }
+            }
+        }
+
+        Some(outputs)
+    }
+
+    /**
+     Compone este transductor con otro: `(T1 ∘ T2)(x) = T2(T1(x))`.
+        # Arguments
+        * `other` - Transductor cuya entrada es la salida de este.
+        # Returns
+        Retorna un transductor de la entrada de este a la salida de `other`.
+    */
+    pub fn compose<P: Symbol>(&self, other: &NFST<O, P>) -> NFST<I, P> {
+        let left = self.unit();
+        let right = other.unit();
+
+        let start = (left.start, right.start);
+        let mut pairs = vec![start];
+        let mut index: HashMap<(usize, usize), usize> = HashMap::from([(start, 0)]);
+        let mut edges: Vec<Vec<UnitEdge<I, P>>> = vec![Vec::new()];
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            let (p, q) = pairs[current];
+            let mut targets = Vec::new();
+
+            for (a, b, p_next) in &left.edges[p] {
+                match b {
+                    // El primero escribe ε: el segundo no se mueve
+                    None => targets.push((a.clone(), None, (*p_next, q))),
+                    Some(b) => {
+                        for (b2, c, q_next) in &right.edges[q] {
+                            if b2.as_ref() == Some(b) {
+                                targets.push((a.clone(), c.clone(), (*p_next, *q_next)));
+                            }
+                        }
+                    }
+                }
+            }
+            // El segundo avanza sin leer: el primero no se mueve
+            for (b, c, q_next) in &right.edges[q] {
+                if b.is_none() {
+                    targets.push((None, c.clone(), (p, *q_next)));
+                }
+            }
+
+            for (a, c, pair) in targets {
+                let next = *index.entry(pair).or_insert_with(|| {
+                    pairs.push(pair);
+                    edges.push(Vec::new());
+                    queue.push_back(pairs.len() - 1);
+                    pairs.len() - 1
+                });
+                edges[current].push((a, c, next));
+            }
+        }
+
+        let states = pairs
+            .iter()
+            .map(|&(p, q)| {
+                (
+                    format!("({}, {})", left.states[p].0, right.states[q].0),
+                    left.states[p].1 && right.states[q].1,
+                )
+            })
+            .collect();
+
+        Unit {
+            states,
+            start: 0,
+            edges,
+        }
+        .into_nfst()
+    }
+
+    // Intercambia entrada y salida en cada transición.
+    pub fn invert(&self) -> NFST<O, I> {
+        let table = self.nfa.transition_table();
+        let edges = table.into_iter().enumerate().flat_map(|(from, row)| {
+            row.into_iter().flat_map(move |(label, targets)| {
+                let label = Label::new(label.output, label.input);
+                targets
+                    .into_iter()
+                    .map(move |to| (from, Some(label.clone()), to))
+            })
+        });
+        let epsilon = self.nfa.epsilon_table().into_iter().enumerate();
+        let edges = edges.chain(
+            epsilon.flat_map(|(from, targets)| targets.into_iter().map(move |to| (from, None, to))),
+        );
+
+        NFST {
+            nfa: NFA::from_table(self.state_list(), self.nfa.start_index(), edges),
+        }
+    }
+
+    // Autómata que acepta las entradas que el transductor puede leer.
+    pub fn input_projection(&self) -> NFA<I> {
+        let unit = self.unit();
+        let edges = unit.edges.iter().enumerate().flat_map(|(from, edges)| {
+            edges
+                .iter()
+                .map(move |(input, _, to)| (from, input.clone(), *to))
+        });
+        NFA::from_table(unit.states.clone(), unit.start, edges)
+    }
+
+    // Autómata que acepta las salidas que el transductor puede producir.
+    pub fn output_projection(&self) -> NFA<O> {
+        let unit = self.unit();
+        let edges = unit.edges.iter().enumerate().flat_map(|(from, edges)| {
+            edges
+                .iter()
+                .map(move |(_, output, to)| (from, output.clone(), *to))
+        });
+        NFA::from_table(unit.states.clone(), unit.start, edges)
+    }
+
+    /**
+     Aplica el transductor a un lenguaje regular.
+        # Arguments
+        * `nfa` - Autómata que describe el lenguaje de entrada.
+        # Returns
+        Retorna un autómata que acepta la imagen del lenguaje bajo el transductor.
+    */
+    pub fn apply(&self, nfa: &NFA<I>) -> NFA<O> {
+        let unit = self.unit();
+        let table = nfa.transition_table();
+        let epsilon = nfa.epsilon_table();
+
+        let start = (nfa.start_index(), unit.start);
+        let mut pairs = vec![start];
+        let mut index: HashMap<(usize, usize), usize> = HashMap::from([(start, 0)]);
+        let mut edges: Vec<(usize, Option<O>, usize)> = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            let (p, q) = pairs[current];
+            let mut targets: Vec<(Option<O>, (usize, usize))> = Vec::new();
+
+            for (a, b, q_next) in &unit.edges[q] {
+                match a {
+                    None => targets.push((b.clone(), (p, *q_next))),
+                    Some(a) => {
+                        for &p_next in table[p].get(a).into_iter().flatten() {
+                            targets.push((b.clone(), (p_next, *q_next)));
+                        }
+                    }
+                }
+            }
+            for &p_next in &epsilon[p] {
+                targets.push((None, (p_next, q)));
+            }
+
+            for (symbol, pair) in targets {
+                let next = *index.entry(pair).or_insert_with(|| {
+                    pairs.push(pair);
+                    queue.push_back(pairs.len() - 1);
+                    pairs.len() - 1
+                });
+                edges.push((current, symbol, next));
+            }
+        }
+
+        let states = pairs
+            .iter()
+            .map(|&(p, q)| {
+                let p = nfa.states[p].borrow();
+                (
+                    format!("({}, {})", p.state, unit.states[q].0),
+                    p.is_accept && unit.states[q].1,
+                )
+            })
+            .collect();
+
+        NFA::from_table(states, 0, edges)
+    }
+
+    fn state_list(&self) -> Vec<(String, bool)> {
+        self.nfa
+            .states
+            .iter()
+            .map(|state| (state.borrow().state.clone(), state.borrow().is_accept))
+            .collect()
+    }
+
+    // Divide las etiquetas largas en transiciones de a lo más un símbolo por lado.
+    fn unit(&self) -> Unit<I, O> {
+        let mut states = self.state_list();
+        let mut edges: Vec<Vec<UnitEdge<I, O>>> = vec![Vec::new(); states.len()];
+
+        for (from, row) in self.nfa.transition_table().into_iter().enumerate() {
+            for (label, targets) in row {
+                let steps = label.input.len().max(label.output.len()).max(1);
+
+                for to in targets {
+                    let mut current = from;
+                    for step in 0..steps {
+                        let next = if step + 1 == steps {
+                            to
+                        } else {
+                            states.push((format!("{}·{}", states[from].0, states.len()), false));
+                            edges.push(Vec::new());
+                            states.len() - 1
+                        };
+                        edges[current].push((
+                            label.input.get(step).cloned(),
+                            label.output.get(step).cloned(),
+                            next,
+                        ));
+                        current = next;
+                    }
+                }
+            }
+        }
+        // Las transiciones ε del autómata no leen ni escriben
+        for (from, targets) in self.nfa.epsilon_table().into_iter().enumerate() {
+            edges[from].extend(targets.into_iter().map(|to| (None, None, to)));
+        }
+
+        Unit {
+            states,
+            start: self.nfa.start_index(),
+            edges,
+        }
+    }
+}
+
+impl<I: Symbol, O: Symbol> Unit<I, O> {
+    fn into_nfst(self) -> NFST<I, O> {
+        let edges = self
+            .edges
+            .into_iter()
+            .enumerate()
+            .flat_map(|(from, edges)| {
+                edges.into_iter().map(move |(input, output, to)| {
+                    let label =
+                        Label::new(input.into_iter().collect(), output.into_iter().collect());
+                    (from, Some(label), to)
+                })
+            });
+        NFST {
+            nfa: NFA::from_table(self.states, self.start, edges),
+        }
+    }
+}
+
+impl NFST<char, char> {
+    /**
+     Crea un transductor desde el formato de descripción.
+        Las transiciones se escriben `(q0, entrada:salida)->{q1, q2}`, donde cada lado es
+        una cadena de caracteres y `ε` (o nada) representa la cadena vacía.
+        # Arguments
+        * `description` - Texto con la descripción del transductor.
+        # Returns
+        Retorna el transductor o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut states = StateNames::default();
+        let mut accept: Vec<bool> = Vec::new();
+        let mut start_state = None;
+        let mut edges = Vec::new();
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+
+            match classify(line.trim()).map_err(error)? {
+                Line::Empty | Line::Alphabet(_) => {}
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        if states.declare(name).is_some() {
+                            accept.push(false);
+                        }
+                    }
+                }
+                Line::Start(name) => {
+                    start_state = Some(states.position(name).map_err(with_line)?);
+                }
+                Line::Final(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        accept[states.position(name).map_err(with_line)?] = true;
+                    }
+                }
+                Line::Transition(left, right) => {
+                    let (state, label) = left.split_once(',').ok_or_else(|| {
+                        error("Debe haber un estado y una etiqueta separados por coma.".to_string())
+                    })?;
+                    let (input, output) = label.trim().split_once(':').ok_or_else(|| {
+                        error(format!(
+                            "La etiqueta \"{}\" debe tener la forma \"entrada:salida\".",
+                            label.trim()
+                        ))
+                    })?;
+                    let label = Label::new(parse_word(input), parse_word(output));
+                    let from = states.position(state.trim()).map_err(with_line)?;
+
+                    for target in parse_set(right).map_err(error)? {
+                        let to = states.position(target).map_err(with_line)?;
+                        edges.push((from, Some(label.clone()), to));
+                    }
+                }
+            }
+        }
+
+        if states.is_empty() {
+            return Err(ParseError::new(0, "El autómata no tiene estados."));
+        }
+        let start = start_state.ok_or_else(missing_start)?;
+        let states = states.iter().cloned().zip(accept).collect();
+
+        Ok(NFST {
+            nfa: NFA::from_table(states, start, edges),
+        })
+    }
+}
+
+// Interpreta un lado de la etiqueta, donde `ε` o el texto vacío es la cadena vacía.
+fn parse_word(text: &str) -> Vec<char> {
+    match text.trim() {
+        "ε" => Vec::new(),
+        word => word.chars().collect(),
+    }
+}
+
+// Recorre el grafo desde `sources` y marca los nodos alcanzados.
+fn search<F, S>(total: usize, sources: S, next: F) -> Vec<bool>
+where
+    F: Fn(usize) -> Vec<usize>,
+    S: IntoIterator<Item = usize>,
+{
+    let mut seen = vec![false; total];
+    let mut pending: Vec<usize> = sources.into_iter().collect();
+
+    while let Some(u) = pending.pop() {
+        if !seen[u] {
+            seen[u] = true;
+            pending.extend(next(u));
+        }
+    }
+
+    seen
+}
@@ -0,0 +1,217 @@
+//! Generación de autómatas aleatorios reproducibles, para ejercicios y pruebas.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::nfa::NFA;
+use crate::random::Rng;
+
+// Opciones para `generate`.
+#[derive(Clone, Debug)]
+pub struct GeneratorOptions {
+    pub states: usize,
+    pub alphabet: Vec<char>,
+    // En un NFA, probabilidad de cada transición posible `(q, a, p)`; en un DFA, de que `δ(q, a)` esté definida.
+    pub density: f64,
+    // Probabilidad de que cada estado sea de aceptación.
+    pub accept_ratio: f64,
+    pub deterministic: bool,
+    // Todas las transiciones `δ(q, a)` están definidas (solo con `deterministic`).
+    pub complete: bool,
+    // Todos los estados son alcanzables desde el inicial.
+    pub connected: bool,
+    // Desde todos los estados se puede llegar a uno de aceptación.
+    pub trim: bool,
+    // El autómata es un DFA mínimo (solo con `deterministic`).
+    pub minimal: bool,
+    // El lenguaje aceptado no es vacío.
+    pub nonempty: bool,
+    // Intentos antes de rendirse cuando las garantías no se cumplen.
+    pub max_attempts: usize,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        GeneratorOptions {
+            states: 4,
+            alphabet: vec!['a', 'b'],
+            density: 0.3,
+            accept_ratio: 0.3,
+            deterministic: false,
+            complete: false,
+            connected: true,
+            trim: true,
+            minimal: false,
+            nonempty: true,
+            max_attempts: 10_000,
+        }
+    }
+}
+
+/**
+ Genera un autómata aleatorio con estados `q0`, `q1`, ... y `q0` como inicial.
+    Si se pide que sea conexo, primero se arma un árbol desde `q0` que alcanza todos los
+    estados y luego se agregan transiciones al azar según la densidad. Las demás garantías
+    se verifican y, si no se cumplen, se vuelve a intentar con la misma secuencia aleatoria,
+    por lo que la misma semilla siempre produce el mismo autómata.
+    # Arguments
+    * `rng` - Generador pseudoaleatorio con semilla.
+    * `options` - Tamaño, densidad y garantías pedidas.
+    # Returns
+    Retorna el autómata, o un error si las opciones no son válidas o no se encontró un
+    autómata que cumpla las garantías en `max_attempts` intentos.
+*/
+pub fn generate(rng: &mut Rng, options: &GeneratorOptions) -> Result<NFA<char>, String> {
+    let alphabet: BTreeSet<char> = options.alphabet.iter().copied().collect();
+    if options.states == 0 {
+        return Err("El autómata debe tener al menos un estado.".to_string());
+    }
+    if alphabet.is_empty() {
+        return Err("El alfabeto no puede estar vacío.".to_string());
+    }
+    if alphabet.contains(&'ε') || alphabet.iter().any(|c| ",{}() ".contains(*c)) {
+        return Err("El alfabeto contiene símbolos que no se pueden describir.".to_string());
+    }
+    if !(0.0..=1.0).contains(&options.density) || !(0.0..=1.0).contains(&options.accept_ratio) {
+        return Err(
+            "La densidad y la proporción de aceptación deben estar entre 0 y 1.".to_string(),
+        );
+    }
+    if (options.minimal || options.complete) && !options.deterministic {
+        return Err(
+            "Las garantías de completitud y minimalidad solo aplican a autómatas deterministas."
+                .to_string(),
+        );
+    }
+
+    let symbols: Vec<char> = alphabet.iter().copied().collect();
+    for _ in 0..options.max_attempts {
+        let (edges, accept) = attempt(rng, options, symbols.len());
+        if satisfies(options, &edges, &accept) {
+            let states = accept
+                .iter()
+                .enumerate()
+                .map(|(index, &is_accept)| (format!("q{}", index), is_accept))
+                .collect();
+            let edges = edges
+                .iter()
+                .map(|&(from, symbol, to)| (from, Some(symbols[symbol]), to));
+            let mut nfa = NFA::from_table(states, 0, edges);
+            nfa.alphabet = alphabet.clone();
+
+            if options.minimal && !is_minimal(&nfa) {
+                continue;
+            }
+            return Ok(nfa);
+        }
+    }
+
+    Err(format!(
+        "No se encontró un autómata que cumpla las garantías en {} intentos.",
+        options.max_attempts
+    ))
+}
+
+// Sortea las transiciones `(origen, símbolo, destino)` y los estados de aceptación.
+fn attempt(
+    rng: &mut Rng,
+    options: &GeneratorOptions,
+    symbols: usize,
+) -> (BTreeSet<(usize, usize, usize)>, Vec<bool>) {
+    let n = options.states;
+    let mut edges = BTreeSet::new();
+    // En un DFA cada par (estado, símbolo) admite un solo destino
+    let mut used = vec![vec![false; symbols]; n];
+
+    if options.connected {
+        for state in 1..n {
+            let (parent, symbol) = if options.deterministic {
+                let free: Vec<(usize, usize)> = (0..state)
+                    .flat_map(|parent| (0..symbols).map(move |symbol| (parent, symbol)))
+                    .filter(|&(parent, symbol)| !used[parent][symbol])
+                    .collect();
+                free[rng.below(free.len())]
+            } else {
+                (rng.below(state), rng.below(symbols))
+            };
+            used[parent][symbol] = true;
+            edges.insert((parent, symbol, state));
+        }
+    }
+
+    for (from, defined) in used.iter_mut().enumerate() {
+        for (symbol, defined) in defined.iter_mut().enumerate() {
+            if options.deterministic {
+                if !*defined && (options.complete || rng.chance(options.density)) {
+                    *defined = true;
+                    edges.insert((from, symbol, rng.below(n)));
+                }
+            } else {
+                for to in 0..n {
+                    if rng.chance(options.density) {
+                        edges.insert((from, symbol, to));
+                    }
+                }
+            }
+        }
+    }
+
+    let accept = (0..n).map(|_| rng.chance(options.accept_ratio)).collect();
+    (edges, accept)
+}
+
+// Verifica las garantías que dependen solo del grafo de transiciones.
+fn satisfies(
+    options: &GeneratorOptions,
+    edges: &BTreeSet<(usize, usize, usize)>,
+    accept: &[bool],
+) -> bool {
+    let n = accept.len();
+    let forward = reach(n, [0], edges.iter().map(|&(from, _, to)| (from, to)));
+    let backward = reach(
+        n,
+        (0..n).filter(|&state| accept[state]),
+        edges.iter().map(|&(from, _, to)| (to, from)),
+    );
+
+    (!options.connected || forward.iter().all(|&r| r))
+        && (!options.trim || backward.iter().all(|&r| r))
+        && (!options.nonempty || (0..n).any(|state| forward[state] && accept[state]))
+}
+
+// Estados alcanzables desde `start` siguiendo las aristas `(origen, destino)`.
+fn reach<I, E>(n: usize, start: I, edges: E) -> Vec<bool>
+where
+    I: IntoIterator<Item = usize>,
+    E: Iterator<Item = (usize, usize)>,
+{
+    let mut adjacency = vec![Vec::new(); n];
+    for (from, to) in edges {
+        adjacency[from].push(to);
+    }
+
+    let mut reached = vec![false; n];
+    let mut queue: VecDeque<usize> = start.into_iter().collect();
+    for &state in &queue {
+        reached[state] = true;
+    }
+    while let Some(state) = queue.pop_front() {
+        for &next in &adjacency[state] {
+            if !reached[next] {
+                reached[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    reached
+}
+
+// Un DFA es mínimo si minimizarlo no reduce sus estados; si es parcial, el mínimo agrega un sumidero.
+fn is_minimal(dfa: &NFA<char>) -> bool {
+    let partial = dfa.states.iter().any(|state| {
+        let state = state.borrow();
+        dfa.alphabet
+            .iter()
+            .any(|symbol| state.transitions.get(symbol).is_none_or(Vec::is_empty))
+    });
+    dfa.minimize().states.len() == dfa.states.len() + usize::from(partial)
+}
@@ -0,0 +1,437 @@
+//! Corrección automática de un autómata entregado comparándolo con uno de referencia.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fmt;
+
+use crate::batch::display_word;
+use crate::nfa::NFA;
+
+// Puntaje máximo de una entrega que no es equivalente, aunque coincida en todas las palabras cortas.
+pub const MAX_NON_EQUIVALENT_SCORE: f64 = 99.0;
+
+// Opciones de la corrección.
+#[derive(Clone, Debug)]
+pub struct GradingOptions {
+    // Exige que la entrega no tenga transiciones ε ni varios destinos por símbolo.
+    pub require_deterministic: bool,
+    // Exige además que cada estado tenga una transición por cada símbolo del alfabeto.
+    pub require_complete: bool,
+    // Largo máximo de las palabras usadas para el puntaje parcial.
+    pub max_length: usize,
+    // Cantidad máxima de contraejemplos de cada tipo en el reporte.
+    pub max_counterexamples: usize,
+    // Fracción del puntaje que se descuenta si no se cumplen los requisitos de determinismo.
+    pub determinism_penalty: f64,
+}
+
+impl Default for GradingOptions {
+    fn default() -> Self {
+        GradingOptions {
+            require_deterministic: false,
+            require_complete: false,
+            max_length: 8,
+            max_counterexamples: 5,
+            determinism_penalty: 0.25,
+        }
+    }
+}
+
+// Resultado de corregir una entrega.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GradeReport {
+    // Error de sintaxis de la entrega; si existe el resto del reporte queda vacío.
+    pub syntax_error: Option<String>,
+    // Incumplimientos de los requisitos de determinismo, como `δ(q0, a) tiene 2 destinos`.
+    pub determinism_errors: Vec<String>,
+    pub equivalent: bool,
+    // Palabras que la entrega acepta y la referencia no, de menor a mayor largo.
+    pub wrongly_accepted: Vec<String>,
+    // Palabras que la referencia acepta y la entrega no, de menor a mayor largo.
+    pub wrongly_rejected: Vec<String>,
+    pub max_length: usize,
+    // Fracción de las palabras de largo a lo más `max_length` en que ambos coinciden.
+    pub agreement: f64,
+    // Puntaje entre 0 y 100; solo las entregas equivalentes llegan a 100.
+    pub score: f64,
+}
+
+/**
+ Corrige una entrega escrita en el formato de `NFA::try_from_string`.
+    # Arguments
+    * `reference` - El autómata de referencia (puede venir de `NFA::from_regex`).
+    * `submission` - El texto entregado.
+    * `options` - Requisitos y límites de la corrección.
+    # Returns
+    Retorna el reporte con los contraejemplos y el puntaje.
+*/
+pub fn grade(reference: &NFA<char>, submission: &str, options: &GradingOptions) -> GradeReport {
+    let submission = match NFA::try_from_string(submission) {
+        Ok(submission) => submission,
+        Err(error) => {
+            return GradeReport {
+                syntax_error: Some(error.to_string()),
+                determinism_errors: Vec::new(),
+                equivalent: false,
+                wrongly_accepted: Vec::new(),
+                wrongly_rejected: Vec::new(),
+                max_length: options.max_length,
+                agreement: 0.0,
+                score: 0.0,
+            }
+        }
+    };
+    grade_nfa(reference, &submission, options)
+}
+
+/**
+ Corrige una entrega ya construida.
+    # Arguments
+    * `reference` - El autómata de referencia.
+    * `submission` - El autómata entregado.
+    * `options` - Requisitos y límites de la corrección.
+    # Returns
+    Retorna el reporte con los contraejemplos y el puntaje.
+*/
+pub fn grade_nfa(
+    reference: &NFA<char>,
+    submission: &NFA<char>,
+    options: &GradingOptions,
+) -> GradeReport {
+    let alphabet: Vec<char> = reference
+        .alphabet
+        .union(&submission.alphabet)
+        .copied()
+        .collect();
+    let determinism_errors = determinism_errors(submission, &alphabet, options);
+    let product = Product::new(reference, submission, &alphabet);
+
+    let mut wrongly_accepted =
+        product.counterexamples(options.max_length, options.max_counterexamples, |a, b| {
+            !a && b
+        });
+    let mut wrongly_rejected =
+        product.counterexamples(options.max_length, options.max_counterexamples, |a, b| {
+            a && !b
+        });
+
+    // Si las diferencias son más largas que `max_length` igual se informa la más corta
+    let shortest = product.shortest_difference();
+    if let Some((word, reference_accepts)) = &shortest {
+        if wrongly_accepted.is_empty() && wrongly_rejected.is_empty() {
+            if *reference_accepts {
+                wrongly_rejected.push(word.clone());
+            } else {
+                wrongly_accepted.push(word.clone());
+            }
+        }
+    }
+
+    let agreement = product.agreement(options.max_length);
+    let mut score = agreement * 100.0;
+    if shortest.is_some() {
+        score = score.min(MAX_NON_EQUIVALENT_SCORE);
+    }
+    if !determinism_errors.is_empty() {
+        score *= 1.0 - options.determinism_penalty;
+    }
+
+    GradeReport {
+        syntax_error: None,
+        determinism_errors,
+        equivalent: shortest.is_none(),
+        wrongly_accepted,
+        wrongly_rejected,
+        max_length: options.max_length,
+        agreement,
+        score,
+    }
+}
+
+// Lista los incumplimientos de determinismo de la entrega según las opciones.
+fn determinism_errors(nfa: &NFA<char>, alphabet: &[char], options: &GradingOptions) -> Vec<String> {
+    let mut errors = Vec::new();
+    if !options.require_deterministic && !options.require_complete {
+        return errors;
+    }
+
+    for state in &nfa.states {
+        let state = state.borrow();
+        if options.require_deterministic {
+            if !state.epsilon.is_empty() {
+                errors.push(format!("δ({}, ε) está definida.", state.state));
+            }
+            for (symbol, targets) in &state.transitions {
+                if targets.len() > 1 {
+                    errors.push(format!(
+                        "δ({}, {}) tiene {} destinos.",
+                        state.state,
+                        symbol,
+                        targets.len()
+                    ));
+                }
+            }
+        }
+        if options.require_complete {
+            for symbol in alphabet {
+                if state.transitions.get(symbol).is_none_or(Vec::is_empty) {
+                    errors.push(format!("δ({}, {}) no está definida.", state.state, symbol));
+                }
+            }
+        }
+    }
+    errors
+}
+
+// Producto de los autómatas determinizados por subconjuntos, limitado a los estados alcanzables.
+struct Product {
+    alphabet: Vec<char>,
+    // Si cada estado acepta en la referencia y en la entrega
+    accept: Vec<(bool, bool)>,
+    // Sucesor de cada estado por cada símbolo, en el orden de `alphabet`
+    next: Vec<Vec<usize>>,
+}
+
+impl Product {
+    fn new(reference: &NFA<char>, submission: &NFA<char>, alphabet: &[char]) -> Self {
+        let sides = [reference, submission].map(NFA::runner);
+        let step =
+            |side: usize, subset: &BTreeSet<usize>, symbol: char| sides[side].step(subset, &symbol);
+        let accepts = |side: usize, subset: &BTreeSet<usize>| sides[side].accepts(subset);
+
+        let start = (sides[0].start().clone(), sides[1].start().clone());
+        let mut pairs = vec![start.clone()];
+        let mut index = HashMap::from([(start, 0)]);
+        let mut next = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            let mut row = Vec::with_capacity(alphabet.len());
+            for &symbol in alphabet {
+                let pair = (
+                    step(0, &pairs[current].0, symbol),
+                    step(1, &pairs[current].1, symbol),
+                );
+                let target = *index.entry(pair.clone()).or_insert_with(|| {
+                    pairs.push(pair);
+                    queue.push_back(pairs.len() - 1);
+                    pairs.len() - 1
+                });
+                row.push(target);
+            }
+            // La cola recorre los estados en orden, así `next[current]` queda en su posición
+            next.push(row);
+        }
+
+        Product {
+            alphabet: alphabet.to_vec(),
+            accept: pairs
+                .iter()
+                .map(|(a, b)| (accepts(0, a), accepts(1, b)))
+                .collect(),
+            next,
+        }
+    }
+
+    // Palabra más corta en que difieren (primera en orden lexicográfico) y si la referencia la acepta.
+    fn shortest_difference(&self) -> Option<(String, bool)> {
+        let mut parent: Vec<Option<(usize, char)>> = vec![None; self.accept.len()];
+        let mut seen = vec![false; self.accept.len()];
+        seen[0] = true;
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            let (a, b) = self.accept[current];
+            if a != b {
+                let mut word = Vec::new();
+                let mut state = current;
+                while let Some((previous, symbol)) = parent[state] {
+                    word.push(symbol);
+                    state = previous;
+                }
+                return Some((word.iter().rev().collect(), a));
+            }
+            for (&symbol, &target) in self.alphabet.iter().zip(&self.next[current]) {
+                if !seen[target] {
+                    seen[target] = true;
+                    parent[target] = Some((current, symbol));
+                    queue.push_back(target);
+                }
+            }
+        }
+        None
+    }
+
+    /**
+     Enumera las palabras de largo a lo más `max_length` en que se cumple `differs`,
+     ordenadas por largo y luego lexicográficamente.
+        Para no recorrer todas las palabras se calcula, para cada largo restante, desde
+        qué estados todavía se puede terminar en un estado que cumpla `differs`.
+    */
+    fn counterexamples<F>(&self, max_length: usize, limit: usize, differs: F) -> Vec<String>
+    where
+        F: Fn(bool, bool) -> bool,
+    {
+        let count = self.accept.len();
+        let mut can_finish = vec![self
+            .accept
+            .iter()
+            .map(|&(a, b)| differs(a, b))
+            .collect::<Vec<bool>>()];
+        for remaining in 1..=max_length {
+            let previous = &can_finish[remaining - 1];
+            let row = (0..count)
+                .map(|state| self.next[state].iter().any(|&t| previous[t]))
+                .collect();
+            can_finish.push(row);
+        }
+
+        let mut words = Vec::new();
+        for length in 0..=max_length {
+            let mut word = Vec::with_capacity(length);
+            self.collect(0, length, &can_finish, &mut word, &mut words, limit);
+            if words.len() >= limit {
+                break;
+            }
+        }
+        words
+    }
+
+    // Recorre en profundidad las palabras de largo `remaining` que terminan en un estado válido.
+    fn collect(
+        &self,
+        state: usize,
+        remaining: usize,
+        can_finish: &[Vec<bool>],
+        word: &mut Vec<char>,
+        words: &mut Vec<String>,
+        limit: usize,
+    ) {
+        if words.len() >= limit || !can_finish[remaining][state] {
+            return;
+        }
+        if remaining == 0 {
+            words.push(word.iter().collect());
+            return;
+        }
+        for (&symbol, &target) in self.alphabet.iter().zip(&self.next[state]) {
+            word.push(symbol);
+            self.collect(target, remaining - 1, can_finish, word, words, limit);
+            word.pop();
+        }
+    }
+
+    // Fracción de las palabras de largo a lo más `max_length` en que ambos autómatas coinciden.
+    fn agreement(&self, max_length: usize) -> f64 {
+        // Cantidad de palabras de cada largo que llevan a cada estado
+        let mut words = vec![0.0; self.accept.len()];
+        words[0] = 1.0;
+        let (mut total, mut agree) = (0.0, 0.0);
+
+        for length in 0..=max_length {
+            for (state, &amount) in words.iter().enumerate() {
+                let (a, b) = self.accept[state];
+                total += amount;
+                if a == b {
+                    agree += amount;
+                }
+            }
+            if length == max_length {
+                break;
+            }
+            let mut next_words = vec![0.0; self.accept.len()];
+            for (state, &amount) in words.iter().enumerate() {
+                for &target in &self.next[state] {
+                    next_words[target] += amount;
+                }
+            }
+            words = next_words;
+        }
+
+        agree / total
+    }
+}
+
+impl GradeReport {
+    // Exporta el reporte como JSON para importarlo en el LMS.
+    pub fn to_json(&self) -> String {
+        let list = |items: &[String]| {
+            let items: Vec<String> = items.iter().map(|item| json_string(item)).collect();
+            format!("[{}]", items.join(", "))
+        };
+        let syntax_error = match &self.syntax_error {
+            Some(error) => json_string(error),
+            None => "null".to_string(),
+        };
+
+        format!(
+            concat!(
+                "{{\n",
+                "  \"syntax_error\": {},\n",
+                "  \"determinism_errors\": {},\n",
+                "  \"equivalent\": {},\n",
+                "  \"wrongly_accepted\": {},\n",
+                "  \"wrongly_rejected\": {},\n",
+                "  \"max_length\": {},\n",
+                "  \"agreement\": {:.4},\n",
+                "  \"score\": {:.2}\n",
+                "}}\n"
+            ),
+            syntax_error,
+            list(&self.determinism_errors),
+            self.equivalent,
+            list(&self.wrongly_accepted),
+            list(&self.wrongly_rejected),
+            self.max_length,
+            self.agreement,
+            self.score
+        )
+    }
+}
+
+impl fmt::Display for GradeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(error) = &self.syntax_error {
+            writeln!(f, "Error de sintaxis: {}", error)?;
+            return write!(f, "Puntaje: 0.00");
+        }
+
+        for error in &self.determinism_errors {
+            writeln!(f, "Determinismo: {}", error)?;
+        }
+        if self.equivalent {
+            writeln!(f, "El autómata es equivalente a la referencia.")?;
+        } else {
+            writeln!(f, "El autómata no es equivalente a la referencia.")?;
+        }
+        for word in &self.wrongly_accepted {
+            writeln!(f, "  acepta y no debería: {}", display_word(word))?;
+        }
+        for word in &self.wrongly_rejected {
+            writeln!(f, "  rechaza y no debería: {}", display_word(word))?;
+        }
+        writeln!(
+            f,
+            "Coincidencia en palabras de largo ≤ {}: {:.2}%",
+            self.max_length,
+            self.agreement * 100.0
+        )?;
+        write!(f, "Puntaje: {:.2}", self.score)
+    }
+}
+
+// Escribe un texto como cadena JSON.
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
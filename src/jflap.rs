@@ -0,0 +1,464 @@
+//! Lectura y escritura de autómatas finitos en el formato XML de JFLAP (`.jff`).
+
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use crate::error::ParseError;
+use crate::nfa::NFA;
+use crate::xml::escape;
+
+/**
+ Autómata leído desde JFLAP junto con la posición de cada estado en el lienzo.
+    Las posiciones se indexan por el nombre del estado.
+*/
+pub struct JflapAutomaton {
+    pub nfa: NFA<char>,
+    pub positions: BTreeMap<String, (f64, f64)>,
+}
+
+impl JflapAutomaton {
+    /**
+     Interpreta un archivo `.jff` de un autómata finito.
+        Las transiciones con `<read/>` vacío son transiciones ε. Si un estado no tiene
+        atributo `name` se usa `q` seguido de su `id`.
+        # Arguments
+        * `text` - Contenido del archivo.
+        # Returns
+        Retorna el autómata o un error con la línea del XML donde se encontró.
+    */
+    pub fn from_jff(text: &str) -> Result<Self, ParseError> {
+        let root = XmlParser::new(text).document()?;
+        if root.name != "structure" {
+            return Err(ParseError::new(
+                root.line,
+                format!("Se esperaba <structure> y se encontró <{}>.", root.name),
+            ));
+        }
+
+        let kind = root.child("type").map(|e| e.text.trim()).unwrap_or("fa");
+        if kind != "fa" {
+            return Err(ParseError::new(
+                root.line,
+                format!("Solo se admiten autómatas finitos (fa), no \"{}\".", kind),
+            ));
+        }
+        // JFLAP 6 guarda los estados directamente en <structure>, JFLAP 7 dentro de <automaton>
+        let automaton = root.child("automaton").unwrap_or(&root);
+
+        let mut states = Vec::new();
+        let mut positions = BTreeMap::new();
+        let mut ids = HashMap::new();
+        let mut start = None;
+
+        for state in automaton.children_named("state") {
+            let id = state.attribute("id").ok_or_else(|| {
+                ParseError::new(state.line, "El estado no tiene atributo \"id\".")
+            })?;
+            let name = state
+                .attribute("name")
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("q{}", id));
+            if ids.insert(id.to_string(), states.len()).is_some() {
+                return Err(ParseError::new(
+                    state.line,
+                    format!("El id \"{}\" está repetido.", id),
+                ));
+            }
+            if states.iter().any(|(existing, _)| *existing == name) {
+                return Err(ParseError::new(
+                    state.line,
+                    format!("El estado \"{}\" está repetido.", name),
+                ));
+            }
+
+            if state.child("initial").is_some() {
+                if start.is_some() {
+                    return Err(ParseError::new(
+                        state.line,
+                        "El autómata tiene más de un estado inicial.",
+                    ));
+                }
+                start = Some(states.len());
+            }
+            if let (Some(x), Some(y)) = (state.child("x"), state.child("y")) {
+                let coordinate = |e: &Element| {
+                    e.text.trim().parse::<f64>().map_err(|_| {
+                        ParseError::new(e.line, format!("Coordenada inválida \"{}\".", e.text))
+                    })
+                };
+                positions.insert(name.clone(), (coordinate(x)?, coordinate(y)?));
+            }
+            states.push((name, state.child("final").is_some()));
+        }
+
+        if states.is_empty() {
+            return Err(ParseError::new(root.line, "El autómata no tiene estados."));
+        }
+        let start = start
+            .ok_or_else(|| ParseError::new(root.line, "El autómata no tiene estado inicial."))?;
+
+        let mut edges = Vec::new();
+        for transition in automaton.children_named("transition") {
+            let endpoint = |tag: &str| {
+                let element = transition.child(tag).ok_or_else(|| {
+                    ParseError::new(
+                        transition.line,
+                        format!("Falta <{}> en la transición.", tag),
+                    )
+                })?;
+                ids.get(element.text.trim()).copied().ok_or_else(|| {
+                    ParseError::new(
+                        element.line,
+                        format!("El estado con id \"{}\" no existe.", element.text.trim()),
+                    )
+                })
+            };
+            let from = endpoint("from")?;
+            let to = endpoint("to")?;
+
+            let read = transition
+                .child("read")
+                .map(|e| e.text.as_str())
+                .unwrap_or("");
+            let mut chars = read.chars();
+            let symbol = match (chars.next(), chars.next()) {
+                (None, _) => None,
+                (Some(c), None) => Some(c),
+                _ => {
+                    return Err(ParseError::new(
+                        transition.line,
+                        format!(
+                            "La transición lee \"{}\", solo se admite un carácter.",
+                            read
+                        ),
+                    ))
+                }
+            };
+            edges.push((from, symbol, to));
+        }
+
+        Ok(JflapAutomaton {
+            nfa: NFA::from_table(states, start, edges),
+            positions,
+        })
+    }
+
+    /**
+     Escribe el autómata en formato `.jff`.
+        Los estados sin posición se ubican en un círculo.
+        # Returns
+        Retorna el XML listo para abrir en JFLAP.
+    */
+    pub fn to_jff(&self) -> String {
+        let nfa = &self.nfa;
+        let count = nfa.states.len();
+        let start = nfa.start_index();
+        let mut xml = String::new();
+
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>\n");
+        xml.push_str("<structure>\n\t<type>fa</type>\n\t<automaton>\n");
+
+        for (index, state) in nfa.states.iter().enumerate() {
+            let state = state.borrow();
+            let (x, y) = self
+                .positions
+                .get(&state.state)
+                .copied()
+                .unwrap_or_else(|| circle_position(index, count));
+            let _ = writeln!(
+                xml,
+                "\t\t<state id=\"{}\" name=\"{}\">",
+                index,
+                escape(&state.state)
+            );
+            let _ = writeln!(xml, "\t\t\t<x>{:.1}</x>\n\t\t\t<y>{:.1}</y>", x, y);
+            if index == start {
+                xml.push_str("\t\t\t<initial/>\n");
+            }
+            if state.is_accept {
+                xml.push_str("\t\t\t<final/>\n");
+            }
+            xml.push_str("\t\t</state>\n");
+        }
+
+        let epsilon = nfa.epsilon_table();
+        for (from, row) in nfa.transition_table().into_iter().enumerate() {
+            let reads = row
+                .into_iter()
+                .flat_map(|(symbol, targets)| {
+                    targets.into_iter().map(move |to| {
+                        (format!("<read>{}</read>", escape(&symbol.to_string())), to)
+                    })
+                })
+                .chain(epsilon[from].iter().map(|&to| ("<read/>".to_string(), to)));
+            for (read, to) in reads {
+                let _ = writeln!(
+                    xml,
+                    "\t\t<transition>\n\t\t\t<from>{}</from>\n\t\t\t<to>{}</to>\n\t\t\t{}\n\t\t</transition>",
+                    from, to, read
+                );
+            }
+        }
+
+        xml.push_str("\t</automaton>\n</structure>\n");
+        xml
+    }
+}
+
+impl NFA<char> {
+    // Lee un autómata desde un archivo `.jff`, descartando las posiciones.
+    pub fn from_jff(text: &str) -> Result<Self, ParseError> {
+        JflapAutomaton::from_jff(text).map(|automaton| automaton.nfa)
+    }
+
+    // Exporta el autómata a `.jff` con los estados ubicados en un círculo.
+    pub fn to_jff(&self) -> String {
+        let automaton = JflapAutomaton {
+            nfa: NFA::from_parts(
+                self.states.clone(),
+                self.alphabet.clone(),
+                self.start_state.clone(),
+            ),
+            positions: BTreeMap::new(),
+        };
+        automaton.to_jff()
+    }
+}
+
+// Posición del estado `index` de `count` estados repartidos en un círculo.
+fn circle_position(index: usize, count: usize) -> (f64, f64) {
+    let radius = 60.0 + 25.0 * count as f64;
+    let angle = 2.0 * PI * index as f64 / count.max(1) as f64 + PI;
+    (
+        radius + 50.0 + radius * angle.cos(),
+        radius + 50.0 + radius * angle.sin(),
+    )
+}
+
+// Elemento de un árbol XML simplificado: sin espacios de nombres ni contenido mixto.
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+    line: usize,
+}
+
+impl Element {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+}
+
+// Lector de XML suficiente para los archivos de JFLAP.
+struct XmlParser<'a> {
+    text: &'a str,
+    position: usize,
+    line: usize,
+}
+
+impl<'a> XmlParser<'a> {
+    fn new(text: &'a str) -> Self {
+        XmlParser {
+            text,
+            position: 0,
+            line: 1,
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, message)
+    }
+
+    // Avanza `length` bytes contando los saltos de línea.
+    fn advance(&mut self, length: usize) {
+        let skipped = &self.text[self.position..self.position + length];
+        self.line += skipped.matches('\n').count();
+        self.position += length;
+    }
+
+    // Avanza hasta después de `end`, o falla si no aparece.
+    fn skip_past(&mut self, end: &str) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let found = rest
+            .find(end)
+            .ok_or_else(|| self.error(format!("Falta \"{}\".", end)))?;
+        self.advance(found + end.len());
+        Ok(&rest[..found])
+    }
+
+    fn skip_whitespace(&mut self) {
+        let length = self.rest().len() - self.rest().trim_start().len();
+        self.advance(length);
+    }
+
+    // Salta declaraciones, comentarios y espacios entre elementos.
+    fn skip_misc(&mut self) -> Result<(), ParseError> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.rest().starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.rest().starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    // Lee el elemento raíz del documento.
+    fn document(&mut self) -> Result<Element, ParseError> {
+        self.skip_misc()?;
+        let root = self.element()?;
+        self.skip_misc()?;
+        if !self.rest().is_empty() {
+            return Err(self.error("Contenido inesperado después del elemento raíz."));
+        }
+        Ok(root)
+    }
+
+    fn element(&mut self) -> Result<Element, ParseError> {
+        if !self.rest().starts_with('<') {
+            return Err(self.error("Se esperaba un elemento XML."));
+        }
+        let line = self.line;
+        self.advance(1);
+
+        let name = self.name()?;
+        let mut element = Element {
+            name,
+            attributes: Vec::new(),
+            children: Vec::new(),
+            text: String::new(),
+            line,
+        };
+
+        // Atributos
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("/>") {
+                self.advance(2);
+                return Ok(element);
+            }
+            if self.rest().starts_with('>') {
+                self.advance(1);
+                break;
+            }
+            let key = self.name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.error(format!("Falta \"=\" en el atributo \"{}\".", key)));
+            }
+            self.advance(1);
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(self.error("El valor del atributo debe ir entre comillas.")),
+            };
+            self.advance(1);
+            let value = self.skip_past(&quote.to_string())?;
+            element.attributes.push((key, unescape(value, self.line)?));
+        }
+
+        // Contenido
+        loop {
+            let rest = self.rest();
+            if rest.starts_with("</") {
+                self.advance(2);
+                let closing = self.name()?;
+                if closing != element.name {
+                    return Err(self.error(format!(
+                        "Se esperaba </{}> y se encontró </{}>.",
+                        element.name, closing
+                    )));
+                }
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return Err(self.error("Falta \">\" al cerrar el elemento."));
+                }
+                self.advance(1);
+                return Ok(element);
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.advance(9);
+                let data = self.skip_past("]]>")?;
+                element.text.push_str(data);
+            } else if rest.starts_with('<') {
+                let child = self.element()?;
+                element.children.push(child);
+            } else if rest.is_empty() {
+                return Err(self.error(format!("Falta cerrar <{}>.", element.name)));
+            } else {
+                let length = rest.find('<').unwrap_or(rest.len());
+                let line = self.line;
+                self.advance(length);
+                element.text.push_str(&unescape(&rest[..length], line)?);
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, ParseError> {
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/' || c == '=')
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error("Se esperaba un nombre de elemento o atributo."));
+        }
+        self.advance(length);
+        Ok(rest[..length].to_string())
+    }
+}
+
+// Reemplaza las entidades de XML por los caracteres que representan.
+fn unescape(text: &str, line: usize) -> Result<String, ParseError> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(';')
+            .ok_or_else(|| ParseError::new(line, "Entidad XML sin \";\"."))?;
+        let entity = &rest[start + 1..start + end];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        result.push(c.ok_or_else(|| {
+            ParseError::new(line, format!("Entidad XML desconocida \"&{};\".", entity))
+        })?);
+        rest = &rest[start + end + 1..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
@@ -0,0 +1,199 @@
+//! Distribución automática de los estados para los exportadores gráficos.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::f64::consts::PI;
+use std::fmt::Display;
+
+use crate::nfa::{Symbol, NFA};
+
+// Algoritmo para ubicar los estados.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    // Columnas según la distancia desde el estado inicial, ordenadas para reducir cruces.
+    #[default]
+    Layered,
+    // Estados repartidos en un círculo, empezando por el inicial a la izquierda.
+    Circular,
+}
+
+/**
+ Calcula la posición de cada estado en unidades abstractas, donde dos estados vecinos
+ quedan a distancia 1. El eje `y` crece hacia abajo.
+    # Arguments
+    * `nfa` - El autómata a dibujar.
+    * `layout` - El algoritmo de distribución.
+    # Returns
+    Retorna una posición por estado, en el orden de `states()`.
+*/
+pub fn positions<S: Symbol>(nfa: &NFA<S>, layout: Layout) -> Vec<(f64, f64)> {
+    match layout {
+        Layout::Layered => {
+            let mut layers = layers(nfa);
+            reduce_crossings(nfa, &mut layers);
+            let mut positions = vec![(0.0, 0.0); nfa.states.len()];
+            for (column, layer) in layers.iter().enumerate() {
+                let offset = (layer.len() as f64 - 1.0) / 2.0;
+                for (row, &state) in layer.iter().enumerate() {
+                    positions[state] = (column as f64, row as f64 - offset);
+                }
+            }
+            positions
+        }
+        Layout::Circular => {
+            let count = nfa.states.len();
+            let start = nfa.start_index();
+            // Radio para que estados consecutivos queden a distancia 1
+            let radius = if count < 2 {
+                0.0
+            } else {
+                0.5 / (PI / count as f64).sin()
+            };
+            (0..count)
+                .map(|state| {
+                    let step = (state + count - start) % count;
+                    let angle = PI + 2.0 * PI * step as f64 / count as f64;
+                    (radius * angle.cos(), -radius * angle.sin())
+                })
+                .collect()
+        }
+    }
+}
+
+/**
+ Agrupa los estados en capas según su distancia (en transiciones) desde el inicial.
+    # Returns
+    Retorna las capas en orden; los estados inalcanzables forman una última capa.
+*/
+pub fn layers<S: Symbol>(nfa: &NFA<S>) -> Vec<Vec<usize>> {
+    let table = nfa.transition_table();
+    let epsilon = nfa.epsilon_table();
+    let start = nfa.start_index();
+
+    let mut depth = vec![None; nfa.states.len()];
+    depth[start] = Some(0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let next = depth[state].map(|d| d + 1);
+        let targets = table[state].values().flatten().chain(&epsilon[state]);
+        for &target in targets {
+            if depth[target].is_none() {
+                depth[target] = next;
+                queue.push_back(target);
+            }
+        }
+    }
+
+    let deepest = depth.iter().flatten().copied().max().unwrap_or(0);
+    let mut layers = vec![Vec::new(); deepest + 1];
+    let mut unreachable = Vec::new();
+    for (state, depth) in depth.iter().enumerate() {
+        match depth {
+            Some(depth) => layers[*depth].push(state),
+            None => unreachable.push(state),
+        }
+    }
+    if !unreachable.is_empty() {
+        layers.push(unreachable);
+    }
+    layers
+}
+
+/**
+ Reordena cada capa según el baricentro de sus vecinos en la capa adyacente
+ (heurística de Sugiyama), alternando barridos hacia adelante y hacia atrás.
+    # Arguments
+    * `nfa` - El autómata cuyas transiciones definen los vecinos.
+    * `layers` - Las capas a reordenar.
+*/
+pub fn reduce_crossings<S: Symbol>(nfa: &NFA<S>, layers: &mut [Vec<usize>]) {
+    let mut neighbours = vec![Vec::new(); nfa.states.len()];
+    for &(from, to) in merged_edges(nfa).keys() {
+        if from != to {
+            neighbours[from].push(to);
+            neighbours[to].push(from);
+        }
+    }
+
+    let mut row = vec![0.0; nfa.states.len()];
+    for layer in layers.iter() {
+        place(layer, &mut row);
+    }
+
+    for sweep in 0..4 {
+        let order: Vec<usize> = if sweep % 2 == 0 {
+            (1..layers.len()).collect()
+        } else {
+            (0..layers.len().saturating_sub(1)).rev().collect()
+        };
+        for current in order {
+            let fixed = if sweep % 2 == 0 {
+                current - 1
+            } else {
+                current + 1
+            };
+            let barycenters: HashMap<usize, f64> = layers[current]
+                .iter()
+                .map(|&state| {
+                    let adjacent: Vec<f64> = neighbours[state]
+                        .iter()
+                        .filter(|n| layers[fixed].contains(n))
+                        .map(|&n| row[n])
+                        .collect();
+                    let value = if adjacent.is_empty() {
+                        row[state]
+                    } else {
+                        adjacent.iter().sum::<f64>() / adjacent.len() as f64
+                    };
+                    (state, value)
+                })
+                .collect();
+            layers[current].sort_by(|a, b| barycenters[a].total_cmp(&barycenters[b]));
+            place(&layers[current], &mut row);
+        }
+    }
+}
+
+// Guarda en `row` la posición de cada estado dentro de su capa.
+fn place(layer: &[usize], row: &mut [f64]) {
+    for (index, &state) in layer.iter().enumerate() {
+        row[state] = index as f64;
+    }
+}
+
+/**
+ Agrupa las transiciones paralelas entre cada par de estados.
+    # Returns
+    Retorna, para cada par `(origen, destino)`, los símbolos de las transiciones en
+    orden, con `None` para ε al final.
+*/
+pub(crate) fn merged_edges<S: Symbol>(nfa: &NFA<S>) -> BTreeMap<(usize, usize), Vec<Option<S>>> {
+    let mut edges: BTreeMap<(usize, usize), Vec<Option<S>>> = BTreeMap::new();
+    let epsilon = nfa.epsilon_table();
+
+    for (from, row) in nfa.transition_table().into_iter().enumerate() {
+        for (symbol, targets) in row {
+            for to in targets {
+                edges
+                    .entry((from, to))
+                    .or_default()
+                    .push(Some(symbol.clone()));
+            }
+        }
+        for &to in &epsilon[from] {
+            edges.entry((from, to)).or_default().push(None);
+        }
+    }
+    edges
+}
+
+// Etiqueta de una arista agrupada, como `0, 1, ε`.
+pub(crate) fn edge_label<S: Display>(symbols: &[Option<S>]) -> String {
+    let labels: Vec<String> = symbols
+        .iter()
+        .map(|symbol| match symbol {
+            Some(symbol) => symbol.to_string(),
+            None => "ε".to_string(),
+        })
+        .collect();
+    labels.join(", ")
+}
@@ -0,0 +1,34 @@
+//! Autómatas finitos no deterministas y utilidades para construirlos,
+//! ejecutarlos e imprimirlos.
+
+pub mod alternating;
+pub mod batch;
+pub mod buchi;
+pub mod cli;
+mod description;
+pub mod diagram;
+pub mod error;
+pub mod fst;
+pub mod generator;
+pub mod grading;
+pub mod jflap;
+pub mod layout;
+pub mod mealy;
+pub mod nerode;
+pub mod nfa;
+pub mod operations;
+pub mod pda;
+pub mod probabilistic;
+pub mod pumping;
+pub mod random;
+pub mod regex;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod subset;
+pub mod svg;
+pub mod symbolic;
+pub mod tikz;
+pub mod turing;
+pub mod twoway;
+pub mod weighted;
+mod xml;
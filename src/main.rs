@@ -1,253 +1,38 @@
-use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
-use std::process::Command;
-use std::rc::Rc;
+use std::process::exit;
 
-struct NFA {
-    states: Vec<Rc<RefCell<Node>>>,
-    alphabet: HashSet<char>,
-    start_state: Rc<RefCell<Node>>,
-}
-
-struct Node {
-    state: String,
-    is_accept: bool,
-    transitions: HashMap<char, Vec<Rc<RefCell<Node>>>>,
-}
-
-impl Node {
-    fn new(state: &str, is_accept: bool) -> Rc<RefCell<Node>> {
-        Rc::new(RefCell::new(Node {
-            state: state.to_string(),
-            is_accept,
-            transitions: HashMap::new(),
-        }))
-    }
-
-    fn add_transition(node: &Rc<RefCell<Node>>, symbol: char, to: Rc<RefCell<Node>>) {
-        node.borrow_mut()
-            .transitions
-            .entry(symbol)
-            .or_insert(Vec::new())
-            .push(to);
-    }
-
-    fn next_states(&self, symbol: char) -> Vec<Rc<RefCell<Node>>> {
-        self.transitions
-            .get(&symbol)
-            .cloned()
-            .unwrap_or_else(Vec::new)
-    }
-}
-
-impl NFA {
-    fn from_string(nfa_string: &str) -> Self {
-        let mut alphabet = HashSet::new();
-        let mut states: Vec<Rc<RefCell<Node>>> = Vec::new();
-        let mut start_state: Rc<RefCell<Node>> = Node::new("", false);
-        let mut final_states: Vec<Rc<RefCell<Node>>> = Vec::new();
-
-        // Procesar cada línea del string
-        for line in nfa_string.lines() {
-            let line = line.trim();
-
-            // Procesar el alfabeto
-            if line.starts_with("alphabet=") {
-                let chars = line
-                    .trim_start_matches("alphabet=")
-                    .replace("{", "")
-                    .replace("}", "");
-                for ch in chars.chars() {
-                    if ch != ',' && ch != ' ' {
-                        alphabet.insert(ch);
-                    }
-                }
-            }
-            // Procesar los estados
-            else if line.starts_with("state=") {
-                let state_str = line
-                    .trim_start_matches("state=")
-                    .replace("{", "")
-                    .replace("}", "");
-                for state in state_str.split(',') {
-                    states.push(Node::new(state.trim(), false));
-                }
-            }
-            // Procesar el estado inicial
-            else if line.starts_with("start_state=") {
-                start_state = states
-                    .iter()
-                    .find(|state| state.borrow().state == line.trim_start_matches("start_state="))
-                    .unwrap()
-                    .clone();
-            }
-            // Procesar los estados finales
-            else if line.starts_with("F=") {
-                let final_state_str = line
-                    .trim_start_matches("F=")
-                    .trim()
-                    .trim_matches(|c| c == '{' || c == '}');
-
-                // Convertir los nombres de los estados finales en un vector y filtrar los que existen
-                final_states.extend(final_state_str.split(',').filter_map(|state| {
-                    states
-                        .iter()
-                        .find(|s| s.borrow().state == state.trim())
-                        .cloned()
-                }));
-
-                // Marcar los estados finales como aceptados
-                for state in &final_states {
-                    state.borrow_mut().is_accept = true;
-                }
-            }
-            // Procesar las transiciones
-            else if line.starts_with("(") {
-                create_transitions_without_cli(&states, line);
-            }
-        }
-
-        NFA {
-            alphabet,
-            states,
-            start_state,
-        }
-    }
-
-    fn new() -> NFA {
-        let alphabet = create_alphabet();
-        let states = create_states();
-        create_transitions(&states, &alphabet);
-        let start_state = define_start_states(&states);
-
-        NFA {
-            states,
-            alphabet,
-            start_state,
-        }
-    }
-
-    /**
-     Valida si la palabra ingresada es aceptada por el autómata.
-        # Arguments
-        * `input` - La palabra a analizar.
-        # Returns
-        Retorna un `bool` que puede determinar si la palabra es aceptada o no por el autómata.
-    */
-    fn run(&self, input: &str) -> bool {
-        // println!("Recorrido: {} {}", self.start_state.borrow().state, input);
-        let last_node = NFA::backtracking(self.start_state.clone(), input);
-        
-        let is_accept = last_node.0.borrow().is_accept;
-        is_accept
-    }
-
-    fn backtracking(state: Rc<RefCell<Node>>, word: &str) -> (Rc<RefCell<Node>>, &str) {
-        let first_char = word.chars().next();
-
-        // println!("Recorrido recursivo: {} {}", state.borrow().state, word);
-
-        if let Some(first_char) = first_char {
-            for next_state in state.borrow().next_states(first_char) {
-                let (state, word) = Self::backtracking(next_state, &word[1..]);
-
-                if state.borrow().is_accept {
-                    return (state, word);
-                }
-            }
-        }
-
-        return (state, word);
-    }
-
-    // Imprime el conjunto de estados
-    fn print_states(&self) {
-        print!("{{");
-        let mut first = true;
-        for state in &self.states {
-            if !first {
-                print!(", ");
-            }
-            print!("{}", state.borrow().state);
-            first = false;
-        }
-        print!("}}");
-    }
-
-    // Imprime el alfabeto
-    fn print_alphabet(&self) {
-        print!("{:?}", self.alphabet);
-    }
-
-    // Imprime el estado inicial
-    fn print_start_state(&self) {
-        print!("{}", self.start_state.borrow().state);
-    }
-
-    // Imprime el conjunto de estados de aceptación
-    fn print_accept_states(&self) {
-        print!("{{");
-        let mut first = true;
-        for state in &self.states {
-            if state.borrow().is_accept {
-                if !first {
-                    print!(", ");
-                }
-                print!("{}", state.borrow().state);
-                first = false;
-            }
-        }
-        print!("}}");
-    }
-
-    // Imprime las transiciones del autómata NFA
-    fn print_transitions(&self) {
-        for state in &self.states {
-            let state_borrow = state.borrow();
-            for (symbol, next_states) in &state_borrow.transitions {
-                let next_states_str: Vec<String> = next_states
-                    .iter()
-                    .map(|next_state| next_state.borrow().state.clone())
-                    .collect();
-
-                println!(
-                    "δ({}, {}) = {{{}}}",
-                    state_borrow.state,
-                    symbol,
-                    next_states_str.join(", ")
-                );
-            }
-        }
-    }
-
-    // Imprime la 5-tupla (Definición formal de un NFA)
-    fn tupla(&self) {
-        print!("A = <");
-
-        // Imprimir Q
-        print!("Q = ");
-        Self::print_states(&self);
-        print!(", ");
-
-        // Imprimir Σ
-        print!("Σ = ");
-        Self::print_alphabet(&self);
-        print!(", ");
-
-        // Imprimir el estado inicial
-        Self::print_start_state(&self);
-
-        // Imprimir δ (transiciones) - Asumiendo que tienes una función para esto o lo omites por ahora
-        print!(", δ, ");
-
-        // Imprimir F
-        print!("F = ");
-        Self::print_accept_states(&self);
-        println!(">");
-    }
-}
+use automata::cli;
+use automata::nfa::NFA;
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        None => run_example(),
+        Some("render") => cli::render(&args[1..]),
+        Some("test") => cli::test(&args[1..]),
+        Some("grade") => cli::grade(&args[1..]),
+        Some("explain") => cli::explain(&args[1..]),
+        Some("nerode") => cli::nerode(&args[1..]),
+        Some("pump") => cli::pump(&args[1..]),
+        Some("random") => cli::random(&args[1..]),
+        Some("-h" | "--help" | "help") => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        Some(command) => Err(format!(
+            "Comando \"{}\" desconocido.\n{}",
+            command,
+            cli::USAGE
+        )),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        exit(1);
+    }
+}
+
+// Ejecuta el autómata de ejemplo con la palabra "0110".
+fn run_example() -> Result<(), String> {
     let nfa_description = "
         alphabet={0,1}
         state={q0, q1, q2}
@@ -261,437 +46,14 @@ fn main() {
         (q2, 1)->{q1, q2}
     ";
 
-    let nfa = NFA::from_string(nfa_description);
+    let nfa = NFA::try_from_string(nfa_description).map_err(|error| error.to_string())?;
 
-    if nfa.run("0110") {
+    if nfa.run("0110".chars()) {
         println!("La palabra es aceptada por el autómata.");
     } else {
         println!("La palabra es rechazada por el autómata.");
     }
 
     println!("Gracias por usar el programa.");
-}
-
-
-/**
- Función que crea el alfabeto del autómata.
-    # Returns
-    Retorna un `HashSet<char>` el cual representa mi conjunto de símbolos .
-*/
-fn create_alphabet() -> HashSet<char> {
-    let mut alphabet = HashSet::new();
-    let size: usize;
-    let mut input;
-
-    loop {
-        println!("Ingrese la cardinalidad del alfabeto: ");
-        input = String::new();
-        if std::io::stdin().read_line(&mut input).is_err() {
-            println!("Error al leer la entrada.");
-            continue;
-        }
-
-        size = match input.trim().parse() {
-            Ok(size) => size,
-            Err(_) => {
-                println!("Error al leer la entrada.");
-                continue;
-            }
-        };
-        break;
-    }
-
-    let mut i = 0;
-
-    while i < size {
-        println!("Ingrese el símbolo {}: ", i + 1);
-        let mut input = String::new();
-
-        if std::io::stdin().read_line(&mut input).is_err() {
-            println!("Error al leer la entrada.");
-            continue; // No incrementa i si hay error en la lectura
-        }
-
-        let symbol = match input.trim().chars().next() {
-            Some(c) => c,
-            None => {
-                println!("Entrada vacía, por favor ingrese un símbolo.");
-                continue; // No incrementa i si no se ingresa un símbolo
-            }
-        };
-
-        if alphabet.insert(symbol) {
-            i += 1; // Solo incrementa i si el símbolo es válido y no está duplicado
-        } else {
-            println!("El símbolo ya existe en el alfabeto.");
-            // No se incrementa i si el símbolo ya existe en el alfabeto
-        }
-    }
-    alphabet
-}
-
-/**
- Función que crea mi conjunto de estados del autómata .
-    # Returns
-    Retorna un `Vec<Rc<RefCell<Node>>>` el cual representa mi conjunto de estados.
-*/
-fn create_states() -> Vec<Rc<RefCell<Node>>> {
-    let mut states = Vec::new();
-    let mut states_name: HashSet<String> = HashSet::new();
-    let mut input;
-
-    loop {
-        println!("Ingrese la cardinalidad del conjunto de los estados: ");
-        input = String::new();
-        if std::io::stdin().read_line(&mut input).is_err() {
-            println!("Error al leer la entrada.");
-            continue;
-        }
-
-        let size = match input.trim().parse() {
-            Ok(size) => size,
-            Err(_) => {
-                println!("Error al leer la entrada.");
-                continue;
-            }
-        };
-
-        let mut i: usize = 0;
-
-        while i < size {
-            println!("Ingrese el nombre del estado {}: ", i);
-            input = String::new();
-
-            if std::io::stdin().read_line(&mut input).is_err() {
-                println!("Error al leer la entrada.");
-                continue;
-            }
-
-            let state = input.trim().to_string();
-
-            if !states_name.insert(state.clone()) {
-                println!("El estado \"{}\" ya ha sido definido.", state);
-                continue;
-            }
-
-            let is_accept;
-
-            loop {
-                println!("Es estado de aceptacion? (s/n): ");
-                input = String::new();
-
-                if std::io::stdin().read_line(&mut input).is_err() {
-                    println!("Error al leer la entrada.");
-                    continue;
-                }
-
-                match input.trim() {
-                    "s" => {
-                        is_accept = true;
-                        break;
-                    }
-                    "n" => {
-                        is_accept = false;
-                        break;
-                    }
-                    _ => {
-                        println!("Opcion invalida.");
-                    }
-                }
-            }
-
-            states.push(Node::new(&state, is_accept));
-            i += 1;
-        }
-
-        break;
-    }
-
-    states
-}
-
-/**
- Crea las transiciones entre los nodos (estados) del autómata.
-    # Arguments
-    * `states` - Referencia al vector de nodos.
-    * `alphabet` - Referencia al alfabeto.
-*/
-fn create_transitions(states: &Vec<Rc<RefCell<Node>>>, alphabet: &HashSet<char>) {
-    loop {
-        // Solicitar al usuario el estado-símbolo-estados_destino
-        println!("Ingrese la transición en el formato \"(estado_actual, símbolo)->{{estados_destino}}\" (o escriba \"exit\" para salir):");
-        let mut input = String::new();
-
-        if std::io::stdin().read_line(&mut input).is_err() {
-            println!("Error al leer la entrada.");
-            continue;
-        }
-
-        let input = input.trim();
-
-        // Verificar si el usuario quiere salir
-        if input.to_lowercase() == "exit" {
-            break;
-        }
-
-        // Verificar el formato de la entrada
-        let parts: Vec<&str> = input.split("->").collect();
-        if parts.len() != 2 {
-            println!(
-                "Formato incorrecto. Debe ser \"(estado_actual, símbolo)->{{estados_destino}}\"."
-            );
-            continue;
-        }
-
-        let transition_part = parts[0].trim();
-        let next_states_input = parts[1].trim();
-
-        // Verificar que el formato de la parte de transición sea correcto (debe ser "(estado_actual, símbolo)")
-        if !transition_part.starts_with('(') || !transition_part.ends_with(')') {
-            println!("Formato incorrecto en la parte de transición. Debe ser \"(estado_actual, símbolo)\".");
-            continue;
-        }
-
-        // Remover los paréntesis y dividir por la coma
-        let transition_inner = &transition_part[1..transition_part.len() - 1];
-        let transition_parts: Vec<&str> = transition_inner.split(',').collect();
-
-        if transition_parts.len() != 2 {
-            println!("Formato incorrecto. Debe haber un estado y un símbolo separados por coma.");
-            continue;
-        }
-
-        let state_input = transition_parts[0].trim();
-        let symbol_input = transition_parts[1].trim();
-
-        // Verificar que el símbolo tenga un solo carácter
-        if symbol_input.len() != 1 {
-            println!("El símbolo debe ser un solo carácter.");
-            continue;
-        }
-
-        let symbol = symbol_input.chars().next().unwrap();
-
-        // Verificar que el símbolo pertenezca al alfabeto
-        if !alphabet.contains(&symbol) {
-            println!("El símbolo '{}' no pertenece al alfabeto.", symbol);
-            continue;
-        }
-
-        // Verificar que los estados destino estén entre llaves
-        if !next_states_input.starts_with('{') || !next_states_input.ends_with('}') {
-            println!("Formato incorrecto en los estados destino. Deben estar dentro de llaves \"{{estado1, estado2}}\".");
-            continue;
-        }
-
-        // Remover las llaves y dividir los estados destino por comas
-        let next_states_inner = &next_states_input[1..next_states_input.len() - 1];
-        let next_states: Vec<&str> = next_states_inner.split(',').map(|s| s.trim()).collect();
-
-        // Buscar el estado actual
-        let current_state = states.iter().find(|&x| x.borrow().state == state_input);
-
-        if let Some(current) = current_state {
-            // Buscar y agregar las transiciones para cada estado destino
-            for next_state_name in next_states {
-                let next_state = states.iter().find(|&x| x.borrow().state == next_state_name);
-
-                match next_state {
-                    Some(next) => {
-                        // Agregar la transición a cada estado destino
-                        Node::add_transition(current, symbol, next.clone());
-                        println!(
-                            "Transición agregada: δ({}, {}) = {}",
-                            state_input, symbol, next_state_name
-                        );
-                    }
-                    None => {
-                        println!("El estado destino \"{}\" no existe.", next_state_name);
-                    }
-                }
-            }
-        } else {
-            println!("El estado \"{}\" no existe.", state_input);
-        }
-    }
-}
-
-/**
- Crea las transiciones entre los nodos (estados) del autómata sin nececidad del usuario.
-    # Arguments
-    * `states` - Referencia al vector de nodos.
-    * `input` - Referencia a un string generador.
-*/
-fn create_transitions_without_cli(states: &Vec<Rc<RefCell<Node>>>, input: &str) {
-    // Verificar el formato de la entrada
-    let parts: Vec<&str> = input.split("->").collect();
-
-    let transition_part = parts[0].trim();
-    let next_states_input = parts[1].trim();
-
-    // Remover los paréntesis y dividir por la coma
-    let transition_inner = &transition_part[1..transition_part.len() - 1];
-    let transition_parts: Vec<&str> = transition_inner.split(',').collect();
-
-    let state_input = transition_parts[0].trim();
-    let symbol_input = transition_parts[1].trim();
-
-    let symbol = symbol_input.chars().next().unwrap();
-
-    // Remover las llaves y dividir los estados destino por comas
-    let next_states_inner = &next_states_input[1..next_states_input.len() - 1];
-    let next_states: Vec<&str> = next_states_inner.split(',').map(|s| s.trim()).collect();
-
-    // Buscar el estado actual
-    let current_state = states.iter().find(|&x| x.borrow().state == state_input);
-
-    if let Some(current) = current_state {
-        // Buscar y agregar las transiciones para cada estado destino
-        for next_state_name in next_states {
-            let next_state = states.iter().find(|&x| x.borrow().state == next_state_name);
-
-            match next_state {
-                Some(next) => {
-                    // Agregar la transición a cada estado destino
-                    Node::add_transition(current, symbol, next.clone());
-                }
-                None => {
-                    println!("El estado destino \"{}\" no existe.", next_state_name);
-                }
-            }
-        }
-    } else {
-        panic!("El estado actual \"{}\" no existe.", state_input);
-    }
-}
-
-/**
- Define mi estado inicial del autómata.
-    # Arguments
-    * `states` - Referencia al vector de nodos.
-    # Returns
-    Retorna un `Rc<RefCell<Node>>` que es el puntero al estado inicial.
-*/
-fn define_start_states(states: &Vec<Rc<RefCell<Node>>>) -> Rc<RefCell<Node>> {
-    let mut input;
-
-    if states.is_empty() {
-        return Node::new("Empty", false);
-    }
-
-    loop {
-        println!("Ingrese el estado inicial: ");
-        input = String::new();
-
-        if std::io::stdin().read_line(&mut input).is_err() {
-            println!("Error al leer la entrada.");
-            continue;
-        }
-
-        let start_state = states.iter().find(|&x| x.borrow().state == input.trim());
-
-        match start_state {
-            Some(start) => {
-                return start.clone();
-            }
-            None => {
-                println!("El estado no existe.");
-            }
-        }
-    }
-}
-
-// Menú principal del programa.
-fn menu() {
-    clear_console();
-    println!("Cree un autómata finito No determinista.\n");
-    let mut nfa = NFA::new();
-
-    loop {
-        wait_for_keypress();
-        clear_console();
-        println!("Autómata Finito No Determinista");
-        println!("=============================");
-        println!("1. Crear o reemplazar un nuevo autómata.");
-        println!("2. Validar una palabra.");
-        println!("3. Imprimir el conjunto de estados.");
-        println!("4. Imprimir el alfabeto.");
-        println!("5. Imprimir el estado inicial.");
-        println!("6. Imprimir los conjuntos de aceptación.");
-        println!("7. Imprimir las 5-tupla.");
-        println!("8. Imprimir las transiciones.");
-        println!("9. Salir del programa.\n");
-
-        let mut choice = String::new();
-        std::io::stdin().read_line(&mut choice).unwrap();
-
-        match choice.trim() {
-            "1" => {
-                nfa = NFA::new();
-                println!("Nuevo autómata creado.");
-            }
-            "2" => {
-                println!("Ingrese la palabra a validar:");
-                let mut input = String::new();
-                std::io::stdin().read_line(&mut input).unwrap();
-                let input = input.trim();
-                if nfa.run(input) {
-                    println!("La palabra es aceptada por el autómata.");
-                } else {
-                    println!("La palabra es rechazada por el autómata.");
-                }
-            }
-            "3" => {
-                println!("Conjunto de estados:");
-                nfa.print_states();
-                println!();
-            }
-            "4" => {
-                println!("Alfabeto:");
-                nfa.print_alphabet();
-                println!();
-            }
-            "5" => {
-                println!("Estado inicial:");
-                nfa.print_start_state();
-                println!();
-            }
-            "6" => {
-                println!("Conjuntos de aceptación:");
-                nfa.print_accept_states();
-                println!();
-            }
-            "7" => {
-                println!("Conjuntos de aceptación:");
-                nfa.tupla();
-            }
-            "8" => {
-                println!("Transiciones:");
-                nfa.print_transitions();
-            }
-            "9" => break,
-            _ => println!("Opción no válida, intente de nuevo."),
-        }
-    }
-}
-
-// Función para limpiar la consola
-fn clear_console() {
-    if cfg!(target_os = "windows") {
-        Command::new("cmd")
-            .args(&["/C", "cls"])
-            .status()
-            .expect("Error al limpiar la consola");
-    } else {
-        Command::new("clear")
-            .status()
-            .expect("Error al limpiar la consola");
-    }
-}
-
-// Función para esperar a que el usuario presione una tecla
-fn wait_for_keypress() {
-    let mut input = String::new();
-    println!("Presione enter para continuar...");
-    std::io::stdin().read_line(&mut input).ok();
+    Ok(())
 }
@@ -0,0 +1,516 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+
+use crate::description::{
+    classify, declared, parse_set, parse_symbol, split_state_symbol, unknown_state, Line,
+};
+use crate::error::ParseError;
+use crate::nfa::Symbol;
+
+/**
+ Máquina de Mealy: transductor determinista cuya salida depende del estado y del símbolo leído.
+    En el formato de descripción cada transición lleva su salida después de `/`:
+    `(q0, a)->{q1}/x`.
+*/
+pub struct Mealy<S: Symbol = char, O: Clone = String> {
+    states: Vec<String>,
+    alphabet: BTreeSet<S>,
+    start_state: usize,
+    transitions: Vec<BTreeMap<S, (usize, O)>>,
+}
+
+/**
+ Máquina de Moore: transductor determinista cuya salida depende solo del estado.
+    En el formato de descripción la salida de cada estado se escribe después de `/`
+    en la línea de estados: `state={q0/x, q1/y}`.
+*/
+pub struct Moore<S: Symbol = char, O: Clone = String> {
+    states: Vec<String>,
+    outputs: Vec<O>,
+    alphabet: BTreeSet<S>,
+    start_state: usize,
+    transitions: Vec<BTreeMap<S, usize>>,
+}
+
+impl<S: Symbol, O: Clone> Mealy<S, O> {
+    // Crea una máquina con un único estado, que es el inicial.
+    pub fn new(start_state: &str) -> Self {
+        Mealy {
+            states: vec![start_state.to_string()],
+            alphabet: BTreeSet::new(),
+            start_state: 0,
+            transitions: vec![BTreeMap::new()],
+        }
+    }
+
+    // Agrega un estado si no existe y retorna su posición.
+    pub fn add_state(&mut self, name: &str) -> usize {
+        match self.position(name) {
+            Some(index) => index,
+            None => {
+                self.states.push(name.to_string());
+                self.transitions.push(BTreeMap::new());
+                self.states.len() - 1
+            }
+        }
+    }
+
+    /**
+     Define la transición `δ(from, symbol) = to` con salida `λ(from, symbol) = output`.
+        # Returns
+        Retorna un error si algún estado no existe o si la transición ya estaba definida.
+    */
+    pub fn add_transition(
+        &mut self,
+        from: &str,
+        symbol: S,
+        to: &str,
+        output: O,
+    ) -> Result<(), ParseError> {
+        let from_index = self.position(from).ok_or_else(|| unknown_state(from))?;
+        let to_index = self.position(to).ok_or_else(|| unknown_state(to))?;
+
+        if self.transitions[from_index].contains_key(&symbol) {
+            return Err(ParseError::new(
+                0,
+                format!(
+                    "La transición desde \"{}\" ya está definida, la máquina debe ser determinista.",
+                    from
+                ),
+            ));
+        }
+
+        self.alphabet.insert(symbol.clone());
+        self.transitions[from_index].insert(symbol, (to_index, output));
+        Ok(())
+    }
+
+    // Retorna los nombres de los estados
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    // Retorna el alfabeto de entrada
+    pub fn alphabet(&self) -> &BTreeSet<S> {
+        &self.alphabet
+    }
+
+    // Retorna el nombre del estado inicial
+    pub fn start_state(&self) -> &str {
+        &self.states[self.start_state]
+    }
+
+    // Indica si todos los estados tienen transición con todos los símbolos del alfabeto.
+    pub fn is_complete(&self) -> bool {
+        self.transitions
+            .iter()
+            .all(|row| self.alphabet.iter().all(|symbol| row.contains_key(symbol)))
+    }
+
+    /**
+     Traduce la entrada produciendo una salida por cada símbolo leído.
+        # Arguments
+        * `input` - La secuencia de símbolos de entrada.
+        # Returns
+        Retorna las salidas producidas. Si la máquina no está completa y encuentra
+        una transición indefinida, se detiene y retorna lo producido hasta ese momento.
+    */
+    pub fn transduce<I: IntoIterator<Item = S>>(&self, input: I) -> Vec<O> {
+        let mut state = self.start_state;
+        let mut output = Vec::new();
+
+        for symbol in input {
+            match self.transitions[state].get(&symbol) {
+                Some((next, out)) => {
+                    output.push(out.clone());
+                    state = *next;
+                }
+                None => break,
+            }
+        }
+
+        output
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.states.iter().position(|state| state == name)
+    }
+}
+
+impl<S: Symbol, O: Clone + Ord + fmt::Display> Mealy<S, O> {
+    /**
+     Convierte la máquina en una máquina de Moore equivalente.
+        Cada estado de Moore es un par `q/o` formado por un estado de Mealy y la salida
+        con la que se llega a él.
+        # Arguments
+        * `initial_output` - Salida asociada al estado inicial, que Moore emite antes de leer.
+        # Returns
+        Retorna una máquina de Moore cuya traducción es `initial_output` seguida de la de Mealy.
+    */
+    pub fn to_moore(&self, initial_output: O) -> Moore<S, O> {
+        let start = (self.start_state, initial_output);
+        let mut pairs: Vec<(usize, O)> = vec![start.clone()];
+        let mut index: BTreeMap<(usize, O), usize> = BTreeMap::from([(start, 0)]);
+        let mut transitions: Vec<BTreeMap<S, usize>> = vec![BTreeMap::new()];
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            let state = pairs[current].0;
+
+            for (symbol, (next, output)) in &self.transitions[state] {
+                let pair = (*next, output.clone());
+                let target = *index.entry(pair.clone()).or_insert_with(|| {
+                    pairs.push(pair);
+                    transitions.push(BTreeMap::new());
+                    queue.push_back(pairs.len() - 1);
+                    pairs.len() - 1
+                });
+                transitions[current].insert(symbol.clone(), target);
+            }
+        }
+
+        Moore {
+            states: pairs
+                .iter()
+                .map(|(state, output)| format!("{}/{}", self.states[*state], output))
+                .collect(),
+            outputs: pairs.into_iter().map(|(_, output)| output).collect(),
+            alphabet: self.alphabet.clone(),
+            start_state: 0,
+            transitions,
+        }
+    }
+}
+
+impl<S: Symbol + fmt::Display, O: Clone + fmt::Display> Mealy<S, O> {
+    // Imprime las transiciones con su salida
+    pub fn print_transitions(&self) {
+        for (state, row) in self.states.iter().zip(&self.transitions) {
+            for (symbol, (next, output)) in row {
+                println!(
+                    "δ({}, {}) = {}, λ({}, {}) = {}",
+                    state, symbol, self.states[*next], state, symbol, output
+                );
+            }
+        }
+    }
+}
+
+impl Mealy<char, String> {
+    /**
+     Crea una máquina de Mealy desde el formato de descripción.
+        # Arguments
+        * `description` - Texto con líneas `state=`, `start_state=` y transiciones `(q0, a)->{q1}/x`.
+        # Returns
+        Retorna la máquina o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut machine: Option<Mealy<char, String>> = None;
+        let mut alphabet = BTreeSet::new();
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+
+            match classify(line.trim()).map_err(error)? {
+                Line::Empty => {}
+                Line::Alphabet(list) => {
+                    for symbol in parse_set(list).map_err(error)? {
+                        alphabet.insert(parse_symbol(symbol).map_err(error)?);
+                    }
+                }
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        match &mut machine {
+                            Some(machine) => {
+                                machine.add_state(name);
+                            }
+                            None => machine = Some(Mealy::new(name)),
+                        }
+                    }
+                }
+                Line::Start(name) => {
+                    let machine = declared(&mut machine).map_err(error)?;
+                    machine.start_state = machine
+                        .position(name)
+                        .ok_or_else(|| with_line(unknown_state(name)))?;
+                }
+                Line::Final(_) => {
+                    return Err(error(
+                        "Las máquinas de Mealy no tienen estados de aceptación.".to_string(),
+                    ));
+                }
+                Line::Transition(left, right) => {
+                    let machine = declared(&mut machine).map_err(error)?;
+                    let (state, symbol) = split_state_symbol(left).map_err(error)?;
+                    let (target, output) = split_output(right).map_err(error)?;
+                    machine
+                        .add_transition(state, symbol, target, output.to_string())
+                        .map_err(with_line)?;
+                }
+            }
+        }
+
+        let mut machine =
+            machine.ok_or_else(|| ParseError::new(0, "El autómata no tiene estados."))?;
+        machine.alphabet.extend(alphabet);
+        Ok(machine)
+    }
+}
+
+impl<S: Symbol, O: Clone> Moore<S, O> {
+    // Crea una máquina con un único estado, que es el inicial, y su salida.
+    pub fn new(start_state: &str, output: O) -> Self {
+        Moore {
+            states: vec![start_state.to_string()],
+            outputs: vec![output],
+            alphabet: BTreeSet::new(),
+            start_state: 0,
+            transitions: vec![BTreeMap::new()],
+        }
+    }
+
+    // Agrega un estado con su salida (o la reemplaza si ya existe) y retorna su posición.
+    pub fn add_state(&mut self, name: &str, output: O) -> usize {
+        match self.position(name) {
+            Some(index) => {
+                self.outputs[index] = output;
+                index
+            }
+            None => {
+                self.states.push(name.to_string());
+                self.outputs.push(output);
+                self.transitions.push(BTreeMap::new());
+                self.states.len() - 1
+            }
+        }
+    }
+
+    /**
+     Define la transición `δ(from, symbol) = to`.
+        # Returns
+        Retorna un error si algún estado no existe o si la transición ya estaba definida.
+    */
+    pub fn add_transition(&mut self, from: &str, symbol: S, to: &str) -> Result<(), ParseError> {
+        let from_index = self.position(from).ok_or_else(|| unknown_state(from))?;
+        let to_index = self.position(to).ok_or_else(|| unknown_state(to))?;
+
+        if self.transitions[from_index].contains_key(&symbol) {
+            return Err(ParseError::new(
+                0,
+                format!(
+                    "La transición desde \"{}\" ya está definida, la máquina debe ser determinista.",
+                    from
+                ),
+            ));
+        }
+
+        self.alphabet.insert(symbol.clone());
+        self.transitions[from_index].insert(symbol, to_index);
+        Ok(())
+    }
+
+    // Retorna los nombres de los estados
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    // Retorna el alfabeto de entrada
+    pub fn alphabet(&self) -> &BTreeSet<S> {
+        &self.alphabet
+    }
+
+    // Retorna el nombre del estado inicial
+    pub fn start_state(&self) -> &str {
+        &self.states[self.start_state]
+    }
+
+    // Retorna la salida asociada a un estado.
+    pub fn output(&self, state: &str) -> Option<&O> {
+        self.position(state).map(|index| &self.outputs[index])
+    }
+
+    // Indica si todos los estados tienen transición con todos los símbolos del alfabeto.
+    pub fn is_complete(&self) -> bool {
+        self.transitions
+            .iter()
+            .all(|row| self.alphabet.iter().all(|symbol| row.contains_key(symbol)))
+    }
+
+    /**
+     Traduce la entrada emitiendo la salida de cada estado visitado.
+        # Arguments
+        * `input` - La secuencia de símbolos de entrada.
+        # Returns
+        Retorna la salida del estado inicial seguida de una salida por símbolo leído.
+        Si encuentra una transición indefinida se detiene y retorna lo producido.
+    */
+    pub fn transduce<I: IntoIterator<Item = S>>(&self, input: I) -> Vec<O> {
+        let mut state = self.start_state;
+        let mut output = vec![self.outputs[state].clone()];
+
+        for symbol in input {
+            match self.transitions[state].get(&symbol) {
+                Some(&next) => {
+                    state = next;
+                    output.push(self.outputs[state].clone());
+                }
+                None => break,
+            }
+        }
+
+        output
+    }
+
+    /**
+     Convierte la máquina en una máquina de Mealy equivalente.
+        La salida de cada transición es la salida del estado al que llega.
+        # Returns
+        Retorna una máquina de Mealy cuya traducción es la de Moore sin su primera salida.
+    */
+    pub fn to_mealy(&self) -> Mealy<S, O> {
+        Mealy {
+            states: self.states.clone(),
+            alphabet: self.alphabet.clone(),
+            start_state: self.start_state,
+            transitions: self
+                .transitions
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|(symbol, &next)| (symbol.clone(), (next, self.outputs[next].clone())))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.states.iter().position(|state| state == name)
+    }
+}
+
+impl<S: Symbol + fmt::Display, O: Clone + fmt::Display> Moore<S, O> {
+    // Imprime la salida de cada estado
+    pub fn print_outputs(&self) {
+        for (state, output) in self.states.iter().zip(&self.outputs) {
+            println!("λ({}) = {}", state, output);
+        }
+    }
+
+    // Imprime las transiciones de la máquina
+    pub fn print_transitions(&self) {
+        for (state, row) in self.states.iter().zip(&self.transitions) {
+            for (symbol, next) in row {
+                println!("δ({}, {}) = {}", state, symbol, self.states[*next]);
+            }
+        }
+    }
+}
+
+impl Moore<char, String> {
+    /**
+     Crea una máquina de Moore desde el formato de descripción.
+        # Arguments
+        * `description` - Texto con `state={q0/x, q1/y}`, `start_state=` y transiciones `(q0, a)->{q1}`.
+        # Returns
+        Retorna la máquina o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut machine: Option<Moore<char, String>> = None;
+        let mut alphabet = BTreeSet::new();
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+
+            match classify(line.trim()).map_err(error)? {
+                Line::Empty => {}
+                Line::Alphabet(list) => {
+                    for symbol in parse_set(list).map_err(error)? {
+                        alphabet.insert(parse_symbol(symbol).map_err(error)?);
+                    }
+                }
+                Line::States(list) => {
+                    for entry in parse_set(list).map_err(error)? {
+                        let (name, output) = entry
+                            .split_once('/')
+                            .map(|(name, output)| (name.trim(), output.trim()))
+                            .filter(|(_, output)| !output.is_empty())
+                            .ok_or_else(|| {
+                                error(format!(
+                                    "El estado \"{}\" debe indicar su salida como \"estado/salida\".",
+                                    entry
+                                ))
+                            })?;
+                        match &mut machine {
+                            Some(machine) => {
+                                machine.add_state(name, output.to_string());
+                            }
+                            None => machine = Some(Moore::new(name, output.to_string())),
+                        }
+                    }
+                }
+                Line::Start(name) => {
+                    let machine = declared(&mut machine).map_err(error)?;
+                    machine.start_state = machine
+                        .position(name)
+                        .ok_or_else(|| with_line(unknown_state(name)))?;
+                }
+                Line::Final(_) => {
+                    return Err(error(
+                        "Las máquinas de Moore no tienen estados de aceptación.".to_string(),
+                    ));
+                }
+                Line::Transition(left, right) => {
+                    let machine = declared(&mut machine).map_err(error)?;
+                    let (state, symbol) = split_state_symbol(left).map_err(error)?;
+                    let target = single_target(right).map_err(error)?;
+                    machine
+                        .add_transition(state, symbol, target)
+                        .map_err(with_line)?;
+                }
+            }
+        }
+
+        let mut machine =
+            machine.ok_or_else(|| ParseError::new(0, "El autómata no tiene estados."))?;
+        machine.alphabet.extend(alphabet);
+        Ok(machine)
+    }
+}
+
+// Interpreta el destino `{q1}` de una transición determinista.
+fn single_target(text: &str) -> Result<&str, String> {
+    match parse_set(text)?[..] {
+        [target] => Ok(target),
+        _ => Err(format!(
+            "La transición debe tener exactamente un estado destino y tiene \"{}\".",
+            text
+        )),
+    }
+}
+
+// Separa el destino y la salida de `{q1}/x`.
+fn split_output(text: &str) -> Result<(&str, &str), String> {
+    let close = text
+        .find('}')
+        .ok_or_else(|| format!("Falta \"}}\" en \"{}\".", text))?;
+    let target = single_target(&text[..=close])?;
+    let output = text[close + 1..]
+        .trim()
+        .strip_prefix('/')
+        .map(str::trim)
+        .filter(|output| !output.is_empty())
+        .ok_or_else(|| format!("Falta la salida \"/salida\" en \"{}\".", text))?;
+
+    Ok((target, output))
+}
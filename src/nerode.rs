@@ -0,0 +1,415 @@
+//! Algoritmo de llenado de tabla (Myhill–Nerode) para distinguir estados de un autómata
+//! determinista, y minimización basada en él.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{Display, Write};
+
+use crate::nfa::{Symbol, NFA};
+use crate::tikz::escape_latex;
+
+// Marca de un par de estados distinguibles.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mark<S> {
+    // Ronda en la que se marcó el par; en la ronda 0 se marcan los pares que difieren en aceptación.
+    pub round: usize,
+    // Palabra más corta que lleva a uno de los estados a aceptar y al otro a rechazar.
+    pub word: Vec<S>,
+}
+
+// Tabla de distinguibilidad completa de un autómata determinista.
+#[derive(Clone, Debug)]
+pub struct DistinguishabilityTable<S> {
+    // Nombres de los estados, en el orden de `NFA::states`.
+    pub states: Vec<String>,
+    // Marca de cada par `(p, q)` con `p < q`, o `None` si los estados son equivalentes.
+    pub marks: BTreeMap<(usize, usize), Option<Mark<S>>>,
+    // Cantidad de rondas en las que se marcó algún par.
+    pub rounds: usize,
+}
+
+impl<S: Symbol + Display> NFA<S> {
+    /**
+     Aplica el algoritmo de llenado de tabla sobre un autómata determinista y completo.
+        En la ronda 0 se marcan los pares en que un estado acepta y el otro no. En cada ronda
+        siguiente se marca `(p, q)` si para algún símbolo `a` el par `(δ(p, a), δ(q, a))` se
+        marcó en una ronda anterior; la palabra distinguidora es `a` seguida de la de ese par.
+        # Returns
+        Retorna la tabla, o un error si el autómata tiene transiciones ε, más de un destino
+        o transiciones sin definir (en ese caso se puede usar `determinize` antes).
+    */
+    pub fn table_filling(&self) -> Result<DistinguishabilityTable<S>, String> {
+        let next = self.deterministic_table()?;
+        let alphabet: Vec<S> = self.alphabet.iter().cloned().collect();
+        let accept: Vec<bool> = self
+            .states
+            .iter()
+            .map(|state| state.borrow().is_accept)
+            .collect();
+        let pair = |p: usize, q: usize| if p < q { (p, q) } else { (q, p) };
+
+        let mut marks: BTreeMap<(usize, usize), Option<Mark<S>>> = BTreeMap::new();
+        for p in 0..self.states.len() {
+            for q in p + 1..self.states.len() {
+                let mark = (accept[p] != accept[q]).then(|| Mark {
+                    round: 0,
+                    word: Vec::new(),
+                });
+                marks.insert((p, q), mark);
+            }
+        }
+
+        let mut rounds = usize::from(marks.values().any(Option::is_some));
+        loop {
+            let round = rounds;
+            let mut found = Vec::new();
+            for (&(p, q), mark) in &marks {
+                if mark.is_some() {
+                    continue;
+                }
+                let distinguishing = alphabet.iter().enumerate().find_map(|(i, symbol)| {
+                    let (np, nq) = (next[p][i], next[q][i]);
+                    if np == nq {
+                        return None;
+                    }
+                    marks[&pair(np, nq)].as_ref().map(|mark| {
+                        let mut word = vec![symbol.clone()];
+                        word.extend(mark.word.iter().cloned());
+                        word
+                    })
+                });
+                if let Some(word) = distinguishing {
+                    found.push(((p, q), Mark { round, word }));
+                }
+            }
+
+            if found.is_empty() {
+                break;
+            }
+            for (key, mark) in found {
+                marks.insert(key, Some(mark));
+            }
+            rounds += 1;
+        }
+
+        Ok(DistinguishabilityTable {
+            states: self
+                .states
+                .iter()
+                .map(|state| state.borrow().state.clone())
+                .collect(),
+            marks,
+            rounds,
+        })
+    }
+
+    /**
+     Construye el autómata determinista mínimo equivalente.
+        Si el autómata no es determinista y completo se determiniza primero; los estados
+        inalcanzables se descartan y los equivalentes según `table_filling` se fusionan.
+        Un estado fusionado se nombra con sus miembros, como `[q1, q2]`.
+        # Returns
+        Retorna el autómata mínimo, completo sobre el alfabeto.
+    */
+    pub fn minimize(&self) -> NFA<S> {
+        let dfa = match self.deterministic_table() {
+            Ok(_) => self.reachable(),
+            Err(_) => self.determinize(),
+        };
+        let table = dfa
+            .table_filling()
+            .expect("el autómata determinizado es determinista y completo");
+        let classes = table.equivalence_classes();
+
+        let mut class_of = vec![0; dfa.states.len()];
+        for (class, members) in classes.iter().enumerate() {
+            for &member in members {
+                class_of[member] = class;
+            }
+        }
+
+        let states = classes
+            .iter()
+            .map(|members| {
+                let name = match members.as_slice() {
+                    [single] => table.states[*single].clone(),
+                    _ => {
+                        let names: Vec<&str> = members
+                            .iter()
+                            .map(|&member| table.states[member].as_str())
+                            .collect();
+                        format!("[{}]", names.join(", "))
+                    }
+                };
+                (name, dfa.states[members[0]].borrow().is_accept)
+            })
+            .collect();
+        let transitions = dfa.transition_table();
+        let mut edges = Vec::new();
+        for (class, members) in classes.iter().enumerate() {
+            for (symbol, targets) in &transitions[members[0]] {
+                edges.push((class, Some(symbol.clone()), class_of[targets[0]]));
+            }
+        }
+
+        let mut minimal = NFA::from_table(states, class_of[dfa.start_index()], edges);
+        minimal.alphabet = dfa.alphabet.clone();
+        minimal
+    }
+
+    /**
+     Representa la función de transición de un autómata determinista y completo.
+        # Returns
+        Retorna el sucesor de cada estado por cada símbolo, en el orden del alfabeto,
+        o un mensaje que indica el primer incumplimiento encontrado.
+    */
+    fn deterministic_table(&self) -> Result<Vec<Vec<usize>>, String> {
+        let table = self.transition_table();
+        let epsilon = self.epsilon_table();
+
+        let mut next = Vec::with_capacity(table.len());
+        for (index, transitions) in table.iter().enumerate() {
+            let name = &self.states[index].borrow().state;
+            if !epsilon[index].is_empty() {
+                return Err(format!(
+                    "El autómata no es determinista: δ({}, ε) está definida.",
+                    name
+                ));
+            }
+            let mut row = Vec::with_capacity(self.alphabet.len());
+            for symbol in &self.alphabet {
+                match transitions.get(symbol).map(Vec::as_slice) {
+                    Some([target]) => row.push(*target),
+                    Some([_, _, ..]) => {
+                        return Err(format!(
+                            "El autómata no es determinista: δ({}, {}) tiene más de un destino.",
+                            name, symbol
+                        ))
+                    }
+                    _ => {
+                        return Err(format!(
+                            "El autómata no es completo: δ({}, {}) no está definida.",
+                            name, symbol
+                        ))
+                    }
+                }
+            }
+            next.push(row);
+        }
+        Ok(next)
+    }
+
+    // Copia del autómata limitada a los estados alcanzables desde el inicial.
+    fn reachable(&self) -> NFA<S> {
+        let table = self.transition_table();
+        let epsilon = self.epsilon_table();
+        let start = self.start_index();
+
+        let mut position = vec![None; self.states.len()];
+        let mut order = vec![start];
+        position[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(state) = queue.pop_front() {
+            let targets = table[state].values().flatten().chain(&epsilon[state]);
+            for &target in targets {
+                if position[target].is_none() {
+                    position[target] = Some(order.len());
+                    order.push(target);
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        let states = order
+            .iter()
+            .map(|&state| {
+                let node = self.states[state].borrow();
+                (node.state.clone(), node.is_accept)
+            })
+            .collect();
+        let mut edges = Vec::new();
+        for (from, &state) in order.iter().enumerate() {
+            for (symbol, targets) in &table[state] {
+                for &target in targets {
+                    edges.push((from, Some(symbol.clone()), position[target].unwrap_or(0)));
+                }
+            }
+            for &target in &epsilon[state] {
+                edges.push((from, None, position[target].unwrap_or(0)));
+            }
+        }
+
+        let mut nfa = NFA::from_table(states, 0, edges);
+        nfa.alphabet = self.alphabet.clone();
+        nfa
+    }
+}
+
+impl<S> DistinguishabilityTable<S> {
+    // Marca del par de estados `p` y `q`, en cualquier orden, o `None` si son equivalentes.
+    pub fn mark(&self, p: usize, q: usize) -> Option<&Mark<S>> {
+        let key = if p < q { (p, q) } else { (q, p) };
+        self.marks.get(&key).and_then(Option::as_ref)
+    }
+
+    // Indica si los estados `p` y `q` son distinguibles.
+    pub fn distinguishable(&self, p: usize, q: usize) -> bool {
+        self.mark(p, q).is_some()
+    }
+
+    /**
+     Agrupa los estados que no se pueden distinguir.
+        # Returns
+        Retorna las clases de equivalencia como posiciones ordenadas, en el orden de su
+        primer estado.
+    */
+    pub fn equivalence_classes(&self) -> Vec<Vec<usize>> {
+        let mut classes: Vec<Vec<usize>> = Vec::new();
+        for state in 0..self.states.len() {
+            match classes
+                .iter_mut()
+                .find(|class| !self.distinguishable(class[0], state))
+            {
+                Some(class) => class.push(state),
+                None => classes.push(vec![state]),
+            }
+        }
+        classes
+    }
+}
+
+impl<S: Display> DistinguishabilityTable<S> {
+    /**
+     Escribe la tabla triangular, la palabra distinguidora de cada par marcado y las
+     clases de equivalencia.
+        En la tabla cada celda indica la ronda en que se marcó el par, o `=` si los
+        estados son equivalentes.
+        # Returns
+        Retorna el texto.
+    */
+    pub fn to_text(&self) -> String {
+        let n = self.states.len();
+        let mut grid = vec![vec![String::new()]];
+        grid[0].extend(self.states.iter().take(n.saturating_sub(1)).cloned());
+        for q in 1..n {
+            let mut row = vec![self.states[q].clone()];
+            for p in 0..q {
+                row.push(match self.mark(p, q) {
+                    Some(mark) => mark.round.to_string(),
+                    None => "=".to_string(),
+                });
+            }
+            grid.push(row);
+        }
+
+        let columns = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                grid.iter()
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let mut text =
+            String::from("Tabla de distinguibilidad (ronda en que se marcó cada par):\n\n");
+        for row in &grid {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| {
+                    format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+                })
+                .collect();
+            text.push_str(cells.join(" | ").trim_end());
+            text.push('\n');
+        }
+
+        text.push_str("\nPalabras distinguidoras:\n");
+        for (&(p, q), mark) in &self.marks {
+            if let Some(mark) = mark {
+                let _ = writeln!(
+                    text,
+                    "  ({}, {}): {} (ronda {})",
+                    self.states[p],
+                    self.states[q],
+                    word_text(&mark.word),
+                    mark.round
+                );
+            }
+        }
+
+        let classes: Vec<String> = self
+            .equivalence_classes()
+            .iter()
+            .map(|class| {
+                let names: Vec<&str> = class.iter().map(|&s| self.states[s].as_str()).collect();
+                format!("{{{}}}", names.join(", "))
+            })
+            .collect();
+        let _ = writeln!(text, "\nClases de equivalencia: {}", classes.join(", "));
+        text
+    }
+
+    /**
+     Escribe la tabla triangular y las palabras distinguidoras como entornos `tabular` de LaTeX.
+        # Returns
+        Retorna el código LaTeX.
+    */
+    pub fn to_latex(&self) -> String {
+        let n = self.states.len();
+        let names: Vec<String> = self.states.iter().map(|s| escape_latex(s)).collect();
+
+        let mut latex = format!(
+            "\\begin{{tabular}}{{c|{}}}\n",
+            "c".repeat(n.saturating_sub(1))
+        );
+        let header: Vec<&str> = names
+            .iter()
+            .take(n.saturating_sub(1))
+            .map(String::as_str)
+            .collect();
+        let _ = writeln!(latex, "   & {} \\\\\n  \\hline", header.join(" & "));
+        for (q, name) in names.iter().enumerate().skip(1) {
+            let mut cells = vec![name.clone()];
+            for p in 0..n - 1 {
+                cells.push(if p >= q {
+                    String::new()
+                } else {
+                    match self.mark(p, q) {
+                        Some(mark) => mark.round.to_string(),
+                        None => "$\\equiv$".to_string(),
+                    }
+                });
+            }
+            let _ = writeln!(latex, "  {} \\\\", cells.join(" & "));
+        }
+        latex.push_str("\\end{tabular}\n\n");
+
+        latex.push_str("\\begin{tabular}{lll}\n  Par & Palabra & Ronda \\\\\n  \\hline\n");
+        for (&(p, q), mark) in &self.marks {
+            if let Some(mark) = mark {
+                let _ = writeln!(
+                    latex,
+                    "  ({}, {}) & {} & {} \\\\",
+                    names[p],
+                    names[q],
+                    escape_latex(&word_text(&mark.word)),
+                    mark.round
+                );
+            }
+        }
+        latex.push_str("\\end{tabular}\n");
+        latex
+    }
+}
+
+// Muestra una palabra, usando `ε` para la vacía.
+fn word_text<S: Display>(word: &[S]) -> String {
+    if word.is_empty() {
+        "ε".to_string()
+    } else {
+        word.iter().map(ToString::to_string).collect()
+    }
+}
@@ -0,0 +1,540 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::description::{
+    classify, parse_set, parse_symbol, split_state_symbol, unknown_state, Line,
+};
+use crate::error::ParseError;
+
+/**
+ Requisitos que debe cumplir un símbolo del alfabeto.
+    Se implementa automáticamente para cualquier tipo `Eq + Hash + Clone + Ord`
+    (`char`, `u8`, `String`, enumeraciones propias, ...).
+*/
+pub trait Symbol: Eq + Hash + Clone + Ord {}
+
+impl<T: Eq + Hash + Clone + Ord> Symbol for T {}
+
+// Puntero compartido a un estado del autómata.
+pub type StateRef<S = char> = Rc<RefCell<Node<S>>>;
+
+pub struct NFA<S: Symbol = char> {
+    pub(crate) states: Vec<StateRef<S>>,
+    pub(crate) alphabet: BTreeSet<S>,
+    pub(crate) start_state: StateRef<S>,
+}
+
+pub struct Node<S: Symbol = char> {
+    pub state: String,
+    pub is_accept: bool,
+    pub transitions: BTreeMap<S, Vec<StateRef<S>>>,
+    pub epsilon: Vec<StateRef<S>>,
+}
+
+impl<S: Symbol> Node<S> {
+    pub fn new(state: &str, is_accept: bool) -> StateRef<S> {
+        Rc::new(RefCell::new(Node {
+            state: state.to_string(),
+            is_accept,
+            transitions: BTreeMap::new(),
+            epsilon: Vec::new(),
+        }))
+    }
+
+    pub fn add_transition(node: &StateRef<S>, symbol: S, to: StateRef<S>) {
+        node.borrow_mut()
+            .transitions
+            .entry(symbol)
+            .or_default()
+            .push(to);
+    }
+
+    // Agrega una transición ε (sin consumir símbolo) hacia `to`.
+    pub fn add_epsilon_transition(node: &StateRef<S>, to: StateRef<S>) {
+        node.borrow_mut().epsilon.push(to);
+    }
+
+    pub fn next_states(&self, symbol: &S) -> Vec<StateRef<S>> {
+        self.transitions.get(symbol).cloned().unwrap_or_default()
+    }
+}
+
+impl NFA<char> {
+    /**
+     Crea un autómata desde el formato de descripción, informando los errores.
+        Exige que los estados se declaren con `state=` antes de usarlos y que cada
+        símbolo sea un solo carácter; `ε` indica una transición vacía.
+        # Arguments
+        * `description` - Texto con la descripción del autómata.
+        # Returns
+        Retorna el autómata o el primer error encontrado, con su número de línea.
+    */
+    pub fn try_from_string(description: &str) -> Result<Self, ParseError> {
+        let mut alphabet = BTreeSet::new();
+        let mut states: Vec<StateRef> = Vec::new();
+        let mut start_state = None;
+        // Índice por nombre para no recorrer `states` en cada referencia
+        let mut by_name: HashMap<&str, StateRef> = HashMap::new();
+        let find = |by_name: &HashMap<&str, StateRef>, name: &str| by_name.get(name).cloned();
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+
+            match classify(line.trim()).map_err(error)? {
+                Line::Empty => {}
+                Line::Alphabet(list) => {
+                    for symbol in parse_set(list).map_err(error)? {
+                        alphabet.insert(parse_symbol(symbol).map_err(error)?);
+                    }
+                }
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        by_name.entry(name).or_insert_with(|| {
+                            let state = Node::new(name, false);
+                            states.push(state.clone());
+                            state
+                        });
+                    }
+                }
+                Line::Start(name) => {
+                    start_state =
+                        Some(find(&by_name, name).ok_or_else(|| with_line(unknown_state(name)))?);
+                }
+                Line::Final(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        let state =
+                            find(&by_name, name).ok_or_else(|| with_line(unknown_state(name)))?;
+                        state.borrow_mut().is_accept = true;
+                    }
+                }
+                Line::Transition(left, right) => {
+                    let (name, symbol) = split_state_symbol(left).map_err(error)?;
+                    let from =
+                        find(&by_name, name).ok_or_else(|| with_line(unknown_state(name)))?;
+                    for target in parse_set(right).map_err(error)? {
+                        let to = find(&by_name, target)
+                            .ok_or_else(|| with_line(unknown_state(target)))?;
+                        if symbol == 'ε' {
+                            Node::add_epsilon_transition(&from, to);
+                        } else {
+                            alphabet.insert(symbol);
+                            Node::add_transition(&from, symbol, to);
+                        }
+                    }
+                }
+            }
+        }
+
+        if states.is_empty() {
+            return Err(ParseError::new(0, "El autómata no tiene estados."));
+        }
+        let start_state = start_state
+            .ok_or_else(|| ParseError::new(0, "Falta el estado inicial (\"start_state=\")."))?;
+
+        Ok(NFA {
+            alphabet,
+            states,
+            start_state,
+        })
+    }
+
+    /**
+     Escribe el autómata en el formato de descripción que lee `try_from_string`.
+        Los nombres con comas o llaves, como los que produce `determinize`, no se pueden
+        volver a leer.
+        # Returns
+        Retorna el texto, con una línea por cada par de estado y símbolo con transiciones.
+    */
+    pub fn to_description(&self) -> String {
+        let names = |states: &[StateRef]| -> String {
+            let names: Vec<String> = states.iter().map(|s| s.borrow().state.clone()).collect();
+            format!("{{{}}}", names.join(", "))
+        };
+        let symbols: Vec<String> = self.alphabet.iter().map(char::to_string).collect();
+        let accept: Vec<StateRef> = self
+            .states
+            .iter()
+            .filter(|state| state.borrow().is_accept)
+            .cloned()
+            .collect();
+
+        let mut description = format!("alphabet={{{}}}\n", symbols.join(", "));
+        description.push_str(&format!("state={}\n", names(&self.states)));
+        description.push_str(&format!(
+            "start_state={}\n",
+            self.start_state.borrow().state
+        ));
+        description.push_str(&format!("F={}\n", names(&accept)));
+        for state in &self.states {
+            let state = state.borrow();
+            for (symbol, next_states) in &state.transitions {
+                description.push_str(&format!(
+                    "({}, {})->{}\n",
+                    state.state,
+                    symbol,
+                    names(next_states)
+                ));
+            }
+            if !state.epsilon.is_empty() {
+                description.push_str(&format!(
+                    "({}, ε)->{}\n",
+                    state.state,
+                    names(&state.epsilon)
+                ));
+            }
+        }
+        description
+    }
+}
+
+impl<S: Symbol> NFA<S> {
+    /**
+     Construye un autómata a partir de nodos ya creados con `Node::new` y `Node::add_transition`.
+        # Arguments
+        * `states` - Conjunto de estados del autómata.
+        * `alphabet` - Conjunto de símbolos.
+        * `start_state` - Estado inicial; si no pertenece a `states` se agrega al comienzo.
+        # Returns
+        Retorna el `NFA` formado por las partes recibidas.
+    */
+    pub fn from_parts(
+        mut states: Vec<StateRef<S>>,
+        alphabet: BTreeSet<S>,
+        start_state: StateRef<S>,
+    ) -> Self {
+        // Todo autómata tiene su estado inicial en `states`, aunque esté vacío
+        if !states.iter().any(|state| Rc::ptr_eq(state, &start_state)) {
+            states.insert(0, start_state.clone());
+        }
+        NFA {
+            states,
+            alphabet,
+            start_state,
+        }
+    }
+
+    /**
+     Construye un autómata a partir de estados y transiciones dadas por posición.
+        # Arguments
+        * `states` - Nombre de cada estado y si es de aceptación.
+        * `start` - Posición del estado inicial.
+        * `edges` - Transiciones `(origen, símbolo, destino)`, donde `None` representa ε.
+        # Returns
+        Retorna el `NFA` con el alfabeto formado por los símbolos usados.
+    */
+    pub(crate) fn from_table<E>(states: Vec<(String, bool)>, start: usize, edges: E) -> Self
+    where
+        E: IntoIterator<Item = (usize, Option<S>, usize)>,
+    {
+        let nodes: Vec<StateRef<S>> = states
+            .iter()
+            .map(|(name, is_accept)| Node::new(name, *is_accept))
+            .collect();
+        let mut alphabet = BTreeSet::new();
+
+        for (from, symbol, to) in edges {
+            match symbol {
+                Some(symbol) => {
+                    alphabet.insert(symbol.clone());
+                    Node::add_transition(&nodes[from], symbol, nodes[to].clone());
+                }
+                None => Node::add_epsilon_transition(&nodes[from], nodes[to].clone()),
+            }
+        }
+
+        let start_state = nodes[start].clone();
+        NFA::from_parts(nodes, alphabet, start_state)
+    }
+
+    // Retorna el conjunto de estados
+    pub fn states(&self) -> &[StateRef<S>] {
+        &self.states
+    }
+
+    // Retorna el alfabeto
+    pub fn alphabet(&self) -> &BTreeSet<S> {
+        &self.alphabet
+    }
+
+    // Retorna el estado inicial
+    pub fn start_state(&self) -> &StateRef<S> {
+        &self.start_state
+    }
+
+    // Asocia cada estado con su posición dentro de `states`.
+    pub(crate) fn state_indices(&self) -> HashMap<*const RefCell<Node<S>>, usize> {
+        self.states
+            .iter()
+            .enumerate()
+            .map(|(i, state)| (Rc::as_ptr(state), i))
+            .collect()
+    }
+
+    // Posición del estado inicial dentro de `states`; `from_parts` garantiza que existe.
+    pub(crate) fn start_index(&self) -> usize {
+        self.states
+            .iter()
+            .position(|state| Rc::ptr_eq(state, &self.start_state))
+            .expect("el estado inicial pertenece a `states`")
+    }
+
+    // Transiciones ε de cada estado, usando posiciones.
+    pub(crate) fn epsilon_table(&self) -> Vec<Vec<usize>> {
+        let indices = self.state_indices();
+        self.states
+            .iter()
+            .map(|state| {
+                state
+                    .borrow()
+                    .epsilon
+                    .iter()
+                    .filter_map(|next| indices.get(&Rc::as_ptr(next)).copied())
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Indica si el autómata tiene transiciones ε.
+    pub fn has_epsilon(&self) -> bool {
+        self.states
+            .iter()
+            .any(|state| !state.borrow().epsilon.is_empty())
+    }
+
+    /**
+     Representa la función de transición usando posiciones en lugar de punteros.
+        # Returns
+        Retorna, para cada estado, un mapa de símbolo a las posiciones de los estados destino.
+    */
+    pub(crate) fn transition_table(&self) -> Vec<BTreeMap<S, Vec<usize>>> {
+        let indices = self.state_indices();
+        self.states
+            .iter()
+            .map(|state| {
+                state
+                    .borrow()
+                    .transitions
+                    .iter()
+                    .map(|(symbol, next_states)| {
+                        let targets = next_states
+                            .iter()
+                            .filter_map(|next| indices.get(&Rc::as_ptr(next)).copied())
+                            .collect();
+                        (symbol.clone(), targets)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /**
+     Valida si la palabra ingresada es aceptada por el autómata.
+        Prepara las tablas en cada llamada; para validar muchas palabras conviene usar
+        `runner` una vez y reutilizarlo.
+        # Arguments
+        * `input` - La palabra a analizar, como cualquier secuencia de símbolos.
+        # Returns
+        Retorna un `bool` que puede determinar si la palabra es aceptada o no por el autómata.
+    */
+    pub fn run<I: IntoIterator<Item = S>>(&self, input: I) -> bool {
+        self.runner().run(input)
+    }
+
+    // Prepara el autómata para validar varias palabras sin reconstruir sus tablas.
+    pub fn runner(&self) -> Runner<S> {
+        let epsilon = self.epsilon_table();
+        Runner {
+            table: self.transition_table(),
+            start: epsilon_closure(&epsilon, [self.start_index()]),
+            epsilon,
+            accept: self
+                .states
+                .iter()
+                .map(|state| state.borrow().is_accept)
+                .collect(),
+        }
+    }
+}
+
+/**
+ Autómata preparado para la simulación, con los estados representados por posiciones.
+    Guarda la tabla de transiciones, las transiciones ε y la clausura del estado inicial,
+    de modo que validar una palabra no vuelve a recorrer los punteros del autómata.
+*/
+pub struct Runner<S: Symbol = char> {
+    table: Vec<BTreeMap<S, Vec<usize>>>,
+    epsilon: Vec<Vec<usize>>,
+    accept: Vec<bool>,
+    start: BTreeSet<usize>,
+}
+
+impl<S: Symbol> Runner<S> {
+    // Valida si la palabra ingresada es aceptada, igual que `NFA::run`.
+    pub fn run<I: IntoIterator<Item = S>>(&self, input: I) -> bool {
+        let mut current = self.start.clone();
+
+        for symbol in input {
+            current = self.step(&current, &symbol);
+
+            if current.is_empty() {
+                return false;
+            }
+        }
+
+        self.accepts(&current)
+    }
+
+    // Clausura ε del estado inicial.
+    pub(crate) fn start(&self) -> &BTreeSet<usize> {
+        &self.start
+    }
+
+    // Estados alcanzables desde `states` leyendo `symbol`, incluyendo su clausura ε.
+    pub(crate) fn step(&self, states: &BTreeSet<usize>, symbol: &S) -> BTreeSet<usize> {
+        let targets = states
+            .iter()
+            .filter_map(|&state| self.table[state].get(symbol))
+            .flatten()
+            .copied();
+        epsilon_closure(&self.epsilon, targets)
+    }
+
+    // Indica si alguno de los estados es de aceptación.
+    pub(crate) fn accepts(&self, states: &BTreeSet<usize>) -> bool {
+        states.iter().any(|&state| self.accept[state])
+    }
+}
+
+impl<S: Symbol + fmt::Debug + fmt::Display> NFA<S> {
+    // Imprime el conjunto de estados
+    pub fn print_states(&self) {
+        print!("{{");
+        let mut first = true;
+        for state in &self.states {
+            if !first {
+                print!(", ");
+            }
+            print!("{}", state.borrow().state);
+            first = false;
+        }
+        print!("}}");
+    }
+
+    // Imprime el alfabeto
+    pub fn print_alphabet(&self) {
+        print!("{:?}", self.alphabet);
+    }
+
+    // Imprime el estado inicial
+    pub fn print_start_state(&self) {
+        print!("{}", self.start_state.borrow().state);
+    }
+
+    // Imprime el conjunto de estados de aceptación
+    pub fn print_accept_states(&self) {
+        print!("{{");
+        let mut first = true;
+        for state in &self.states {
+            if state.borrow().is_accept {
+                if !first {
+                    print!(", ");
+                }
+                print!("{}", state.borrow().state);
+                first = false;
+            }
+        }
+        print!("}}");
+    }
+
+    // Imprime las transiciones del autómata NFA
+    pub fn print_transitions(&self) {
+        for state in &self.states {
+            let state_borrow = state.borrow();
+            for (symbol, next_states) in &state_borrow.transitions {
+                let next_states_str: Vec<String> = next_states
+                    .iter()
+                    .map(|next_state| next_state.borrow().state.clone())
+                    .collect();
+
+                println!(
+                    "δ({}, {}) = {{{}}}",
+                    state_borrow.state,
+                    symbol,
+                    next_states_str.join(", ")
+                );
+            }
+
+            if !state_borrow.epsilon.is_empty() {
+                let next_states_str: Vec<String> = state_borrow
+                    .epsilon
+                    .iter()
+                    .map(|next_state| next_state.borrow().state.clone())
+                    .collect();
+
+                println!(
+                    "δ({}, ε) = {{{}}}",
+                    state_borrow.state,
+                    next_states_str.join(", ")
+                );
+            }
+        }
+    }
+
+    // Imprime la 5-tupla (Definición formal de un NFA)
+    pub fn tupla(&self) {
+        print!("A = <");
+
+        // Imprimir Q
+        print!("Q = ");
+        self.print_states();
+        print!(", ");
+
+        // Imprimir Σ
+        print!("Σ = ");
+        self.print_alphabet();
+        print!(", ");
+
+        // Imprimir el estado inicial
+        self.print_start_state();
+
+        // Imprimir δ (transiciones) - Asumiendo que tienes una función para esto o lo omites por ahora
+        print!(", δ, ");
+
+        // Imprimir F
+        print!("F = ");
+        self.print_accept_states();
+        println!(">");
+    }
+}
+
+/**
+ Calcula la clausura ε de un conjunto de estados.
+    # Arguments
+    * `epsilon` - Transiciones ε por posición, como las retorna `epsilon_table`.
+    * `states` - Posiciones de los estados de partida.
+    # Returns
+    Retorna las posiciones alcanzables desde `states` usando solo transiciones ε.
+*/
+pub(crate) fn epsilon_closure<I: IntoIterator<Item = usize>>(
+    epsilon: &[Vec<usize>],
+    states: I,
+) -> BTreeSet<usize> {
+    let mut closure = BTreeSet::new();
+    let mut pending: Vec<usize> = states.into_iter().collect();
+
+    while let Some(state) = pending.pop() {
+        if closure.insert(state) {
+            pending.extend(epsilon[state].iter().copied());
+        }
+    }
+
+    closure
+}
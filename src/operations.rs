@@ -0,0 +1,101 @@
+//! Operaciones booleanas sobre los lenguajes de los autómatas: complemento, unión e intersección.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use crate::nfa::{epsilon_closure, Symbol, NFA};
+
+impl<S: Symbol> NFA<S> {
+    // Autómata que acepta las palabras del alfabeto que este rechaza.
+    pub fn complement(&self) -> NFA<S> {
+        let dfa = self.determinize();
+        for state in &dfa.states {
+            let mut state = state.borrow_mut();
+            state.is_accept = !state.is_accept;
+        }
+        dfa
+    }
+
+    // Autómata que acepta las palabras aceptadas por alguno de los dos.
+    pub fn union(&self, other: &NFA<S>) -> NFA<S> {
+        self.product(other, |left, right| left || right)
+    }
+
+    // Autómata que acepta las palabras aceptadas por ambos.
+    pub fn intersection(&self, other: &NFA<S>) -> NFA<S> {
+        self.product(other, |left, right| left && right)
+    }
+
+    /**
+     Construye el producto de las determinizaciones de ambos autómatas sobre la unión de
+     sus alfabetos, limitado a los pares alcanzables.
+        # Arguments
+        * `other` - El otro autómata.
+        * `accept` - Decide si un par acepta a partir de si acepta cada lado.
+        # Returns
+        Retorna un autómata determinista y completo con estados nombrados `(izquierdo, derecho)`.
+    */
+    fn product(&self, other: &NFA<S>, accept: fn(bool, bool) -> bool) -> NFA<S> {
+        let alphabet: BTreeSet<S> = self.alphabet.union(&other.alphabet).cloned().collect();
+        let sides = [self, other].map(|nfa| (nfa, nfa.transition_table(), nfa.epsilon_table()));
+        let step = |side: usize, subset: &BTreeSet<usize>, symbol: &S| {
+            let (_, table, epsilon) = &sides[side];
+            let moved = subset
+                .iter()
+                .filter_map(|&state| table[state].get(symbol))
+                .flatten()
+                .copied();
+            epsilon_closure(epsilon, moved)
+        };
+
+        let start = [0, 1].map(|side| {
+            let (nfa, _, epsilon) = &sides[side];
+            epsilon_closure(epsilon, [nfa.start_index()])
+        });
+        let mut pairs = vec![start.clone()];
+        let mut index = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([0]);
+        let mut edges = Vec::new();
+
+        while let Some(current) = queue.pop_front() {
+            for symbol in &alphabet {
+                let next = [0, 1].map(|side| step(side, &pairs[current][side], symbol));
+                let target = *index.entry(next.clone()).or_insert_with(|| {
+                    pairs.push(next);
+                    queue.push_back(pairs.len() - 1);
+                    pairs.len() - 1
+                });
+                edges.push((current, Some(symbol.clone()), target));
+            }
+        }
+
+        let states = pairs
+            .iter()
+            .map(|pair| {
+                let [left, right] = [0, 1].map(|side| {
+                    let nfa = sides[side].0;
+                    let names: Vec<String> = pair[side]
+                        .iter()
+                        .map(|&state| nfa.states[state].borrow().state.clone())
+                        .collect();
+                    let accepts = pair[side]
+                        .iter()
+                        .any(|&state| nfa.states[state].borrow().is_accept);
+                    let name = if names.is_empty() {
+                        "∅".to_string()
+                    } else {
+                        format!("{{{}}}", names.join(", "))
+                    };
+                    (name, accepts)
+                });
+                (
+                    format!("({}, {})", left.0, right.0),
+                    accept(left.1, right.1),
+                )
+            })
+            .collect();
+
+        let mut nfa = NFA::from_table(states, 0, edges);
+        nfa.alphabet = alphabet;
+        nfa
+    }
+}
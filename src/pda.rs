@@ -0,0 +1,411 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+
+use crate::description::{classify, missing_start, parse_set, parse_symbol, Line, StateNames};
+use crate::error::ParseError;
+
+// Cantidad de configuraciones que explora `run` antes de rendirse.
+pub const DEFAULT_CONFIGURATION_LIMIT: usize = 100_000;
+
+// Criterio de aceptación del autómata de pila.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Acceptance {
+    FinalState,
+    EmptyStack,
+}
+
+/**
+ Configuración instantánea `(estado, entrada restante, pila)`.
+    La pila se guarda con el tope al final del vector.
+*/
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Configuration {
+    pub state: usize,
+    pub position: usize,
+    pub stack: Vec<char>,
+}
+
+// Resultado de la búsqueda de configuraciones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    // La palabra es aceptada; incluye la secuencia de configuraciones desde la inicial.
+    Accepted(Vec<Configuration>),
+    // Se exploraron todas las configuraciones alcanzables sin aceptar.
+    Rejected,
+    // Se alcanzó el límite de configuraciones (por ejemplo, ciclos ε que hacen crecer la pila).
+    LimitReached,
+}
+
+// Transición `δ(from, input, pop) ∋ (to, push)`, donde `None` representa ε.
+struct Transition {
+    input: Option<char>,
+    pop: Option<char>,
+    to: usize,
+    push: Vec<char>,
+}
+
+/**
+ Autómata de pila no determinista.
+    En el formato de descripción las transiciones se escriben `(q, a, X)->{(p, γ), ...}`,
+    donde `γ` se apila con su primer símbolo en el tope y `ε` indica que no se lee,
+    no se desapila o no se apila nada. Además de las líneas de `NFA::try_from_string`
+    acepta `stack_alphabet=`, `start_stack=` y `acceptance=final` o `acceptance=empty`.
+*/
+pub struct PDA {
+    states: StateNames,
+    accept: Vec<bool>,
+    alphabet: BTreeSet<char>,
+    stack_alphabet: BTreeSet<char>,
+    start_state: usize,
+    start_stack: Option<char>,
+    acceptance: Acceptance,
+    transitions: Vec<Vec<Transition>>,
+}
+
+impl PDA {
+    /**
+     Crea un autómata de pila desde el formato de descripción.
+        # Arguments
+        * `description` - Texto con la descripción del autómata.
+        # Returns
+        Retorna el autómata o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut pda = PDA {
+            states: StateNames::default(),
+            accept: Vec::new(),
+            alphabet: BTreeSet::new(),
+            stack_alphabet: BTreeSet::new(),
+            start_state: 0,
+            start_stack: None,
+            acceptance: Acceptance::FinalState,
+            transitions: Vec::new(),
+        };
+        let mut start_state = None;
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+            let line = line.trim();
+
+            // Líneas propias del autómata de pila
+            if let Some(list) = line.strip_prefix("stack_alphabet=") {
+                for symbol in parse_set(list).map_err(error)? {
+                    pda.stack_alphabet
+                        .insert(parse_symbol(symbol).map_err(error)?);
+                }
+                continue;
+            }
+            if let Some(symbol) = line.strip_prefix("start_stack=") {
+                let symbol = parse_symbol(symbol).map_err(error)?;
+                pda.stack_alphabet.insert(symbol);
+                pda.start_stack = Some(symbol);
+                continue;
+            }
+            if let Some(mode) = line.strip_prefix("acceptance=") {
+                pda.acceptance = match mode.trim() {
+                    "final" => Acceptance::FinalState,
+                    "empty" => Acceptance::EmptyStack,
+                    other => {
+                        return Err(error(format!(
+                            "Criterio de aceptación \"{}\" desconocido, use \"final\" o \"empty\".",
+                            other
+                        )))
+                    }
+                };
+                continue;
+            }
+
+            match classify(line).map_err(error)? {
+                Line::Empty => {}
+                Line::Alphabet(list) => {
+                    for symbol in parse_set(list).map_err(error)? {
+                        pda.alphabet.insert(parse_symbol(symbol).map_err(error)?);
+                    }
+                }
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        if pda.states.declare(name).is_some() {
+                            pda.accept.push(false);
+                            pda.transitions.push(Vec::new());
+                        }
+                    }
+                }
+                Line::Start(name) => {
+                    start_state = Some(pda.states.position(name).map_err(with_line)?);
+                }
+                Line::Final(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        let index = pda.states.position(name).map_err(with_line)?;
+                        pda.accept[index] = true;
+                    }
+                }
+                Line::Transition(left, right) => {
+                    let parts: Vec<&str> = left.split(',').map(str::trim).collect();
+                    let (from, input, pop) =
+                        match parts[..] {
+                            [from, input, pop] => (from, input, pop),
+                            _ => return Err(error(
+                                "La transición debe tener la forma \"(estado, símbolo, tope)\"."
+                                    .to_string(),
+                            )),
+                        };
+                    let from = pda.states.position(from).map_err(with_line)?;
+                    let input = parse_optional(input).map_err(error)?;
+                    let pop = parse_optional(pop).map_err(error)?;
+
+                    if let Some(input) = input {
+                        pda.alphabet.insert(input);
+                    }
+                    pda.stack_alphabet.extend(pop);
+
+                    for (to, push) in parse_moves(right).map_err(error)? {
+                        let to = pda.states.position(to).map_err(with_line)?;
+                        let push: Vec<char> = match push {
+                            "ε" => Vec::new(),
+                            push => push.chars().collect(),
+                        };
+                        pda.stack_alphabet.extend(push.iter().copied());
+                        pda.transitions[from].push(Transition {
+                            input,
+                            pop,
+                            to,
+                            push,
+                        });
+                    }
+                }
+            }
+        }
+
+        if pda.states.is_empty() {
+            return Err(ParseError::new(0, "El autómata no tiene estados."));
+        }
+        pda.start_state = start_state.ok_or_else(missing_start)?;
+
+        Ok(pda)
+    }
+
+    // Retorna los nombres de los estados
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    // Retorna el alfabeto de entrada
+    pub fn alphabet(&self) -> &BTreeSet<char> {
+        &self.alphabet
+    }
+
+    // Retorna el alfabeto de la pila
+    pub fn stack_alphabet(&self) -> &BTreeSet<char> {
+        &self.stack_alphabet
+    }
+
+    // Retorna el criterio de aceptación
+    pub fn acceptance(&self) -> Acceptance {
+        self.acceptance
+    }
+
+    // Valida si la palabra es aceptada, explorando a lo más `DEFAULT_CONFIGURATION_LIMIT` configuraciones.
+    pub fn run(&self, input: &str) -> bool {
+        matches!(
+            self.search(input, DEFAULT_CONFIGURATION_LIMIT),
+            Outcome::Accepted(_)
+        )
+    }
+
+    /**
+     Busca en anchura una secuencia de configuraciones que acepte la palabra.
+        Las configuraciones repetidas se descartan, así que los ciclos ε que no cambian
+        la pila terminan; los que la hacen crecer se cortan con el límite.
+        # Arguments
+        * `input` - La palabra a analizar.
+        * `limit` - Cantidad máxima de configuraciones a explorar.
+        # Returns
+        Retorna el resultado de la búsqueda, con la traza de aceptación si existe.
+    */
+    pub fn search(&self, input: &str, limit: usize) -> Outcome {
+        let word: Vec<char> = input.chars().collect();
+        let start = Configuration {
+            state: self.start_state,
+            position: 0,
+            stack: self.start_stack.into_iter().collect(),
+        };
+
+        let mut arena: Vec<(Configuration, Option<usize>)> = vec![(start.clone(), None)];
+        let mut seen: HashSet<Configuration> = HashSet::from([start]);
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            let configuration = arena[current].0.clone();
+
+            if self.is_accepting(&configuration, word.len()) {
+                let mut trace = Vec::new();
+                let mut node = Some(current);
+                while let Some(index) = node {
+                    trace.push(arena[index].0.clone());
+                    node = arena[index].1;
+                }
+                trace.reverse();
+                return Outcome::Accepted(trace);
+            }
+
+            for next in self.successors(&configuration, &word) {
+                if seen.contains(&next) {
+                    continue;
+                }
+                if arena.len() >= limit {
+                    return Outcome::LimitReached;
+                }
+                seen.insert(next.clone());
+                arena.push((next, Some(current)));
+                queue.push_back(arena.len() - 1);
+            }
+        }
+
+        Outcome::Rejected
+    }
+
+    // Imprime las transiciones del autómata de pila
+    pub fn print_transitions(&self) {
+        for (state, row) in self.states.iter().zip(&self.transitions) {
+            let mut grouped: BTreeMap<(Option<char>, Option<char>), Vec<String>> = BTreeMap::new();
+            for transition in row {
+                grouped
+                    .entry((transition.input, transition.pop))
+                    .or_default()
+                    .push(format!(
+                        "({}, {})",
+                        self.states[transition.to],
+                        word_or_epsilon(&transition.push)
+                    ));
+            }
+            for ((input, pop), targets) in grouped {
+                println!(
+                    "δ({}, {}, {}) = {{{}}}",
+                    state,
+                    symbol_or_epsilon(input),
+                    symbol_or_epsilon(pop),
+                    targets.join(", ")
+                );
+            }
+        }
+    }
+
+    /**
+     Imprime una traza de configuraciones como `(q0, ab, Z) ⊢ (q0, b, AZ) ⊢ ...`.
+        # Arguments
+        * `input` - La palabra con la que se obtuvo la traza.
+        * `trace` - Configuraciones retornadas en `Outcome::Accepted`.
+    */
+    pub fn print_trace(&self, input: &str, trace: &[Configuration]) {
+        let word: Vec<char> = input.chars().collect();
+        let steps: Vec<String> = trace
+            .iter()
+            .map(|configuration| {
+                let rest: Vec<char> = word[configuration.position.min(word.len())..].to_vec();
+                let stack: Vec<char> = configuration.stack.iter().rev().copied().collect();
+                format!(
+                    "({}, {}, {})",
+                    self.states[configuration.state],
+                    word_or_epsilon(&rest),
+                    word_or_epsilon(&stack)
+                )
+            })
+            .collect();
+        println!("{}", steps.join(" ⊢ "));
+    }
+
+    fn is_accepting(&self, configuration: &Configuration, length: usize) -> bool {
+        configuration.position == length
+            && match self.acceptance {
+                Acceptance::FinalState => self.accept[configuration.state],
+                Acceptance::EmptyStack => configuration.stack.is_empty(),
+            }
+    }
+
+    // Configuraciones alcanzables en un paso.
+    fn successors(&self, configuration: &Configuration, word: &[char]) -> Vec<Configuration> {
+        let mut next = Vec::new();
+
+        for transition in &self.transitions[configuration.state] {
+            let position = match transition.input {
+                None => configuration.position,
+                Some(symbol) if word.get(configuration.position) == Some(&symbol) => {
+                    configuration.position + 1
+                }
+                Some(_) => continue,
+            };
+
+            let mut stack = configuration.stack.clone();
+            if let Some(top) = transition.pop {
+                if stack.pop() != Some(top) {
+                    continue;
+                }
+            }
+            stack.extend(transition.push.iter().rev());
+
+            next.push(Configuration {
+                state: transition.to,
+                position,
+                stack,
+            });
+        }
+
+        next
+    }
+}
+
+// Interpreta un símbolo que puede ser `ε`.
+fn parse_optional(text: &str) -> Result<Option<char>, String> {
+    match text.trim() {
+        "ε" => Ok(None),
+        symbol => parse_symbol(symbol).map(Some),
+    }
+}
+
+// Interpreta la parte derecha `{(p, γ), (r, ε)}`.
+fn parse_moves(text: &str) -> Result<Vec<(&str, &str)>, String> {
+    let inner = text
+        .trim()
+        .strip_prefix('{')
+        .and_then(|t| t.strip_suffix('}'))
+        .ok_or_else(|| {
+            format!(
+                "Se esperaba un conjunto entre llaves y se encontró \"{}\".",
+                text
+            )
+        })?;
+
+    let mut moves = Vec::new();
+    let mut rest = inner.trim();
+    while !rest.is_empty() {
+        let close = rest
+            .find(')')
+            .filter(|_| rest.starts_with('('))
+            .ok_or_else(|| format!("Se esperaba un par \"(estado, pila)\" en \"{}\".", rest))?;
+        let (state, push) = rest[1..close]
+            .split_once(',')
+            .ok_or_else(|| format!("Falta la coma en \"{}\".", &rest[..=close]))?;
+        moves.push((state.trim(), push.trim()));
+
+        rest = rest[close + 1..].trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+
+    Ok(moves)
+}
+
+fn symbol_or_epsilon(symbol: Option<char>) -> String {
+    symbol.map_or("ε".to_string(), |symbol| symbol.to_string())
+}
+
+fn word_or_epsilon(word: &[char]) -> String {
+    if word.is_empty() {
+        "ε".to_string()
+    } else {
+        word.iter().collect()
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::description::{classify, split_state_symbol, Line};
+use crate::error::ParseError;
+use crate::nfa::Symbol;
+use crate::random::Rng;
+use crate::weighted::{Probability, Semiring, WeightedNFA};
+
+// Tolerancia al comparar sumas de probabilidades con 1.
+const TOLERANCE: f64 = 1e-9;
+
+// Parte de la descripción responsable de que un autómata no sea un PFA.
+enum Culprit<S> {
+    // El peso final del estado.
+    Final,
+    // La distribución de todas las transiciones del estado.
+    Transitions,
+    // Una transición del estado con el símbolo.
+    Transition(S),
+}
+
+/**
+ Autómata finito probabilístico (PFA) que genera palabras como una cadena de Markov.
+    Usa el mismo formato que `WeightedNFA<Probability>`, por ejemplo
+    `(q0, a)->{q0:0.3, q1:0.2}` y `(q0, b)->{q1:0.5}`, con la condición de que las
+    probabilidades de todas las transiciones de un estado, sumando sobre todo el alfabeto,
+    sumen 1. Un estado sin transiciones termina el recorrido. Los estados de `F=` no llevan peso.
+*/
+pub struct PFA<S: Symbol = char> {
+    automaton: WeightedNFA<Probability, S>,
+}
+
+impl<S: Symbol> PFA<S> {
+    /**
+     Valida un autómata con probabilidades y lo convierte en un PFA.
+        # Arguments
+        * `automaton` - Autómata cuyas transiciones llevan probabilidades.
+        # Returns
+        Retorna un error si las probabilidades de las transiciones de algún estado no suman 1
+        o si algún estado de aceptación tiene un peso distinto de 1.
+    */
+    pub fn from_weighted(automaton: WeightedNFA<Probability, S>) -> Result<Self, ParseError> {
+        match PFA::check(&automaton) {
+            Some((state, _, message)) => Err(ParseError::new(
+                0,
+                format!("Estado \"{}\": {}", automaton.states[state], message),
+            )),
+            None => Ok(PFA { automaton }),
+        }
+    }
+
+    // Vista del PFA como autómata con pesos.
+    pub fn as_weighted(&self) -> &WeightedNFA<Probability, S> {
+        &self.automaton
+    }
+
+    /**
+     Calcula la probabilidad de la palabra: la suma, sobre los caminos que la leen y terminan
+     en un estado de aceptación, del producto de las probabilidades de sus transiciones.
+        # Arguments
+        * `input` - La palabra a evaluar.
+        # Returns
+        Retorna un valor entre 0 y 1.
+    */
+    pub fn acceptance_probability<I: IntoIterator<Item = S>>(&self, input: I) -> f64 {
+        self.automaton.weight(input).0
+    }
+
+    /**
+     Genera una palabra al azar recorriendo el autómata como una cadena de Markov.
+        En cada paso, si el estado actual es de aceptación se termina con probabilidad
+        `stop_probability`; si no, se elige una transición `(símbolo, destino)` según su
+        probabilidad. El recorrido también termina al llegar a `max_length` símbolos o a un
+        estado sin transiciones.
+        # Arguments
+        * `rng` - Generador pseudoaleatorio.
+        * `stop_probability` - Probabilidad de detenerse en un estado de aceptación.
+        * `max_length` - Longitud máxima de la palabra.
+        # Returns
+        Retorna la palabra generada y si el recorrido terminó en un estado de aceptación.
+    */
+    pub fn sample(
+        &self,
+        rng: &mut Rng,
+        stop_probability: f64,
+        max_length: usize,
+    ) -> (Vec<S>, bool) {
+        let automaton = &self.automaton;
+        let mut state = automaton.start_state;
+        let mut word = Vec::new();
+
+        loop {
+            let is_accept = automaton.finals[state] != Probability::zero();
+            let transitions = &automaton.transitions[state];
+
+            if word.len() >= max_length
+                || transitions.is_empty()
+                || (is_accept && rng.chance(stop_probability))
+            {
+                return (word, is_accept);
+            }
+
+            // Si el redondeo deja la suma bajo el valor elegido se usa la última transición posible
+            let mut target = rng.next_f64();
+            let (symbol, _, next) = transitions
+                .iter()
+                .find(|(_, probability, _)| {
+                    target -= probability.0;
+                    target < 0.0
+                })
+                .or_else(|| {
+                    transitions
+                        .iter()
+                        .rfind(|(_, p, _)| *p != Probability::zero())
+                })
+                .expect("las probabilidades de cada estado suman 1");
+
+            word.push(symbol.clone());
+            state = *next;
+        }
+    }
+
+    // Busca el primer estado que no cumple las condiciones de un PFA y la parte responsable.
+    fn check(automaton: &WeightedNFA<Probability, S>) -> Option<(usize, Culprit<S>, String)> {
+        for (state, row) in automaton.transitions.iter().enumerate() {
+            let final_weight = automaton.finals[state].0;
+            if final_weight != 0.0 && final_weight != 1.0 {
+                return Some((
+                    state,
+                    Culprit::Final,
+                    format!("el peso final debe ser 0 o 1 y es {}.", final_weight),
+                ));
+            }
+
+            for (symbol, probability, _) in row {
+                if probability.0 > 1.0 {
+                    return Some((
+                        state,
+                        Culprit::Transition(symbol.clone()),
+                        format!("la probabilidad {} es mayor que 1.", probability.0),
+                    ));
+                }
+            }
+            let sum: f64 = row.iter().map(|(_, probability, _)| probability.0).sum();
+            if !row.is_empty() && (sum - 1.0).abs() > TOLERANCE {
+                return Some((
+                    state,
+                    Culprit::Transitions,
+                    format!(
+                        "las probabilidades de sus transiciones suman {} y deben sumar 1.",
+                        sum
+                    ),
+                ));
+            }
+        }
+        None
+    }
+}
+
+impl<S: Symbol + fmt::Display> PFA<S> {
+    // Imprime las transiciones con sus probabilidades
+    pub fn print_transitions(&self) {
+        self.automaton.print_transitions();
+    }
+}
+
+impl PFA<char> {
+    /**
+     Crea un PFA desde el formato de descripción, validando las probabilidades.
+        # Arguments
+        * `description` - Texto con transiciones como `(q0, a)->{q0:0.3, q1:0.7}`.
+        # Returns
+        Retorna el PFA o un error que indica la línea de la transición inválida.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let automaton = WeightedNFA::<Probability>::from_string(description)?;
+
+        let (state, culprit, message) = match PFA::check(&automaton) {
+            Some(problem) => problem,
+            None => return Ok(PFA { automaton }),
+        };
+        let name = &automaton.states[state];
+
+        // Ubicar la primera línea que define la transición (o los estados finales)
+        let line = description
+            .lines()
+            .position(|line| match (classify(line.trim()), &culprit) {
+                (Ok(Line::Transition(left, _)), Culprit::Transition(symbol)) => {
+                    split_state_symbol(left).is_ok_and(|(s, c)| s == name && c == *symbol)
+                }
+                (Ok(Line::Transition(left, _)), Culprit::Transitions) => {
+                    split_state_symbol(left).is_ok_and(|(s, _)| s == name)
+                }
+                (Ok(Line::Final(_)), Culprit::Final) => true,
+                _ => false,
+            })
+            .map_or(0, |line| line + 1);
+
+        let context = match culprit {
+            Culprit::Transition(symbol) => format!("δ({}, {})", name, symbol),
+            Culprit::Final | Culprit::Transitions => format!("Estado \"{}\"", name),
+        };
+        Err(ParseError::new(line, format!("{}: {}", context, message)))
+    }
+}
@@ -0,0 +1,198 @@
+//! Demostración del lema de bombeo para lenguajes regulares sobre un recorrido de aceptación.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::nfa::{Symbol, NFA};
+
+// Una palabra bombeada `x y^i z` y si el autómata la acepta.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PumpedWord<S> {
+    pub power: usize,
+    pub word: Vec<S>,
+    pub accepted: bool,
+}
+
+// Descomposición `xyz` de una palabra aceptada y las palabras bombeadas a partir de ella.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pumping<S> {
+    // Estado alcanzado después de leer cada prefijo de la palabra, empezando por el inicial.
+    pub run: Vec<String>,
+    // Estado que se repite al terminar de leer `x` y al terminar de leer `xy`.
+    pub state: String,
+    pub x: Vec<S>,
+    pub y: Vec<S>,
+    pub z: Vec<S>,
+    pub examples: Vec<PumpedWord<S>>,
+}
+
+impl<S: Symbol> NFA<S> {
+    /**
+     Descompone una palabra aceptada como `xyz` según el lema de bombeo.
+        Se busca un recorrido de aceptación y, entre los estados alcanzados después de
+        leer los primeros símbolos, el primero que se repite: el tramo entre ambas
+        apariciones es `y`, por lo que `|xy| ≤ |Q|` y `|y| ≥ 1`.
+        # Arguments
+        * `word` - Palabra aceptada de largo al menos la cantidad de estados.
+        * `powers` - Valores de `i` para los que se construye y valida `x y^i z` con `run`.
+        # Returns
+        Retorna la descomposición con las palabras bombeadas, o un error si la palabra
+        es demasiado corta o no es aceptada.
+    */
+    pub fn pump(&self, word: &[S], powers: &[usize]) -> Result<Pumping<S>, String> {
+        if word.len() < self.states.len() {
+            return Err(format!(
+                "La palabra tiene largo {} y se necesita al menos {} (la cantidad de estados).",
+                word.len(),
+                self.states.len()
+            ));
+        }
+        let run = self
+            .accepting_run(word)
+            .ok_or("La palabra no es aceptada por el autómata.")?;
+
+        // Por el principio del palomar algún estado se repite entre los primeros |Q| + 1
+        let mut first_seen = vec![None; self.states.len()];
+        let (start, end) = run
+            .iter()
+            .enumerate()
+            .find_map(|(position, &state)| match first_seen[state] {
+                Some(previous) => Some((previous, position)),
+                None => {
+                    first_seen[state] = Some(position);
+                    None
+                }
+            })
+            .expect("una palabra de largo |Q| repite algún estado en su recorrido");
+
+        let (x, rest) = word.split_at(start);
+        let (y, z) = rest.split_at(end - start);
+        let runner = self.runner();
+        let examples = powers
+            .iter()
+            .map(|&power| {
+                let mut pumped = x.to_vec();
+                for _ in 0..power {
+                    pumped.extend_from_slice(y);
+                }
+                pumped.extend_from_slice(z);
+                PumpedWord {
+                    power,
+                    accepted: runner.run(pumped.iter().cloned()),
+                    word: pumped,
+                }
+            })
+            .collect();
+
+        let name = |state: usize| self.states[state].borrow().state.clone();
+        Ok(Pumping {
+            run: run.iter().map(|&state| name(state)).collect(),
+            state: name(run[start]),
+            x: x.to_vec(),
+            y: y.to_vec(),
+            z: z.to_vec(),
+            examples,
+        })
+    }
+
+    /**
+     Busca un recorrido de aceptación de la palabra, recorriendo en anchura las
+     configuraciones `(símbolos leídos, estado)`.
+        # Arguments
+        * `word` - La palabra a recorrer.
+        # Returns
+        Retorna el estado alcanzado al leer cada símbolo, precedido del estado inicial,
+        o `None` si la palabra no es aceptada.
+    */
+    fn accepting_run(&self, word: &[S]) -> Option<Vec<usize>> {
+        let table = self.transition_table();
+        let epsilon = self.epsilon_table();
+        let count = self.states.len();
+        let configuration = |read: usize, state: usize| read * count + state;
+
+        let mut parent = vec![None; (word.len() + 1) * count];
+        let mut visited = vec![false; parent.len()];
+        let start = self.start_index();
+        visited[configuration(0, start)] = true;
+        let mut queue = VecDeque::from([(0, start)]);
+
+        while let Some((read, state)) = queue.pop_front() {
+            if read == word.len() && self.states[state].borrow().is_accept {
+                // Reconstruye el camino y guarda el estado al que llega cada símbolo
+                let mut run = vec![start; word.len() + 1];
+                let mut current = (read, state);
+                while let Some(previous) = parent[configuration(current.0, current.1)] {
+                    let (previous_read, _) = previous;
+                    if previous_read < current.0 {
+                        run[current.0] = current.1;
+                    }
+                    current = previous;
+                }
+                return Some(run);
+            }
+
+            let moves = epsilon[state].iter().map(|&next| (read, next));
+            let symbol_moves = word
+                .get(read)
+                .and_then(|symbol| table[state].get(symbol))
+                .into_iter()
+                .flatten()
+                .map(|&next| (read + 1, next));
+            for next in moves.chain(symbol_moves) {
+                let index = configuration(next.0, next.1);
+                if !visited[index] {
+                    visited[index] = true;
+                    parent[index] = Some((read, state));
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Pumping<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Recorrido de aceptación: {}", self.run.join(" → "))?;
+        writeln!(
+            f,
+            "Estado repetido: {} (después de leer {} y {} símbolos)",
+            self.state,
+            self.x.len(),
+            self.x.len() + self.y.len()
+        )?;
+        writeln!(
+            f,
+            "x = {}, y = {}, z = {} (|xy| = {}, |y| = {})",
+            word_text(&self.x),
+            word_text(&self.y),
+            word_text(&self.z),
+            self.x.len() + self.y.len(),
+            self.y.len()
+        )?;
+        for example in &self.examples {
+            writeln!(
+                f,
+                "i = {}: x y^{} z = {} → {}",
+                example.power,
+                example.power,
+                word_text(&example.word),
+                if example.accepted {
+                    "aceptada"
+                } else {
+                    "rechazada"
+                }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Muestra una palabra, usando `ε` para la vacía.
+fn word_text<S: fmt::Display>(word: &[S]) -> String {
+    if word.is_empty() {
+        "ε".to_string()
+    } else {
+        word.iter().map(ToString::to_string).collect()
+    }
+}
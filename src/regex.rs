@@ -0,0 +1,366 @@
+//! Expresiones regulares y su traducción a `NFA` con la construcción de Thompson.
+//!
+//! Sintaxis: concatenación por yuxtaposición, `|` para la unión, `*`, `+` y `?` como
+//! operadores posfijos, paréntesis para agrupar, `ε` para la palabra vacía, `∅` para el
+//! lenguaje vacío y `\` para usar un operador como símbolo. Los espacios se ignoran.
+
+use std::fmt;
+
+use crate::error::ParseError;
+use crate::nfa::NFA;
+
+/**
+ Máxima profundidad del árbol de una expresión.
+    Todas las operaciones sobre `Regex` son recursivas, así que el analizador rechaza las
+    expresiones más profundas en vez de agotar la pila. Las cadenas de concatenaciones,
+    uniones y operadores posfijos también cuentan: `aaa` tiene profundidad 3.
+*/
+pub const MAX_DEPTH: usize = 512;
+
+// Máxima cantidad de paréntesis anidados. `Display` escribe un par de paréntesis solo por
+// cada dos niveles del árbol, así que toda expresión aceptada se vuelve a aceptar escrita.
+const MAX_NESTING: usize = MAX_DEPTH / 2;
+
+// Árbol de una expresión regular.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Regex {
+    Empty,
+    Epsilon,
+    Symbol(char),
+    Concat(Box<Regex>, Box<Regex>),
+    Union(Box<Regex>, Box<Regex>),
+    Star(Box<Regex>),
+    // Una o más repeticiones; se representa aparte para no duplicar la subexpresión.
+    Plus(Box<Regex>),
+}
+
+impl Regex {
+    /**
+     Interpreta una expresión regular.
+        # Arguments
+        * `text` - La expresión, por ejemplo `(0|1)*1`.
+        # Returns
+        Retorna el árbol de la expresión o un error que indica la posición del problema.
+    */
+    pub fn parse(text: &str) -> Result<Regex, ParseError> {
+        let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let mut parser = Parser {
+            chars: &chars,
+            position: 0,
+            nesting: 0,
+        };
+        let (regex, _) = parser.union()?;
+        match parser.peek() {
+            None => Ok(regex),
+            Some(c) => Err(parser.error(format!("Carácter inesperado \"{}\".", c))),
+        }
+    }
+
+    /**
+     Construye un `NFA` con transiciones ε equivalente a la expresión (construcción de Thompson).
+        # Returns
+        Retorna el autómata, con estados `q0`, `q1`, ... y un único estado de aceptación.
+    */
+    pub fn to_nfa(&self) -> NFA<char> {
+        let mut builder = Thompson {
+            count: 0,
+            edges: Vec::new(),
+        };
+        let (start, end) = builder.fragment(self);
+
+        let states = (0..builder.count)
+            .map(|index| (format!("q{}", index), index == end))
+            .collect();
+        NFA::from_table(states, start, builder.edges)
+    }
+
+    /**
+     Valida una palabra directamente sobre la expresión usando derivadas de Brzozowski,
+     sin construir un autómata. Sirve como referencia independiente de `to_nfa`.
+        # Arguments
+        * `word` - La palabra a validar.
+        # Returns
+        Retorna `true` si la palabra pertenece al lenguaje de la expresión.
+    */
+    pub fn matches(&self, word: &str) -> bool {
+        let mut current = self.clone();
+        for c in word.chars() {
+            current = current.derivative(c);
+            if current == Regex::Empty {
+                return false;
+            }
+        }
+        current.nullable()
+    }
+
+    // Indica si la palabra vacía pertenece al lenguaje.
+    pub fn nullable(&self) -> bool {
+        match self {
+            Regex::Empty | Regex::Symbol(_) => false,
+            Regex::Epsilon | Regex::Star(_) => true,
+            Regex::Plus(inner) => inner.nullable(),
+            Regex::Concat(left, right) => left.nullable() && right.nullable(),
+            Regex::Union(left, right) => left.nullable() || right.nullable(),
+        }
+    }
+
+    // Derivada respecto del símbolo `c`: el lenguaje de los sufijos de las palabras que empiezan con `c`.
+    pub fn derivative(&self, c: char) -> Regex {
+        match self {
+            Regex::Empty | Regex::Epsilon => Regex::Empty,
+            Regex::Symbol(symbol) if *symbol == c => Regex::Epsilon,
+            Regex::Symbol(_) => Regex::Empty,
+            Regex::Concat(left, right) => {
+                let first = concat(left.derivative(c), (**right).clone());
+                if left.nullable() {
+                    union(first, right.derivative(c))
+                } else {
+                    first
+                }
+            }
+            Regex::Union(left, right) => union(left.derivative(c), right.derivative(c)),
+            Regex::Star(inner) => concat(inner.derivative(c), self.clone()),
+            Regex::Plus(inner) => concat(inner.derivative(c), Regex::Star(inner.clone())),
+        }
+    }
+}
+
+impl NFA<char> {
+    // Construye un autómata desde una expresión regular.
+    pub fn from_regex(text: &str) -> Result<Self, ParseError> {
+        Regex::parse(text).map(|regex| regex.to_nfa())
+    }
+}
+
+impl fmt::Display for Regex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Regex::Empty => write!(f, "∅"),
+            Regex::Epsilon => write!(f, "ε"),
+            Regex::Symbol(c) if "|*+?()\\ε∅".contains(*c) => write!(f, "\\{}", c),
+            Regex::Symbol(c) => write!(f, "{}", c),
+            Regex::Concat(left, right) => {
+                for side in [left, right] {
+                    match **side {
+                        Regex::Union(..) => write!(f, "({})", side)?,
+                        _ => write!(f, "{}", side)?,
+                    }
+                }
+                Ok(())
+            }
+            Regex::Union(left, right) => write!(f, "{}|{}", left, right),
+            Regex::Star(inner) => match **inner {
+                Regex::Concat(..) | Regex::Union(..) => write!(f, "({})*", inner),
+                _ => write!(f, "{}*", inner),
+            },
+            Regex::Plus(inner) => match **inner {
+                Regex::Concat(..) | Regex::Union(..) => write!(f, "({})+", inner),
+                _ => write!(f, "{}+", inner),
+            },
+        }
+    }
+}
+
+// Concatenación que simplifica `∅` y `ε`.
+fn concat(left: Regex, right: Regex) -> Regex {
+    match (left, right) {
+        (Regex::Empty, _) | (_, Regex::Empty) => Regex::Empty,
+        (Regex::Epsilon, other) | (other, Regex::Epsilon) => other,
+        (left, right) => Regex::Concat(Box::new(left), Box::new(right)),
+    }
+}
+
+// Unión que simplifica `∅` y las ramas iguales.
+fn union(left: Regex, right: Regex) -> Regex {
+    match (left, right) {
+        (Regex::Empty, other) | (other, Regex::Empty) => other,
+        (left, right) if left == right => left,
+        (left, right) => Regex::Union(Box::new(left), Box::new(right)),
+    }
+}
+
+// Analizador descendente recursivo de la sintaxis de las expresiones.
+struct Parser<'a> {
+    chars: &'a [char],
+    position: usize,
+    // Paréntesis abiertos en la posición actual
+    nesting: usize,
+}
+
+// Subárbol ya analizado junto con su profundidad.
+type Parsed = (Regex, usize);
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn error(&self, message: String) -> ParseError {
+        ParseError::new(0, format!("posición {}: {}", self.position + 1, message))
+    }
+
+    // Crea un nodo interno verificando que no supere `MAX_DEPTH`.
+    fn node(&self, regex: Regex, depth: usize) -> Result<Parsed, ParseError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error(format!(
+                "La expresión tiene más de {} niveles de anidación.",
+                MAX_DEPTH
+            )));
+        }
+        Ok((regex, depth))
+    }
+
+    // union := concatenación ('|' concatenación)*
+    fn union(&mut self) -> Result<Parsed, ParseError> {
+        let (mut regex, mut depth) = self.concatenation()?;
+        while self.peek() == Some('|') {
+            self.position += 1;
+            let (right, right_depth) = self.concatenation()?;
+            (regex, depth) = self.node(
+                Regex::Union(Box::new(regex), Box::new(right)),
+                1 + depth.max(right_depth),
+            )?;
+        }
+        Ok((regex, depth))
+    }
+
+    // concatenación := repetición*, vacía equivale a ε
+    fn concatenation(&mut self) -> Result<Parsed, ParseError> {
+        let mut parsed: Option<Parsed> = None;
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let (next, next_depth) = self.repetition()?;
+            parsed = Some(match parsed {
+                Some((left, depth)) => self.node(
+                    Regex::Concat(Box::new(left), Box::new(next)),
+                    1 + depth.max(next_depth),
+                )?,
+                None => (next, next_depth),
+            });
+        }
+        Ok(parsed.unwrap_or((Regex::Epsilon, 1)))
+    }
+
+    // repetición := átomo ('*' | '+' | '?')*
+    fn repetition(&mut self) -> Result<Parsed, ParseError> {
+        let (mut regex, mut depth) = self.atom()?;
+        while let Some(c) = self.peek() {
+            let repeated = match c {
+                '*' => Regex::Star(Box::new(regex)),
+                '+' => Regex::Plus(Box::new(regex)),
+                '?' => Regex::Union(Box::new(regex), Box::new(Regex::Epsilon)),
+                _ => break,
+            };
+            (regex, depth) = self.node(repeated, depth + 1)?;
+            self.position += 1;
+        }
+        Ok((regex, depth))
+    }
+
+    // átomo := símbolo | '\' símbolo | 'ε' | '∅' | '(' union ')'
+    fn atom(&mut self) -> Result<Parsed, ParseError> {
+        let c = self
+            .peek()
+            .ok_or_else(|| self.error("La expresión termina de forma inesperada.".to_string()))?;
+        self.position += 1;
+
+        match c {
+            '(' => {
+                self.nesting += 1;
+                if self.nesting > MAX_NESTING {
+                    return Err(
+                        self.error(format!("Hay más de {} paréntesis anidados.", MAX_NESTING))
+                    );
+                }
+                let parsed = self.union()?;
+                if self.peek() != Some(')') {
+                    return Err(self.error("Falta cerrar un paréntesis.".to_string()));
+                }
+                self.nesting -= 1;
+                self.position += 1;
+                Ok(parsed)
+            }
+            '\\' => {
+                let escaped = self
+                    .peek()
+                    .ok_or_else(|| self.error("Falta el símbolo después de \"\\\".".to_string()))?;
+                self.position += 1;
+                Ok((Regex::Symbol(escaped), 1))
+            }
+            'ε' => Ok((Regex::Epsilon, 1)),
+            '∅' => Ok((Regex::Empty, 1)),
+            '*' | '+' | '?' | ')' => {
+                self.position -= 1;
+                Err(self.error(format!("Carácter inesperado \"{}\".", c)))
+            }
+            c => Ok((Regex::Symbol(c), 1)),
+        }
+    }
+}
+
+// Construcción de Thompson: cada fragmento tiene un estado de entrada y uno de salida.
+struct Thompson {
+    count: usize,
+    edges: Vec<(usize, Option<char>, usize)>,
+}
+
+impl Thompson {
+    fn state(&mut self) -> usize {
+        self.count += 1;
+        self.count - 1
+    }
+
+    fn fragment(&mut self, regex: &Regex) -> (usize, usize) {
+        match regex {
+            Regex::Empty => (self.state(), self.state()),
+            Regex::Epsilon => {
+                let (start, end) = (self.state(), self.state());
+                self.edges.push((start, None, end));
+                (start, end)
+            }
+            Regex::Symbol(c) => {
+                let (start, end) = (self.state(), self.state());
+                self.edges.push((start, Some(*c), end));
+                (start, end)
+            }
+            Regex::Concat(left, right) => {
+                let (start, middle) = self.fragment(left);
+                let (next, end) = self.fragment(right);
+                self.edges.push((middle, None, next));
+                (start, end)
+            }
+            Regex::Union(left, right) => {
+                let start = self.state();
+                let (left_start, left_end) = self.fragment(left);
+                let (right_start, right_end) = self.fragment(right);
+                let end = self.state();
+                self.edges.push((start, None, left_start));
+                self.edges.push((start, None, right_start));
+                self.edges.push((left_end, None, end));
+                self.edges.push((right_end, None, end));
+                (start, end)
+            }
+            Regex::Star(inner) => {
+                let start = self.state();
+                let (inner_start, inner_end) = self.fragment(inner);
+                let end = self.state();
+                self.edges.push((start, None, inner_start));
+                self.edges.push((start, None, end));
+                self.edges.push((inner_end, None, inner_start));
+                self.edges.push((inner_end, None, end));
+                (start, end)
+            }
+            Regex::Plus(inner) => {
+                let start = self.state();
+                let (inner_start, inner_end) = self.fragment(inner);
+                let end = self.state();
+                self.edges.push((start, None, inner_start));
+                self.edges.push((inner_end, None, inner_start));
+                self.edges.push((inner_end, None, end));
+                (start, end)
+            }
+        }
+    }
+}
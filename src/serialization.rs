@@ -0,0 +1,290 @@
+//! Serialización de autómatas a JSON y YAML (requiere la feature `serde`).
+//!
+//! Esquema del documento, versión 1:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "name": "termina en 1",
+//!   "description": "Palabras binarias que terminan en 1",
+//!   "states": ["q0", "q1"],
+//!   "alphabet": ["0", "1"],
+//!   "start": "q0",
+//!   "accepting": ["q1"],
+//!   "transitions": [
+//!     { "from": "q0", "symbol": "0", "to": ["q0"] },
+//!     { "from": "q0", "symbol": "1", "to": ["q0", "q1"] },
+//!     { "from": "q1", "to": ["q0"] }
+//!   ]
+//! }
+//! ```
+//!
+//! - `version`, `name` y `description` son opcionales; `version` vale 1 si se omite.
+//! - Cada símbolo es una cadena de un solo carácter. Una transición sin `symbol`
+//!   (o con `null`) es una transición ε.
+//! - No se admiten campos desconocidos.
+
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::nfa::NFA;
+
+// Versión del esquema que produce y acepta este módulo.
+pub const SCHEMA_VERSION: u32 = 1;
+
+// Documento serializable que describe un autómata.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AutomatonDocument {
+    #[serde(default = "default_version")]
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub states: Vec<String>,
+    pub alphabet: Vec<String>,
+    pub start: String,
+    pub accepting: Vec<String>,
+    pub transitions: Vec<TransitionDocument>,
+}
+
+// Transición `δ(from, symbol) = to` del documento.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransitionDocument {
+    pub from: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    pub to: Vec<String>,
+}
+
+fn default_version() -> u32 {
+    SCHEMA_VERSION
+}
+
+/**
+ Error al leer un documento: indica la ruta del campo con problemas,
+ como `transitions[2].to[0]`, o queda vacía si el problema es del documento completo.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    pub path: String,
+    pub message: String,
+}
+
+impl SchemaError {
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        SchemaError {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() || self.path == "." {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+impl AutomatonDocument {
+    /**
+     Crea el documento de un autómata, sin metadatos.
+        # Arguments
+        * `nfa` - El autómata a describir.
+        # Returns
+        Retorna el documento con las transiciones en el orden de los estados.
+    */
+    pub fn from_nfa(nfa: &NFA<char>) -> Self {
+        let name = |index: usize| nfa.states[index].borrow().state.clone();
+        let epsilon = nfa.epsilon_table();
+        let mut transitions = Vec::new();
+
+        for (from, row) in nfa.transition_table().into_iter().enumerate() {
+            for (symbol, targets) in row {
+                transitions.push(TransitionDocument {
+                    from: name(from),
+                    symbol: Some(symbol.to_string()),
+                    to: targets.into_iter().map(name).collect(),
+                });
+            }
+            if !epsilon[from].is_empty() {
+                transitions.push(TransitionDocument {
+                    from: name(from),
+                    symbol: None,
+                    to: epsilon[from].iter().copied().map(name).collect(),
+                });
+            }
+        }
+
+        AutomatonDocument {
+            version: SCHEMA_VERSION,
+            name: None,
+            description: None,
+            states: (0..nfa.states.len()).map(name).collect(),
+            alphabet: nfa.alphabet.iter().map(char::to_string).collect(),
+            start: name(nfa.start_index()),
+            accepting: nfa
+                .states
+                .iter()
+                .filter(|state| state.borrow().is_accept)
+                .map(|state| state.borrow().state.clone())
+                .collect(),
+            transitions,
+        }
+    }
+
+    /**
+     Valida el documento y construye el autómata.
+        # Returns
+        Retorna el `NFA` o el primer error encontrado, con la ruta del campo.
+    */
+    pub fn to_nfa(&self) -> Result<NFA<char>, SchemaError> {
+        if self.version != SCHEMA_VERSION {
+            return Err(SchemaError::new(
+                "version",
+                format!(
+                    "Versión {} no soportada, se esperaba {}.",
+                    self.version, SCHEMA_VERSION
+                ),
+            ));
+        }
+        if self.states.is_empty() {
+            return Err(SchemaError::new(
+                "states",
+                "El autómata debe tener al menos un estado.",
+            ));
+        }
+
+        let mut index = HashMap::new();
+        for (i, state) in self.states.iter().enumerate() {
+            if index.insert(state.as_str(), i).is_some() {
+                return Err(SchemaError::new(
+                    format!("states[{}]", i),
+                    format!("El estado \"{}\" está repetido.", state),
+                ));
+            }
+        }
+        let lookup = |name: &str, path: String| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| SchemaError::new(path, format!("El estado \"{}\" no existe.", name)))
+        };
+
+        let mut alphabet = BTreeSet::new();
+        for (i, symbol) in self.alphabet.iter().enumerate() {
+            alphabet.insert(parse_symbol(symbol, format!("alphabet[{}]", i))?);
+        }
+
+        let start = lookup(&self.start, "start".to_string())?;
+        let mut accepting = vec![false; self.states.len()];
+        for (i, name) in self.accepting.iter().enumerate() {
+            accepting[lookup(name, format!("accepting[{}]", i))?] = true;
+        }
+
+        let mut edges = Vec::new();
+        for (i, transition) in self.transitions.iter().enumerate() {
+            let from = lookup(&transition.from, format!("transitions[{}].from", i))?;
+            let symbol = match &transition.symbol {
+                Some(symbol) => {
+                    let path = format!("transitions[{}].symbol", i);
+                    let symbol = parse_symbol(symbol, path.clone())?;
+                    if !alphabet.contains(&symbol) {
+                        return Err(SchemaError::new(
+                            path,
+                            format!("El símbolo \"{}\" no está en el alfabeto.", symbol),
+                        ));
+                    }
+                    Some(symbol)
+                }
+                None => None,
+            };
+            for (j, name) in transition.to.iter().enumerate() {
+                let to = lookup(name, format!("transitions[{}].to[{}]", i, j))?;
+                edges.push((from, symbol, to));
+            }
+        }
+
+        let states = self.states.iter().cloned().zip(accepting).collect();
+        let mut nfa = NFA::from_table(states, start, edges);
+        nfa.alphabet = alphabet;
+        Ok(nfa)
+    }
+
+    // Escribe el documento como JSON con sangría.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("el documento siempre es serializable")
+    }
+
+    // Escribe el documento como YAML.
+    pub fn to_yaml(&self) -> String {
+        serde_yaml::to_string(self).expect("el documento siempre es serializable")
+    }
+
+    // Lee un documento JSON; los errores de tipo indican la ruta del campo.
+    pub fn from_json(text: &str) -> Result<Self, SchemaError> {
+        let deserializer = &mut serde_json::Deserializer::from_str(text);
+        serde_path_to_error::deserialize(deserializer)
+            .map_err(|e| SchemaError::new(e.path().to_string(), e.inner().to_string()))
+    }
+
+    // Lee un documento YAML; los errores de tipo indican la ruta del campo.
+    pub fn from_yaml(text: &str) -> Result<Self, SchemaError> {
+        let deserializer = serde_yaml::Deserializer::from_str(text);
+        serde_path_to_error::deserialize(deserializer).map_err(|e| {
+            // serde_yaml ya antepone la ruta al mensaje
+            let path = e.path().to_string();
+            let message = e.inner().to_string();
+            let message = message
+                .strip_prefix(&format!("{}: ", path))
+                .map(str::to_string)
+                .unwrap_or(message);
+            SchemaError::new(path, message)
+        })
+    }
+}
+
+impl NFA<char> {
+    // Exporta el autómata como JSON según el esquema de `AutomatonDocument`.
+    pub fn to_json(&self) -> String {
+        AutomatonDocument::from_nfa(self).to_json()
+    }
+
+    // Exporta el autómata como YAML según el esquema de `AutomatonDocument`.
+    pub fn to_yaml(&self) -> String {
+        AutomatonDocument::from_nfa(self).to_yaml()
+    }
+
+    // Importa un autómata desde JSON, validando el esquema.
+    pub fn from_json(text: &str) -> Result<Self, SchemaError> {
+        AutomatonDocument::from_json(text)?.to_nfa()
+    }
+
+    // Importa un autómata desde YAML, validando el esquema.
+    pub fn from_yaml(text: &str) -> Result<Self, SchemaError> {
+        AutomatonDocument::from_yaml(text)?.to_nfa()
+    }
+}
+
+// Un símbolo del documento debe ser una cadena de un solo carácter.
+fn parse_symbol(text: &str, path: String) -> Result<char, SchemaError> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(SchemaError::new(
+            path,
+            format!("El símbolo \"{}\" debe ser un solo carácter.", text),
+        )),
+    }
+}
@@ -0,0 +1,240 @@
+//! Construcción de subconjuntos (determinización) con una explicación paso a paso.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fmt::{Display, Write};
+
+use crate::nfa::{epsilon_closure, Symbol, NFA};
+use crate::tikz::escape_latex;
+
+// Un paso de la construcción: un subconjunto procesado con un símbolo.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubsetStep<S> {
+    // Subconjunto que se está procesando.
+    pub subset: Vec<String>,
+    pub symbol: S,
+    // Unión de `next_states` de los estados del subconjunto.
+    pub moved: Vec<String>,
+    // Clausura ε de `moved`, que es el subconjunto destino.
+    pub closure: Vec<String>,
+    // Si el destino aparece por primera vez y debe procesarse más adelante.
+    pub is_new: bool,
+}
+
+// Registro completo de la construcción y el autómata determinista resultante.
+pub struct SubsetExplanation<S: Symbol> {
+    // Clausura ε del estado inicial, que es el estado inicial del resultado.
+    pub start: Vec<String>,
+    pub steps: Vec<SubsetStep<S>>,
+    pub dfa: NFA<S>,
+}
+
+impl<S: Symbol> NFA<S> {
+    /**
+     Construye un autómata determinista equivalente con la construcción de subconjuntos.
+        Los estados se nombran con el subconjunto, como `{q0, q1}`, y `∅` para el vacío.
+        # Returns
+        Retorna el autómata con los subconjuntos alcanzables, completo sobre el alfabeto.
+    */
+    pub fn determinize(&self) -> NFA<S> {
+        self.explain_determinization().dfa
+    }
+
+    /**
+     Aplica la construcción de subconjuntos registrando cada paso.
+        # Returns
+        Retorna la explicación, con un paso por cada subconjunto y símbolo en el orden
+        en que se procesaron, y el autómata resultante.
+    */
+    pub fn explain_determinization(&self) -> SubsetExplanation<S> {
+        let table = self.transition_table();
+        let epsilon = self.epsilon_table();
+        let names = |subset: &BTreeSet<usize>| -> Vec<String> {
+            subset
+                .iter()
+                .map(|&state| self.states[state].borrow().state.clone())
+                .collect()
+        };
+
+        let start = epsilon_closure(&epsilon, [self.start_index()]);
+        let mut subsets = vec![start.clone()];
+        let mut index = HashMap::from([(start.clone(), 0)]);
+        let mut queue = VecDeque::from([0]);
+        let mut steps = Vec::new();
+        let mut edges = Vec::new();
+
+        while let Some(current) = queue.pop_front() {
+            for symbol in &self.alphabet {
+                let moved: BTreeSet<usize> = subsets[current]
+                    .iter()
+                    .filter_map(|&state| table[state].get(symbol))
+                    .flatten()
+                    .copied()
+                    .collect();
+                let closure = epsilon_closure(&epsilon, moved.iter().copied());
+
+                let is_new = !index.contains_key(&closure);
+                if is_new {
+                    index.insert(closure.clone(), subsets.len());
+                    subsets.push(closure.clone());
+                    queue.push_back(subsets.len() - 1);
+                }
+                edges.push((current, Some(symbol.clone()), index[&closure]));
+
+                steps.push(SubsetStep {
+                    subset: names(&subsets[current]),
+                    symbol: symbol.clone(),
+                    moved: names(&moved),
+                    closure: names(&closure),
+                    is_new,
+                });
+            }
+        }
+
+        let states = subsets
+            .iter()
+            .map(|subset| {
+                let accept = subset
+                    .iter()
+                    .any(|&state| self.states[state].borrow().is_accept);
+                (subset_name(&names(subset)), accept)
+            })
+            .collect();
+        let mut dfa = NFA::from_table(states, 0, edges);
+        dfa.alphabet = self.alphabet.clone();
+
+        SubsetExplanation {
+            start: names(&start),
+            steps,
+            dfa,
+        }
+    }
+}
+
+impl<S: Symbol + Display> SubsetExplanation<S> {
+    /**
+     Escribe la explicación como una tabla de texto alineada.
+        # Returns
+        Retorna el texto, con una fila por paso.
+    */
+    pub fn to_text(&self) -> String {
+        let header = [
+            "Subconjunto",
+            "Símbolo",
+            "⋃ next_states",
+            "Clausura ε",
+            "¿Nuevo?",
+        ]
+        .map(str::to_string);
+        let rows: Vec<[String; 5]> = self.steps.iter().map(|step| step.cells()).collect();
+
+        let width = |column: usize| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain([header[column].chars().count()])
+                .max()
+                .unwrap_or(0)
+        };
+        let widths: Vec<usize> = (0..5).map(width).collect();
+        let line = |cells: &[String; 5]| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| {
+                    format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+                })
+                .collect();
+            padded.join(" | ").trim_end().to_string()
+        };
+
+        let mut text = format!(
+            "Estado inicial: clausura ε = {}\n\n",
+            subset_name(&self.start)
+        );
+        text.push_str(&line(&header));
+        text.push('\n');
+        let separator: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+        text.push_str(&separator.join("-+-"));
+        text.push('\n');
+        for row in &rows {
+            text.push_str(&line(row));
+            text.push('\n');
+        }
+        text
+    }
+
+    // Escribe la explicación como una tabla de Markdown.
+    pub fn to_markdown(&self) -> String {
+        let escape = |cell: &str| cell.replace('|', "\\|");
+        let mut markdown = format!(
+            "Estado inicial: clausura ε = `{}`\n\n",
+            subset_name(&self.start)
+        );
+        markdown.push_str("| Subconjunto | Símbolo | ⋃ next_states | Clausura ε | ¿Nuevo? |\n");
+        markdown.push_str("|---|---|---|---|---|\n");
+        for step in &self.steps {
+            let cells = step.cells().map(|cell| escape(&cell));
+            let _ = writeln!(markdown, "| {} |", cells.join(" | "));
+        }
+        markdown
+    }
+
+    // Escribe la explicación como un entorno `tabular` de LaTeX.
+    pub fn to_latex(&self) -> String {
+        let mut latex = format!(
+            "Estado inicial: $\\varepsilon$-clausura $= {}$\n\n",
+            latex_set(&self.start)
+        );
+        latex.push_str("\\begin{tabular}{lllll}\n");
+        latex.push_str(
+            "  Subconjunto & Símbolo & $\\bigcup$ next\\_states & Clausura $\\varepsilon$ & ¿Nuevo? \\\\\n  \\hline\n",
+        );
+        for step in &self.steps {
+            let _ = writeln!(
+                latex,
+                "  ${}$ & {} & ${}$ & ${}$ & {} \\\\",
+                latex_set(&step.subset),
+                escape_latex(&step.symbol.to_string()),
+                latex_set(&step.moved),
+                latex_set(&step.closure),
+                if step.is_new { "sí" } else { "no" }
+            );
+        }
+        latex.push_str("\\end{tabular}\n");
+        latex
+    }
+}
+
+impl<S: Display> SubsetStep<S> {
+    // Celdas del paso en el orden de las columnas de las tablas.
+    fn cells(&self) -> [String; 5] {
+        [
+            subset_name(&self.subset),
+            self.symbol.to_string(),
+            subset_name(&self.moved),
+            subset_name(&self.closure),
+            if self.is_new { "sí" } else { "no" }.to_string(),
+        ]
+    }
+}
+
+// Nombre de un subconjunto, como `{q0, q1}`, o `∅` si está vacío.
+fn subset_name(states: &[String]) -> String {
+    if states.is_empty() {
+        "∅".to_string()
+    } else {
+        format!("{{{}}}", states.join(", "))
+    }
+}
+
+// Subconjunto en modo matemático de LaTeX.
+fn latex_set(states: &[String]) -> String {
+    if states.is_empty() {
+        "\\emptyset".to_string()
+    } else {
+        let names: Vec<String> = states
+            .iter()
+            .map(|state| format!("\\text{{{}}}", escape_latex(state)))
+            .collect();
+        format!("\\{{{}\\}}", names.join(", "))
+    }
+}
@@ -0,0 +1,184 @@
+//! Dibujo de autómatas en SVG, sin depender de herramientas externas.
+
+use std::fmt::{Display, Write};
+
+use crate::layout::{edge_label, merged_edges, positions, Layout};
+use crate::nfa::{Symbol, NFA};
+use crate::xml::escape;
+
+// Radio de los círculos de los estados, en píxeles.
+const RADIUS: f64 = 22.0;
+// Margen alrededor del dibujo, en píxeles.
+const MARGIN: f64 = 80.0;
+
+// Opciones para `NFA::to_svg_with`.
+#[derive(Clone, Copy, Debug)]
+pub struct SvgOptions {
+    pub layout: Layout,
+    // Distancia en píxeles entre estados vecinos.
+    pub spacing: f64,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            layout: Layout::Layered,
+            spacing: 120.0,
+        }
+    }
+}
+
+impl<S: Symbol + Display> NFA<S> {
+    // Dibuja el autómata en SVG con la distribución por capas.
+    pub fn to_svg(&self) -> String {
+        self.to_svg_with(&SvgOptions::default())
+    }
+
+    /**
+     Dibuja el autómata como una imagen SVG independiente.
+        Los estados de aceptación llevan doble círculo y el inicial una flecha de entrada.
+        Las transiciones paralelas se agrupan en una sola arista, los bucles se dibujan
+        como curvas sobre el estado y los pares de ida y vuelta se curvan para no superponerse.
+        # Arguments
+        * `options` - Distribución y separación de los estados.
+        # Returns
+        Retorna el documento SVG.
+    */
+    pub fn to_svg_with(&self, options: &SvgOptions) -> String {
+        let raw = positions(self, options.layout);
+        let min_x = raw.iter().map(|p| p.0).fold(f64::INFINITY, f64::min);
+        let min_y = raw.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+        let points: Vec<(f64, f64)> = raw
+            .iter()
+            .map(|&(x, y)| {
+                (
+                    MARGIN + (x - min_x) * options.spacing,
+                    MARGIN + (y - min_y) * options.spacing,
+                )
+            })
+            .collect();
+        let width = points.iter().map(|p| p.0).fold(0.0, f64::max) + MARGIN;
+        let height = points.iter().map(|p| p.1).fold(0.0, f64::max) + MARGIN;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" viewBox=\"0 0 {:.0} {:.0}\" font-family=\"sans-serif\" font-size=\"14\">\n",
+            width, height, width, height
+        );
+        svg.push_str(concat!(
+            "  <defs>\n",
+            "    <marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"8\" markerHeight=\"8\" orient=\"auto-start-reverse\">\n",
+            "      <path d=\"M 0 0 L 10 5 L 0 10 z\"/>\n",
+            "    </marker>\n",
+            "  </defs>\n",
+            "  <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n",
+        ));
+
+        // Flecha de inicio
+        let (sx, sy) = points[self.start_index()];
+        let _ = writeln!(
+            svg,
+            "  <line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"black\" marker-end=\"url(#arrow)\"/>",
+            sx - RADIUS - 35.0,
+            sy,
+            sx - RADIUS,
+            sy
+        );
+
+        let edges = merged_edges(self);
+        for (&(from, to), symbols) in &edges {
+            let text = escape(&edge_label(symbols));
+            let (x1, y1) = points[from];
+
+            if from == to {
+                // Bucle sobre el estado, entre los ángulos de -120° y -60°
+                let (ax, ay) = (x1 - RADIUS * 0.5, y1 - RADIUS * 0.87);
+                let (bx, by) = (x1 + RADIUS * 0.5, y1 - RADIUS * 0.87);
+                let _ = writeln!(
+                    svg,
+                    "  <path d=\"M {:.1} {:.1} C {:.1} {:.1}, {:.1} {:.1}, {:.1} {:.1}\" fill=\"none\" stroke=\"black\" marker-end=\"url(#arrow)\"/>",
+                    ax, ay, x1 - 30.0, y1 - 70.0, x1 + 30.0, y1 - 70.0, bx, by
+                );
+                let _ = writeln!(
+                    svg,
+                    "  <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                    x1,
+                    y1 - 64.0,
+                    text
+                );
+                continue;
+            }
+
+            let (x2, y2) = points[to];
+            let (dx, dy) = (x2 - x1, y2 - y1);
+            let length = (dx * dx + dy * dy).sqrt().max(1.0);
+            let (ux, uy) = (dx / length, dy / length);
+            // Con una arista en sentido contrario ambas se curvan hacia su izquierda
+            let bend = if edges.contains_key(&(to, from)) {
+                25.0
+            } else {
+                0.0
+            };
+            let (nx, ny) = (uy, -ux);
+            let (cx, cy) = (
+                (x1 + x2) / 2.0 + nx * bend * 2.0,
+                (y1 + y2) / 2.0 + ny * bend * 2.0,
+            );
+
+            // Extremos sobre el borde de los círculos, en dirección al punto de control
+            let toward = |(px, py): (f64, f64), (qx, qy): (f64, f64)| {
+                let (vx, vy) = (qx - px, qy - py);
+                let norm = (vx * vx + vy * vy).sqrt().max(1.0);
+                (px + vx / norm * RADIUS, py + vy / norm * RADIUS)
+            };
+            let (ax, ay) = toward((x1, y1), (cx, cy));
+            let (bx, by) = toward((x2, y2), (cx, cy));
+            let _ = writeln!(
+                svg,
+                "  <path d=\"M {:.1} {:.1} Q {:.1} {:.1}, {:.1} {:.1}\" fill=\"none\" stroke=\"black\" marker-end=\"url(#arrow)\"/>",
+                ax, ay, cx, cy, bx, by
+            );
+
+            // La etiqueta va en el punto medio de la curva, desplazada hacia afuera
+            let (mx, my) = (
+                0.25 * ax + 0.5 * cx + 0.25 * bx,
+                0.25 * ay + 0.5 * cy + 0.25 * by,
+            );
+            let _ = writeln!(
+                svg,
+                "  <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                mx + nx * 10.0,
+                my + ny * 10.0 + 4.0,
+                text
+            );
+        }
+
+        for (index, state) in self.states.iter().enumerate() {
+            let state = state.borrow();
+            let (x, y) = points[index];
+            let _ = writeln!(
+                svg,
+                "  <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\" fill=\"white\" stroke=\"black\"/>",
+                x, y, RADIUS
+            );
+            if state.is_accept {
+                let _ = writeln!(
+                    svg,
+                    "  <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{:.1}\" fill=\"none\" stroke=\"black\"/>",
+                    x,
+                    y,
+                    RADIUS - 4.0
+                );
+            }
+            let _ = writeln!(
+                svg,
+                "  <text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                x,
+                y + 5.0,
+                escape(&state.state)
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
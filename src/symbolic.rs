@@ -0,0 +1,694 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::fmt;
+
+use crate::description::{classify, missing_start, parse_set, Line, StateNames};
+use crate::error::ParseError;
+use crate::nfa::{Node, StateRef, NFA};
+
+// Último valor escalar de Unicode.
+const MAX_SCALAR: u32 = 0x10FFFF;
+// Rango reservado para sustitutos (surrogates), que no son `char` válidos.
+const SURROGATES: (u32, u32) = (0xD800, 0xDFFF);
+
+/**
+ Clase de caracteres usada como etiqueta de una transición simbólica.
+    Se representa como una lista ordenada de rangos disjuntos de valores escalares
+    de Unicode, lo que permite calcular uniones, intersecciones y complementos
+    sin enumerar cada carácter.
+*/
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CharClass {
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharClass {
+    // Clase vacía, no contiene ningún carácter.
+    pub fn empty() -> Self {
+        CharClass { ranges: Vec::new() }
+    }
+
+    // Clase `.` que contiene todos los caracteres de Unicode.
+    pub fn any() -> Self {
+        CharClass {
+            ranges: vec![(0, SURROGATES.0 - 1), (SURROGATES.1 + 1, MAX_SCALAR)],
+        }
+    }
+
+    // Clase con un único carácter.
+    pub fn single(c: char) -> Self {
+        CharClass::range(c, c)
+    }
+
+    // Clase con todos los caracteres entre `from` y `to` (inclusive).
+    pub fn range(from: char, to: char) -> Self {
+        let (from, to) = if from <= to { (from, to) } else { (to, from) };
+        CharClass::normalize(vec![(from as u32, to as u32)]).intersection(&CharClass::any())
+    }
+
+    /**
+     Interpreta una clase escrita como `a`, `.`, `[a-z]`, `[^0-9]` o `[a-c_x]`.
+        Dentro de los corchetes se pueden escapar `]`, `-`, `^` y `\` con `\`,
+        y cualquier carácter se puede escribir como `\u{hex}`.
+        # Arguments
+        * `text` - Texto de la clase.
+        # Returns
+        Retorna la clase o un mensaje describiendo el error de formato.
+    */
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+
+        if text == "." {
+            return Ok(CharClass::any());
+        }
+
+        let mut chars = text.chars().peekable();
+
+        if !text.starts_with('[') {
+            let c = read_char(&mut chars)?.ok_or("La clase de caracteres está vacía.")?;
+            if chars.next().is_some() {
+                return Err(format!(
+                    "La etiqueta \"{}\" debe ser un solo carácter o una clase entre corchetes.",
+                    text
+                ));
+            }
+            return Ok(CharClass::single(c));
+        }
+
+        chars.next();
+        let negated = chars.peek() == Some(&'^');
+        if negated {
+            chars.next();
+        }
+
+        let mut ranges = Vec::new();
+        loop {
+            match chars.peek() {
+                None => return Err(format!("Falta \"]\" al final de la clase \"{}\".", text)),
+                Some(']') => {
+                    chars.next();
+                    break;
+                }
+                _ => {}
+            }
+
+            let from = read_char(&mut chars)?.ok_or("Clase de caracteres incompleta.")?;
+            let mut to = from;
+
+            // Un `-` forma un rango salvo que sea el último carácter antes de `]`
+            if chars.peek() == Some(&'-') {
+                let mut lookahead = chars.clone();
+                lookahead.next();
+                if lookahead.peek().is_some_and(|&c| c != ']') {
+                    chars.next();
+                    to = read_char(&mut chars)?.ok_or("Rango de caracteres incompleto.")?;
+                    if to < from {
+                        return Err(format!("El rango \"{}-{}\" está invertido.", from, to));
+                    }
+                }
+            }
+
+            ranges.push((from as u32, to as u32));
+        }
+
+        if chars.next().is_some() {
+            return Err(format!("Hay caracteres después de \"]\" en \"{}\".", text));
+        }
+
+        let class = CharClass::normalize(ranges);
+        Ok(if negated { class.complement() } else { class })
+    }
+
+    // Indica si la clase no contiene ningún carácter.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // Indica si el carácter pertenece a la clase.
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        self.ranges.iter().any(|&(from, to)| from <= c && c <= to)
+    }
+
+    // Algún carácter de la clase, si no está vacía.
+    pub fn witness(&self) -> Option<char> {
+        self.ranges
+            .first()
+            .and_then(|&(from, _)| char::from_u32(from))
+    }
+
+    // Unión de dos clases.
+    pub fn union(&self, other: &CharClass) -> CharClass {
+        let mut ranges = self.ranges.clone();
+        ranges.extend_from_slice(&other.ranges);
+        CharClass::normalize(ranges)
+    }
+
+    // Intersección de dos clases.
+    pub fn intersection(&self, other: &CharClass) -> CharClass {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_from, a_to) = self.ranges[i];
+            let (b_from, b_to) = other.ranges[j];
+            let from = a_from.max(b_from);
+            let to = a_to.min(b_to);
+            if from <= to {
+                ranges.push((from, to));
+            }
+            if a_to < b_to {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        CharClass { ranges }
+    }
+
+    // Complemento de la clase respecto a todo Unicode.
+    pub fn complement(&self) -> CharClass {
+        let mut ranges = Vec::new();
+        let mut next = 0;
+
+        for &(from, to) in &self.ranges {
+            if from > next {
+                ranges.push((next, from - 1));
+            }
+            next = to + 1;
+        }
+        if next <= MAX_SCALAR {
+            ranges.push((next, MAX_SCALAR));
+        }
+
+        CharClass { ranges }.intersection(&CharClass::any())
+    }
+
+    // Indica si las dos clases comparten algún carácter.
+    pub fn intersects(&self, other: &CharClass) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    // Ordena los rangos y une los que se solapan o son contiguos.
+    fn normalize(mut ranges: Vec<(u32, u32)>) -> CharClass {
+        ranges.sort();
+        let mut merged: Vec<(u32, u32)> = Vec::new();
+
+        for (from, to) in ranges {
+            match merged.last_mut() {
+                Some(last) if from <= last.1.saturating_add(1) => last.1 = last.1.max(to),
+                _ => merged.push((from, to)),
+            }
+        }
+
+        CharClass { ranges: merged }
+    }
+
+    // Escribe los rangos entre corchetes sin el `^` inicial.
+    fn fmt_ranges(ranges: &[(u32, u32)], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &(from, to) in ranges {
+            write_char(f, from)?;
+            if to > from {
+                if to > from + 1 {
+                    write!(f, "-")?;
+                }
+                write_char(f, to)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Lee un carácter, posiblemente escapado, de la clase.
+fn read_char(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Result<Option<char>, String> {
+    let c = match chars.next() {
+        Some(c) => c,
+        None => return Ok(None),
+    };
+
+    if c != '\\' {
+        return Ok(Some(c));
+    }
+
+    match chars.next() {
+        Some('u') if chars.peek() == Some(&'{') => {
+            chars.next();
+            let mut hex = String::new();
+            loop {
+                match chars.next() {
+                    Some('}') => break,
+                    Some(h) => hex.push(h),
+                    None => return Err("Falta \"}\" en la secuencia \\u{...}.".to_string()),
+                }
+            }
+            u32::from_str_radix(&hex, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .map(Some)
+                .ok_or_else(|| format!("\"\\u{{{}}}\" no es un carácter válido.", hex))
+        }
+        Some('n') => Ok(Some('\n')),
+        Some('t') => Ok(Some('\t')),
+        Some(other) => Ok(Some(other)),
+        None => Err("Secuencia de escape incompleta.".to_string()),
+    }
+}
+
+// Escribe un carácter escapando los que tienen significado especial.
+fn write_char(f: &mut fmt::Formatter<'_>, value: u32) -> fmt::Result {
+    match char::from_u32(value) {
+        Some(c @ ('\\' | ']' | '[' | '-' | '^' | '.')) => write!(f, "\\{}", c),
+        Some('\n') => write!(f, "\\n"),
+        Some('\t') => write!(f, "\\t"),
+        Some(c) if !c.is_control() && !c.is_whitespace() => write!(f, "{}", c),
+        _ => write!(f, "\\u{{{:x}}}", value),
+    }
+}
+
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == CharClass::any() {
+            return write!(f, ".");
+        }
+        if let [(from, to)] = self.ranges[..] {
+            if from == to {
+                return write_char(f, from);
+            }
+        }
+
+        // Se usa la forma negada cuando resulta más corta
+        let complement = self.complement();
+        if !complement.is_empty() && complement.ranges.len() < self.ranges.len() {
+            write!(f, "[^")?;
+            CharClass::fmt_ranges(&complement.ranges, f)?;
+        } else {
+            write!(f, "[")?;
+            CharClass::fmt_ranges(&self.ranges, f)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl fmt::Debug for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/**
+ Calcula los mintérminos de un conjunto de clases.
+    Cada mintérmino es una clase no vacía que, para cada clase de entrada,
+    está contenida en ella o es disjunta con ella. Juntos forman una partición de Unicode.
+    # Arguments
+    * `classes` - Clases que etiquetan las transiciones.
+    # Returns
+    Retorna la partición de Unicode inducida por las clases.
+*/
+pub fn minterms(classes: &[CharClass]) -> Vec<CharClass> {
+    let mut parts = vec![CharClass::any()];
+
+    for class in classes {
+        let complement = class.complement();
+        parts = parts
+            .into_iter()
+            .flat_map(|part| [part.intersection(class), part.intersection(&complement)])
+            .filter(|part| !part.is_empty())
+            .collect();
+    }
+
+    parts
+}
+
+/**
+ Autómata finito simbólico: sus transiciones están etiquetadas con clases de caracteres.
+    Reutiliza el modelo de `Node` usando `CharClass` como símbolo, así que las funciones
+    de impresión de `NFA` se pueden usar a través de `as_nfa`.
+*/
+pub struct SymbolicNFA {
+    nfa: NFA<CharClass>,
+}
+
+impl SymbolicNFA {
+    /**
+     Crea un autómata simbólico desde el formato de descripción.
+        Las transiciones aceptan clases como etiqueta: `(q0, [a-z])->{q1}`.
+        La línea `alphabet=` es opcional y se ignora, el alfabeto es todo Unicode.
+        # Arguments
+        * `description` - Texto con la descripción del autómata.
+        # Returns
+        Retorna el autómata o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut builder = SymbolicNFA::builder();
+        let mut names = StateNames::default();
+        let mut has_start = false;
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+
+            match classify(line.trim()).map_err(error)? {
+                Line::Empty | Line::Alphabet(_) => {}
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        names.declare(name);
+                        builder = builder.state(name);
+                    }
+                }
+                Line::Start(name) => {
+                    names.position(name).map_err(with_line)?;
+                    builder = builder.start(name);
+                    has_start = true;
+                }
+                Line::Final(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        names.position(name).map_err(with_line)?;
+                        builder = builder.accept(name);
+                    }
+                }
+                Line::Transition(left, right) => {
+                    let (state, label) = left.split_once(',').ok_or_else(|| {
+                        error("Debe haber un estado y una clase separados por coma.".to_string())
+                    })?;
+                    let state = state.trim();
+                    names.position(state).map_err(with_line)?;
+                    let class = CharClass::parse(label).map_err(error)?;
+
+                    for target in parse_set(right).map_err(error)? {
+                        names.position(target).map_err(with_line)?;
+                        builder = builder.transition(state, class.clone(), target);
+                    }
+                }
+            }
+        }
+
+        let automaton = builder.build()?;
+        if !has_start {
+            return Err(missing_start());
+        }
+        Ok(automaton)
+    }
+
+    // Crea un constructor vacío para armar el autómata desde código.
+    pub fn builder() -> SymbolicBuilder {
+        SymbolicBuilder::default()
+    }
+
+    // Vista del autómata como `NFA` sobre clases de caracteres.
+    pub fn as_nfa(&self) -> &NFA<CharClass> {
+        &self.nfa
+    }
+
+    /**
+     Valida si la palabra ingresada es aceptada por el autómata.
+        # Arguments
+        * `input` - Los caracteres de la palabra.
+        # Returns
+        Retorna `true` si algún camino etiquetado por la palabra termina en un estado de aceptación.
+    */
+    pub fn run<I: IntoIterator<Item = char>>(&self, input: I) -> bool {
+        let table = self.nfa.transition_table();
+        let mut current: BTreeSet<usize> = BTreeSet::from([self.nfa.start_index()]);
+
+        for c in input {
+            current = current
+                .iter()
+                .flat_map(|&state| {
+                    table[state]
+                        .iter()
+                        .filter(|(class, _)| class.contains(c))
+                        .flat_map(|(_, targets)| targets.iter().copied())
+                })
+                .collect();
+
+            if current.is_empty() {
+                return false;
+            }
+        }
+
+        current
+            .iter()
+            .any(|&state| self.nfa.states[state].borrow().is_accept)
+    }
+
+    // Indica si desde cada estado las clases de sus transiciones son disjuntas y van a un solo destino.
+    pub fn is_deterministic(&self) -> bool {
+        self.nfa.states.iter().all(|state| {
+            let state = state.borrow();
+            let classes: Vec<&CharClass> = state.transitions.keys().collect();
+
+            state.transitions.values().all(|targets| targets.len() <= 1)
+                && classes
+                    .iter()
+                    .enumerate()
+                    .all(|(i, a)| classes[i + 1..].iter().all(|b| !a.intersects(b)))
+        })
+    }
+
+    /**
+     Determiniza el autómata con la construcción de subconjuntos sobre los mintérminos
+     de sus etiquetas. El resultado es completo: incluye el estado `∅` si hace falta.
+        # Returns
+        Retorna un autómata simbólico determinista equivalente.
+    */
+    pub fn determinize(&self) -> SymbolicNFA {
+        let table = self.nfa.transition_table();
+        let labels: Vec<CharClass> = self.nfa.alphabet.iter().cloned().collect();
+        let parts = minterms(&labels);
+
+        let start: BTreeSet<usize> = BTreeSet::from([self.nfa.start_index()]);
+        let mut subsets: Vec<BTreeSet<usize>> = vec![start.clone()];
+        let mut index: HashMap<BTreeSet<usize>, usize> = HashMap::from([(start, 0)]);
+        let mut edges: Vec<(usize, CharClass, usize)> = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            // Agrupar los mintérminos que llevan al mismo subconjunto
+            let mut grouped: BTreeMap<BTreeSet<usize>, CharClass> = BTreeMap::new();
+
+            for part in &parts {
+                let target: BTreeSet<usize> = subsets[current]
+                    .iter()
+                    .flat_map(|&state| {
+                        table[state]
+                            .iter()
+                            .filter(|(class, _)| class.intersects(part))
+                            .flat_map(|(_, targets)| targets.iter().copied())
+                    })
+                    .collect();
+
+                let class = grouped.entry(target).or_insert_with(CharClass::empty);
+                *class = class.union(part);
+            }
+
+            for (target, class) in grouped {
+                let next = match index.get(&target) {
+                    Some(&next) => next,
+                    None => {
+                        subsets.push(target.clone());
+                        index.insert(target, subsets.len() - 1);
+                        queue.push_back(subsets.len() - 1);
+                        subsets.len() - 1
+                    }
+                };
+                edges.push((current, class, next));
+            }
+        }
+
+        let states: Vec<StateRef<CharClass>> = subsets
+            .iter()
+            .map(|subset| {
+                let is_accept = subset
+                    .iter()
+                    .any(|&state| self.nfa.states[state].borrow().is_accept);
+                Node::new(&self.subset_name(subset), is_accept)
+            })
+            .collect();
+
+        SymbolicNFA::assemble(states, 0, edges)
+    }
+
+    /**
+     Construye el autómata producto que acepta la intersección de ambos lenguajes.
+        # Arguments
+        * `other` - El otro autómata simbólico.
+        # Returns
+        Retorna un autómata cuyas etiquetas son las intersecciones no vacías de las etiquetas originales.
+    */
+    pub fn intersection(&self, other: &SymbolicNFA) -> SymbolicNFA {
+        let left = self.nfa.transition_table();
+        let right = other.nfa.transition_table();
+
+        let start = (self.nfa.start_index(), other.nfa.start_index());
+        let mut pairs: Vec<(usize, usize)> = vec![start];
+        let mut index: HashMap<(usize, usize), usize> = HashMap::from([(start, 0)]);
+        let mut edges: Vec<(usize, CharClass, usize)> = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            let (p, q) = pairs[current];
+
+            for (class_p, targets_p) in &left[p] {
+                for (class_q, targets_q) in &right[q] {
+                    let class = class_p.intersection(class_q);
+                    if class.is_empty() {
+                        continue;
+                    }
+
+                    for &tp in targets_p {
+                        for &tq in targets_q {
+                            let next = *index.entry((tp, tq)).or_insert_with(|| {
+                                pairs.push((tp, tq));
+                                queue.push_back(pairs.len() - 1);
+                                pairs.len() - 1
+                            });
+                            edges.push((current, class.clone(), next));
+                        }
+                    }
+                }
+            }
+        }
+
+        let states: Vec<StateRef<CharClass>> = pairs
+            .iter()
+            .map(|&(p, q)| {
+                let p = self.nfa.states[p].borrow();
+                let q = other.nfa.states[q].borrow();
+                Node::new(
+                    &format!("({}, {})", p.state, q.state),
+                    p.is_accept && q.is_accept,
+                )
+            })
+            .collect();
+
+        SymbolicNFA::assemble(states, 0, edges)
+    }
+
+    // Autómata que acepta exactamente las palabras que este rechaza.
+    pub fn complement(&self) -> SymbolicNFA {
+        let dfa = self.determinize();
+        for state in &dfa.nfa.states {
+            let mut state = state.borrow_mut();
+            state.is_accept = !state.is_accept;
+        }
+        dfa
+    }
+
+    // Nombre de un estado formado por un subconjunto de estados.
+    fn subset_name(&self, subset: &BTreeSet<usize>) -> String {
+        if subset.is_empty() {
+            return "∅".to_string();
+        }
+        let names: Vec<String> = subset
+            .iter()
+            .map(|&state| self.nfa.states[state].borrow().state.clone())
+            .collect();
+        format!("{{{}}}", names.join(", "))
+    }
+
+    // Arma el autómata a partir de estados ya creados y aristas por posición.
+    fn assemble(
+        states: Vec<StateRef<CharClass>>,
+        start: usize,
+        edges: Vec<(usize, CharClass, usize)>,
+    ) -> SymbolicNFA {
+        let mut alphabet = BTreeSet::new();
+        for (from, class, to) in edges {
+            alphabet.insert(class.clone());
+            Node::add_transition(&states[from], class, states[to].clone());
+        }
+        let start_state = states[start].clone();
+
+        SymbolicNFA {
+            nfa: NFA::from_parts(states, alphabet, start_state),
+        }
+    }
+}
+
+/**
+ Constructor de autómatas simbólicos desde código.
+    Los estados se crean en el orden en que se declaran; el primero es el inicial
+    salvo que se indique otro con `start`.
+*/
+#[derive(Default)]
+pub struct SymbolicBuilder {
+    states: Vec<(String, bool)>,
+    start: Option<String>,
+    transitions: Vec<(String, CharClass, String)>,
+}
+
+impl SymbolicBuilder {
+    // Declara un estado (si ya existe no hace nada).
+    pub fn state(mut self, name: &str) -> Self {
+        if !self.states.iter().any(|(state, _)| state == name) {
+            self.states.push((name.to_string(), false));
+        }
+        self
+    }
+
+    // Declara un estado de aceptación.
+    pub fn accept(mut self, name: &str) -> Self {
+        self = self.state(name);
+        for (state, is_accept) in &mut self.states {
+            if state == name {
+                *is_accept = true;
+            }
+        }
+        self
+    }
+
+    // Define el estado inicial.
+    pub fn start(mut self, name: &str) -> Self {
+        self = self.state(name);
+        self.start = Some(name.to_string());
+        self
+    }
+
+    // Agrega la transición `from --class--> to`.
+    pub fn transition(mut self, from: &str, class: CharClass, to: &str) -> Self {
+        self.transitions
+            .push((from.to_string(), class, to.to_string()));
+        self
+    }
+
+    /**
+     Construye el autómata verificando que todos los estados usados estén declarados.
+        # Returns
+        Retorna el autómata o un error si falta algún estado o no hay estados.
+    */
+    pub fn build(self) -> Result<SymbolicNFA, ParseError> {
+        if self.states.is_empty() {
+            return Err(ParseError::new(0, "El autómata no tiene estados."));
+        }
+
+        let states: Vec<StateRef<CharClass>> = self
+            .states
+            .iter()
+            .map(|(name, is_accept)| Node::new(name, *is_accept))
+            .collect();
+        let position = |name: &str| {
+            self.states
+                .iter()
+                .position(|(state, _)| state == name)
+                .ok_or_else(|| ParseError::new(0, format!("El estado \"{}\" no existe.", name)))
+        };
+
+        let start = match &self.start {
+            Some(name) => position(name)?,
+            None => 0,
+        };
+
+        let mut edges = Vec::new();
+        for (from, class, to) in &self.transitions {
+            if !class.is_empty() {
+                edges.push((position(from)?, class.clone(), position(to)?));
+            }
+        }
+
+        Ok(SymbolicNFA::assemble(states, start, edges))
+    }
+}
@@ -0,0 +1,211 @@
+//! Exportación a LaTeX: diagramas TikZ y tablas de la función de transición.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+
+use crate::layout::{merged_edges, positions, Layout};
+use crate::nfa::{Symbol, NFA};
+
+// Opciones para `NFA::to_tikz_with`.
+#[derive(Clone, Debug)]
+pub struct TikzOptions {
+    // Distribución automática para los estados sin posición explícita.
+    pub layout: Layout,
+    // Distancia en centímetros entre estados vecinos de la distribución automática.
+    pub spacing: f64,
+    // Posiciones en centímetros elegidas por el usuario, por nombre de estado.
+    pub positions: BTreeMap<String, (f64, f64)>,
+}
+
+impl Default for TikzOptions {
+    fn default() -> Self {
+        TikzOptions {
+            layout: Layout::Layered,
+            spacing: 2.5,
+            positions: BTreeMap::new(),
+        }
+    }
+}
+
+impl<S: Symbol + Display> NFA<S> {
+    // Exporta el autómata como `tikzpicture` con la distribución por capas.
+    pub fn to_tikz(&self) -> String {
+        self.to_tikz_with(&TikzOptions::default())
+    }
+
+    /**
+     Exporta el autómata como un entorno `tikzpicture` de la biblioteca `automata`.
+        El documento debe incluir `\usetikzlibrary{automata, arrows}`. Las transiciones
+        paralelas se agrupan en una sola arista, los bucles se dibujan con `loop above`
+        y los pares de ida y vuelta con `bend left`.
+        # Arguments
+        * `options` - Distribución y posiciones de los estados.
+        # Returns
+        Retorna el código LaTeX.
+    */
+    pub fn to_tikz_with(&self, options: &TikzOptions) -> String {
+        let automatic = positions(self, options.layout);
+        let start = self.start_index();
+        let mut tikz =
+            String::from("\\begin{tikzpicture}[->, >=stealth', shorten >=1pt, auto, semithick]\n");
+
+        for (index, state) in self.states.iter().enumerate() {
+            let state = state.borrow();
+            let (x, y) = options
+                .positions
+                .get(&state.state)
+                .copied()
+                .unwrap_or_else(|| {
+                    let (x, y) = automatic[index];
+                    // En TikZ el eje y crece hacia arriba
+                    (x * options.spacing, -y * options.spacing)
+                });
+
+            let mut styles = vec!["state"];
+            if index == start {
+                styles.push("initial");
+            }
+            if state.is_accept {
+                styles.push("accepting");
+            }
+            let _ = writeln!(
+                tikz,
+                "  \\node[{}] (s{}) at ({:.2}, {:.2}) {{{}}};",
+                styles.join(", "),
+                index,
+                tidy(x),
+                tidy(y),
+                escape_latex(&state.state)
+            );
+        }
+
+        let edges = merged_edges(self);
+        if !edges.is_empty() {
+            tikz.push_str("  \\path");
+            for (&(from, to), symbols) in &edges {
+                let shape = if from == to {
+                    " [loop above]"
+                } else if edges.contains_key(&(to, from)) {
+                    " [bend left]"
+                } else {
+                    ""
+                };
+                let _ = write!(
+                    tikz,
+                    "\n    (s{}) edge{} node {{{}}} (s{})",
+                    from,
+                    shape,
+                    latex_label(symbols),
+                    to
+                );
+            }
+            tikz.push_str(";\n");
+        }
+
+        tikz.push_str("\\end{tikzpicture}\n");
+        tikz
+    }
+
+    /**
+     Exporta la función de transición como un entorno `tabular`, con la misma información
+     que `print_transitions`.
+        El estado inicial se marca con `$\rightarrow$` y los de aceptación con `$*$`; las
+        transiciones vacías se escriben `$\emptyset$`.
+        # Returns
+        Retorna el código LaTeX.
+    */
+    pub fn to_latex_table(&self) -> String {
+        let table = self.transition_table();
+        let epsilon = self.epsilon_table();
+        let with_epsilon = self.has_epsilon();
+        let start = self.start_index();
+        let name = |index: usize| escape_latex(&self.states[index].borrow().state);
+        let set = |targets: &[usize]| {
+            if targets.is_empty() {
+                "$\\emptyset$".to_string()
+            } else {
+                let names: Vec<String> = targets.iter().map(|&t| name(t)).collect();
+                format!("\\{{{}\\}}", names.join(", "))
+            }
+        };
+
+        let columns = self.alphabet.len() + usize::from(with_epsilon);
+        let mut latex = format!("\\begin{{tabular}}{{r|{}}}\n", "c".repeat(columns));
+
+        let mut header = vec!["$\\delta$".to_string()];
+        header.extend(self.alphabet.iter().map(|s| escape_latex(&s.to_string())));
+        if with_epsilon {
+            header.push("$\\varepsilon$".to_string());
+        }
+        let _ = writeln!(latex, "  {} \\\\\n  \\hline", header.join(" & "));
+
+        for (index, row) in table.iter().enumerate() {
+            let mut marker = String::new();
+            if index == start {
+                marker.push_str("$\\rightarrow$");
+            }
+            if self.states[index].borrow().is_accept {
+                marker.push_str("$*$");
+            }
+            if !marker.is_empty() {
+                marker.push(' ');
+            }
+
+            let mut cells = vec![format!("{}{}", marker, name(index))];
+            cells.extend(
+                self.alphabet
+                    .iter()
+                    .map(|symbol| set(row.get(symbol).map(Vec::as_slice).unwrap_or(&[]))),
+            );
+            if with_epsilon {
+                cells.push(set(&epsilon[index]));
+            }
+            let _ = writeln!(latex, "  {} \\\\", cells.join(" & "));
+        }
+
+        latex.push_str("\\end{tabular}\n");
+        latex
+    }
+}
+
+// Redondea a centésimas evitando escribir `-0.00`.
+fn tidy(value: f64) -> f64 {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded == 0.0 {
+        0.0
+    } else {
+        rounded
+    }
+}
+
+// Etiqueta de una arista agrupada, como `0, 1, $\varepsilon$`.
+fn latex_label<S: Display>(symbols: &[Option<S>]) -> String {
+    let labels: Vec<String> = symbols
+        .iter()
+        .map(|symbol| match symbol {
+            Some(symbol) => escape_latex(&symbol.to_string()),
+            None => "$\\varepsilon$".to_string(),
+        })
+        .collect();
+    labels.join(", ")
+}
+
+// Escapa los caracteres con significado especial en LaTeX.
+pub(crate) fn escape_latex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '_' | '#' | '$' | '%' | '&' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            'ε' => escaped.push_str("$\\varepsilon$"),
+            '∅' => escaped.push_str("$\\emptyset$"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
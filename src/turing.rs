@@ -0,0 +1,381 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+
+use crate::description::{classify, missing_start, parse_set, parse_symbol, Line, StateNames};
+use crate::error::ParseError;
+
+// Símbolo blanco por defecto.
+pub const DEFAULT_BLANK: char = '_';
+
+// Movimiento de una cabeza lectora.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Move {
+    Left,
+    Right,
+    Stay,
+}
+
+// Resultado de una ejecución con límite de pasos.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Accept,
+    Reject,
+    Timeout,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter = match self {
+            Move::Left => "L",
+            Move::Right => "R",
+            Move::Stay => "S",
+        };
+        write!(f, "{}", letter)
+    }
+}
+
+// Cinta infinita en ambas direcciones, que crece a medida que la cabeza se mueve.
+#[derive(Clone)]
+struct Tape {
+    cells: VecDeque<char>,
+    head: usize,
+}
+
+impl Tape {
+    fn new(content: &str, blank: char) -> Self {
+        let mut cells: VecDeque<char> = content.chars().collect();
+        if cells.is_empty() {
+            cells.push_back(blank);
+        }
+        Tape { cells, head: 0 }
+    }
+
+    fn read(&self) -> char {
+        self.cells[self.head]
+    }
+
+    fn apply(&mut self, symbol: char, movement: Move, blank: char) {
+        self.cells[self.head] = symbol;
+        match movement {
+            Move::Left if self.head == 0 => self.cells.push_front(blank),
+            Move::Left => self.head -= 1,
+            Move::Right => {
+                self.head += 1;
+                if self.head == self.cells.len() {
+                    self.cells.push_back(blank);
+                }
+            }
+            Move::Stay => {}
+        }
+    }
+}
+
+// Transición `δ(q, símbolos leídos) = (p, símbolos escritos, movimientos)`.
+struct Action {
+    to: usize,
+    write: Vec<char>,
+    moves: Vec<Move>,
+}
+
+/**
+ Máquina de Turing determinista de una o varias cintas.
+    En el formato de descripción las transiciones se escriben `(q0, a)->(q1, b, R)` para
+    una cinta y `(q0, a, b)->(q1, x, y, R, L)` para dos. Además de las líneas de
+    `NFA::try_from_string` acepta `tapes=` (antes de las transiciones), `blank=`,
+    `tape_alphabet=` y `reject=`.
+    La máquina se detiene al entrar en un estado de `F` (acepta), de `reject` o al no
+    tener transición (rechaza).
+*/
+pub struct TuringMachine {
+    states: StateNames,
+    accept: Vec<bool>,
+    reject: Vec<bool>,
+    start_state: usize,
+    tapes: usize,
+    blank: char,
+    alphabet: BTreeSet<char>,
+    tape_alphabet: BTreeSet<char>,
+    transitions: BTreeMap<(usize, Vec<char>), Action>,
+}
+
+impl TuringMachine {
+    /**
+     Crea una máquina de Turing desde el formato de descripción.
+        # Arguments
+        * `description` - Texto con la descripción de la máquina.
+        # Returns
+        Retorna la máquina o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut machine = TuringMachine {
+            states: StateNames::default(),
+            accept: Vec::new(),
+            reject: Vec::new(),
+            start_state: 0,
+            tapes: 1,
+            blank: DEFAULT_BLANK,
+            alphabet: BTreeSet::new(),
+            tape_alphabet: BTreeSet::new(),
+            transitions: BTreeMap::new(),
+        };
+        let mut start_state = None;
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+            let line = line.trim();
+
+            // Líneas propias de la máquina de Turing
+            if let Some(count) = line.strip_prefix("tapes=") {
+                // Las transiciones ya leídas se validaron con la cantidad anterior
+                if !machine.transitions.is_empty() {
+                    return Err(error(
+                        "La línea \"tapes=\" debe ir antes de las transiciones.".to_string(),
+                    ));
+                }
+                machine.tapes = count
+                    .trim()
+                    .parse()
+                    .ok()
+                    .filter(|&count| count > 0)
+                    .ok_or_else(|| {
+                        error(format!("\"{}\" no es un número de cintas válido.", count))
+                    })?;
+                continue;
+            }
+            if let Some(symbol) = line.strip_prefix("blank=") {
+                machine.blank = parse_symbol(symbol).map_err(error)?;
+                continue;
+            }
+            if let Some(list) = line.strip_prefix("tape_alphabet=") {
+                for symbol in parse_set(list).map_err(error)? {
+                    machine
+                        .tape_alphabet
+                        .insert(parse_symbol(symbol).map_err(error)?);
+                }
+                continue;
+            }
+            if let Some(list) = line.strip_prefix("reject=") {
+                for name in parse_set(list).map_err(error)? {
+                    let index = machine.states.position(name).map_err(with_line)?;
+                    machine.reject[index] = true;
+                }
+                continue;
+            }
+
+            match classify(line).map_err(error)? {
+                Line::Empty => {}
+                Line::Alphabet(list) => {
+                    for symbol in parse_set(list).map_err(error)? {
+                        machine
+                            .alphabet
+                            .insert(parse_symbol(symbol).map_err(error)?);
+                    }
+                }
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        if machine.states.declare(name).is_some() {
+                            machine.accept.push(false);
+                            machine.reject.push(false);
+                        }
+                    }
+                }
+                Line::Start(name) => {
+                    start_state = Some(machine.states.position(name).map_err(with_line)?);
+                }
+                Line::Final(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        let index = machine.states.position(name).map_err(with_line)?;
+                        machine.accept[index] = true;
+                    }
+                }
+                Line::Transition(left, right) => {
+                    machine.parse_transition(left, right).map_err(error)?;
+                }
+            }
+        }
+
+        if machine.states.is_empty() {
+            return Err(ParseError::new(0, "La máquina no tiene estados."));
+        }
+        machine.start_state = start_state.ok_or_else(missing_start)?;
+
+        machine.tape_alphabet.insert(machine.blank);
+        machine
+            .tape_alphabet
+            .extend(machine.alphabet.iter().copied());
+        Ok(machine)
+    }
+
+    // Interpreta `q, a1, ..., ak` y `(p, b1, ..., bk, d1, ..., dk)`.
+    fn parse_transition(&mut self, left: &str, right: &str) -> Result<(), String> {
+        let k = self.tapes;
+        let read: Vec<&str> = left.split(',').map(str::trim).collect();
+        if read.len() != k + 1 {
+            return Err(format!(
+                "La transición debe leer {} símbolo(s): \"({})\".",
+                k, left
+            ));
+        }
+
+        let inner = right
+            .strip_prefix('(')
+            .and_then(|r| r.strip_suffix(')'))
+            .ok_or_else(|| format!("La acción \"{}\" debe ir entre paréntesis.", right))?;
+        let action: Vec<&str> = inner.split(',').map(str::trim).collect();
+        if action.len() != 2 * k + 1 {
+            return Err(format!(
+                "La acción debe tener la forma (estado, {} símbolo(s), {} movimiento(s)).",
+                k, k
+            ));
+        }
+
+        let from = self.states.position(read[0]).map_err(|e| e.message)?;
+        let to = self.states.position(action[0]).map_err(|e| e.message)?;
+        let symbols = read[1..]
+            .iter()
+            .map(|s| parse_symbol(s))
+            .collect::<Result<Vec<char>, String>>()?;
+        let write = action[1..=k]
+            .iter()
+            .map(|s| parse_symbol(s))
+            .collect::<Result<Vec<char>, String>>()?;
+        let moves = action[k + 1..]
+            .iter()
+            .map(|m| match *m {
+                "L" => Ok(Move::Left),
+                "R" => Ok(Move::Right),
+                "S" | "N" => Ok(Move::Stay),
+                other => Err(format!("Movimiento \"{}\" inválido, use L, R o S.", other)),
+            })
+            .collect::<Result<Vec<Move>, String>>()?;
+
+        self.tape_alphabet.extend(symbols.iter().copied());
+        self.tape_alphabet.extend(write.iter().copied());
+
+        if self.transitions.contains_key(&(from, symbols.clone())) {
+            return Err(format!(
+                "La transición desde \"{}\" ya está definida, la máquina debe ser determinista.",
+                read[0]
+            ));
+        }
+        self.transitions
+            .insert((from, symbols), Action { to, write, moves });
+        Ok(())
+    }
+
+    // Retorna los nombres de los estados
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    // Retorna el alfabeto de la cinta
+    pub fn tape_alphabet(&self) -> &BTreeSet<char> {
+        &self.tape_alphabet
+    }
+
+    // Retorna la cantidad de cintas
+    pub fn tapes(&self) -> usize {
+        self.tapes
+    }
+
+    /**
+     Ejecuta la máquina con la palabra en la primera cinta.
+        # Arguments
+        * `input` - La palabra de entrada.
+        * `max_steps` - Cantidad máxima de pasos antes de rendirse.
+        # Returns
+        Retorna `Accept`, `Reject` o `Timeout` si se agotaron los pasos.
+    */
+    pub fn run(&self, input: &str, max_steps: usize) -> Outcome {
+        self.execute(input, max_steps, |_, _, _| {})
+    }
+
+    /**
+     Ejecuta la máquina guardando una visualización de las cintas en cada paso.
+        # Arguments
+        * `input` - La palabra de entrada.
+        * `max_steps` - Cantidad máxima de pasos antes de rendirse.
+        # Returns
+        Retorna el resultado y una representación por paso, empezando por la configuración inicial.
+    */
+    pub fn trace(&self, input: &str, max_steps: usize) -> (Outcome, Vec<String>) {
+        let mut snapshots = Vec::new();
+        let outcome = self.execute(input, max_steps, |step, state, tapes| {
+            snapshots.push(self.render(step, state, tapes));
+        });
+        (outcome, snapshots)
+    }
+
+    // Imprime las transiciones de la máquina
+    pub fn print_transitions(&self) {
+        for ((from, read), action) in &self.transitions {
+            let read: Vec<String> = read.iter().map(char::to_string).collect();
+            let write: Vec<String> = action.write.iter().map(char::to_string).collect();
+            let moves: Vec<String> = action.moves.iter().map(Move::to_string).collect();
+            println!(
+                "δ({}, {}) = ({}, {}, {})",
+                self.states[*from],
+                read.join(", "),
+                self.states[action.to],
+                write.join(", "),
+                moves.join(", ")
+            );
+        }
+    }
+
+    fn execute<F>(&self, input: &str, max_steps: usize, mut observe: F) -> Outcome
+    where
+        F: FnMut(usize, usize, &[Tape]),
+    {
+        let mut tapes = vec![Tape::new(input, self.blank)];
+        tapes.extend((1..self.tapes).map(|_| Tape::new("", self.blank)));
+        let mut state = self.start_state;
+
+        for step in 0..=max_steps {
+            observe(step, state, &tapes);
+
+            if self.accept[state] {
+                return Outcome::Accept;
+            }
+            if self.reject[state] {
+                return Outcome::Reject;
+            }
+
+            // Sin transición la máquina se detiene, aunque sea en el último paso
+            let read: Vec<char> = tapes.iter().map(Tape::read).collect();
+            let action = match self.transitions.get(&(state, read)) {
+                Some(action) => action,
+                None => return Outcome::Reject,
+            };
+            if step == max_steps {
+                break;
+            }
+            for (tape, (symbol, movement)) in
+                tapes.iter_mut().zip(action.write.iter().zip(&action.moves))
+            {
+                tape.apply(*symbol, *movement, self.blank);
+            }
+            state = action.to;
+        }
+
+        Outcome::Timeout
+    }
+
+    // Dibuja cada cinta con una marca `^` bajo la cabeza.
+    fn render(&self, step: usize, state: usize, tapes: &[Tape]) -> String {
+        let mut lines = vec![format!("paso {}: {}", step, self.states[state])];
+
+        for tape in tapes {
+            let cells: Vec<String> = tape.cells.iter().map(char::to_string).collect();
+            lines.push(cells.join(" "));
+            lines.push(format!("{}^", " ".repeat(tape.head * 2)));
+        }
+
+        lines.join("\n")
+    }
+}
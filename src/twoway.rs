@@ -0,0 +1,440 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use crate::description::{
+    classify, missing_start, parse_set, parse_symbol, split_state_symbol, Line, StateNames,
+};
+use crate::error::ParseError;
+use crate::nfa::NFA;
+
+// Marcador del extremo izquierdo de la cinta.
+pub const LEFT_MARKER: char = '⊢';
+// Marcador del extremo derecho de la cinta.
+pub const RIGHT_MARKER: char = '⊣';
+
+// Dirección en la que se mueve la cabeza.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+// Resultado de validar una palabra.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Accept,
+    Reject,
+    // No se acepta y alguna ejecución repite una configuración, por lo que nunca se detiene.
+    Loop,
+}
+
+// Comportamiento de un prefijo `⊢u` de la cinta, usado en la construcción de Shepherdson.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Behaviour {
+    // Estados con los que se sale del prefijo hacia la derecha partiendo de la configuración inicial
+    first: BTreeSet<usize>,
+    // Pares `(p, q)`: entrando al último símbolo del prefijo en `p` se puede salir por la derecha en `q`
+    returns: BTreeSet<(usize, usize)>,
+}
+
+/**
+ Autómata finito de dos sentidos (2NFA), que es un 2DFA cuando es determinista.
+    La cinta contiene `⊢ w ⊣` y la cabeza empieza sobre `⊢` en el estado inicial. Cada
+    transición mueve la cabeza a la izquierda (`L`) o a la derecha (`R`); no se puede salir
+    de la cinta. La palabra se acepta al llegar a un estado de `F` con la cabeza sobre `⊣`.
+    En el formato de descripción las transiciones se escriben `(q0, a)->{(q1, R), (q2, L)}`
+    y los marcadores se usan como símbolos `⊢` y `⊣`.
+*/
+pub struct TwoWayNFA {
+    states: StateNames,
+    accept: Vec<bool>,
+    alphabet: BTreeSet<char>,
+    start_state: usize,
+    transitions: BTreeMap<(usize, char), Vec<(usize, Direction)>>,
+}
+
+impl TwoWayNFA {
+    /**
+     Crea un autómata de dos sentidos desde el formato de descripción.
+        # Arguments
+        * `description` - Texto con la descripción del autómata.
+        # Returns
+        Retorna el autómata o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut automaton = TwoWayNFA {
+            states: StateNames::default(),
+            accept: Vec::new(),
+            alphabet: BTreeSet::new(),
+            start_state: 0,
+            transitions: BTreeMap::new(),
+        };
+        let mut start_state = None;
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+
+            match classify(line.trim()).map_err(error)? {
+                Line::Empty => {}
+                Line::Alphabet(list) => {
+                    for symbol in parse_set(list).map_err(error)? {
+                        automaton
+                            .alphabet
+                            .insert(parse_symbol(symbol).map_err(error)?);
+                    }
+                }
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        if automaton.states.declare(name).is_some() {
+                            automaton.accept.push(false);
+                        }
+                    }
+                }
+                Line::Start(name) => {
+                    start_state = Some(automaton.states.position(name).map_err(with_line)?);
+                }
+                Line::Final(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        let index = automaton.states.position(name).map_err(with_line)?;
+                        automaton.accept[index] = true;
+                    }
+                }
+                Line::Transition(left, right) => {
+                    automaton.parse_transition(left, right).map_err(error)?;
+                }
+            }
+        }
+
+        if automaton.states.is_empty() {
+            return Err(ParseError::new(0, "El autómata no tiene estados."));
+        }
+        automaton.start_state = start_state.ok_or_else(missing_start)?;
+        Ok(automaton)
+    }
+
+    // Interpreta `q, a` y `{(p, R), (r, L)}`.
+    fn parse_transition(&mut self, left: &str, right: &str) -> Result<(), String> {
+        let (name, symbol) = split_state_symbol(left)?;
+        let from = self.states.position(name).map_err(|e| e.message)?;
+
+        let inner = right
+            .strip_prefix('{')
+            .and_then(|r| r.strip_suffix('}'))
+            .ok_or_else(|| {
+                format!(
+                    "Se esperaba un conjunto entre llaves y se encontró \"{}\".",
+                    right
+                )
+            })?;
+
+        let mut targets = Vec::new();
+        let mut rest = inner.trim();
+        while !rest.is_empty() {
+            let close = rest
+                .strip_prefix('(')
+                .and_then(|r| r.find(')'))
+                .ok_or_else(|| {
+                    format!(
+                        "Cada destino debe tener la forma (estado, L|R): \"{}\".",
+                        rest
+                    )
+                })?;
+            let pair = &rest[1..close + 1];
+            rest = rest[close + 2..]
+                .trim_start()
+                .trim_start_matches(',')
+                .trim();
+
+            let (to, direction) = pair
+                .split_once(',')
+                .ok_or_else(|| format!("El destino \"({})\" debe indicar el movimiento.", pair))?;
+            let to = self.states.position(to.trim()).map_err(|e| e.message)?;
+            let direction = match direction.trim() {
+                "L" => Direction::Left,
+                "R" => Direction::Right,
+                other => return Err(format!("Movimiento \"{}\" inválido, use L o R.", other)),
+            };
+            if (symbol == LEFT_MARKER && direction == Direction::Left)
+                || (symbol == RIGHT_MARKER && direction == Direction::Right)
+            {
+                return Err(format!(
+                    "La cabeza no puede salir de la cinta desde \"{}\".",
+                    symbol
+                ));
+            }
+            targets.push((to, direction));
+        }
+
+        if symbol != LEFT_MARKER && symbol != RIGHT_MARKER {
+            self.alphabet.insert(symbol);
+        }
+        let entry = self.transitions.entry((from, symbol)).or_default();
+        for target in targets {
+            if !entry.contains(&target) {
+                entry.push(target);
+            }
+        }
+        Ok(())
+    }
+
+    // Retorna los nombres de los estados
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    // Retorna el alfabeto, sin los marcadores
+    pub fn alphabet(&self) -> &BTreeSet<char> {
+        &self.alphabet
+    }
+
+    // Retorna el nombre del estado inicial
+    pub fn start_state(&self) -> &str {
+        &self.states[self.start_state]
+    }
+
+    // Indica si cada par (estado, símbolo) tiene a lo más una transición.
+    pub fn is_deterministic(&self) -> bool {
+        self.transitions.values().all(|targets| targets.len() <= 1)
+    }
+
+    /**
+     Valida una palabra explorando las configuraciones (estado, posición) alcanzables.
+        # Arguments
+        * `input` - La palabra de entrada, sin marcadores.
+        # Returns
+        Retorna `Accept` si se alcanza una configuración de aceptación, `Loop` si no y alguna
+        ejecución entra en un ciclo de configuraciones, y `Reject` en otro caso.
+    */
+    pub fn run<I: IntoIterator<Item = char>>(&self, input: I) -> Outcome {
+        let mut tape = vec![LEFT_MARKER];
+        tape.extend(input);
+        tape.push(RIGHT_MARKER);
+        let end = tape.len() - 1;
+
+        let successors = |(state, pos): (usize, usize)| -> Vec<(usize, usize)> {
+            self.transitions
+                .get(&(state, tape[pos]))
+                .into_iter()
+                .flatten()
+                .map(|&(to, direction)| match direction {
+                    Direction::Left => (to, pos - 1),
+                    Direction::Right => (to, pos + 1),
+                })
+                .collect()
+        };
+
+        // Búsqueda en profundidad con colores para detectar ciclos
+        let start = (self.start_state, 0);
+        let mut finished: BTreeSet<(usize, usize)> = BTreeSet::new();
+        let mut on_stack: BTreeSet<(usize, usize)> = BTreeSet::from([start]);
+        let mut stack = vec![(start, successors(start), 0)];
+        let mut loops = false;
+
+        while let Some((config, next, index)) = stack.last_mut() {
+            let (state, pos) = *config;
+            if *index == 0 && self.accept[state] && pos == end {
+                return Outcome::Accept;
+            }
+
+            match next.get(*index).copied() {
+                Some(target) => {
+                    *index += 1;
+                    if on_stack.contains(&target) {
+                        loops = true;
+                    } else if !finished.contains(&target) {
+                        on_stack.insert(target);
+                        stack.push((target, successors(target), 0));
+                    }
+                }
+                None => {
+                    on_stack.remove(config);
+                    finished.insert(*config);
+                    stack.pop();
+                }
+            }
+        }
+
+        if loops {
+            Outcome::Loop
+        } else {
+            Outcome::Reject
+        }
+    }
+
+    /**
+     Construye un `NFA` equivalente con la construcción de Shepherdson.
+        Cada estado resume el comportamiento del prefijo leído: los estados con los que la
+        primera ejecución sale por la derecha y, para cada estado con el que se vuelve al
+        último símbolo, los estados con los que se puede salir de nuevo por la derecha.
+        # Returns
+        Retorna el autómata de un sentido, que además es determinista.
+    */
+    pub fn to_nfa(&self) -> NFA<char> {
+        let all: Vec<usize> = (0..self.states.len()).collect();
+
+        // Comportamiento de `⊢`: la cabeza solo puede moverse a la derecha
+        let exits = |p: usize| self.right_moves(p, LEFT_MARKER);
+        let start = Behaviour {
+            first: exits(self.start_state),
+            returns: all
+                .iter()
+                .flat_map(|&p| exits(p).into_iter().map(move |q| (p, q)))
+                .collect(),
+        };
+
+        let mut behaviours = vec![start.clone()];
+        let mut index: HashMap<Behaviour, usize> = HashMap::from([(start, 0)]);
+        let mut edges = Vec::new();
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(current) = queue.pop_front() {
+            for &symbol in &self.alphabet {
+                let behaviour = &behaviours[current];
+                let exits: Vec<BTreeSet<usize>> = all
+                    .iter()
+                    .map(|&p| self.exits(p, symbol, &behaviour.returns))
+                    .collect();
+                let next = Behaviour {
+                    first: behaviour
+                        .first
+                        .iter()
+                        .flat_map(|&p| exits[p].iter().copied())
+                        .collect(),
+                    returns: all
+                        .iter()
+                        .flat_map(|&p| exits[p].iter().map(move |&q| (p, q)))
+                        .collect(),
+                };
+
+                let target = *index.entry(next.clone()).or_insert_with(|| {
+                    behaviours.push(next);
+                    queue.push_back(behaviours.len() - 1);
+                    behaviours.len() - 1
+                });
+                edges.push((current, Some(symbol), target));
+            }
+        }
+
+        let states = behaviours
+            .iter()
+            .map(|behaviour| {
+                (
+                    self.behaviour_name(behaviour),
+                    self.accepts_at_end(behaviour),
+                )
+            })
+            .collect();
+
+        let mut nfa = NFA::from_table(states, 0, edges);
+        nfa.alphabet = self.alphabet.clone();
+        nfa
+    }
+
+    // Imprime las transiciones del autómata
+    pub fn print_transitions(&self) {
+        for ((from, symbol), targets) in &self.transitions {
+            let targets: Vec<String> = targets
+                .iter()
+                .map(|(to, direction)| {
+                    let letter = match direction {
+                        Direction::Left => "L",
+                        Direction::Right => "R",
+                    };
+                    format!("({}, {})", self.states[*to], letter)
+                })
+                .collect();
+            println!(
+                "δ({}, {}) = {{{}}}",
+                self.states[*from],
+                symbol,
+                targets.join(", ")
+            );
+        }
+    }
+
+    // Estados a los que se llega moviéndose a la derecha desde `state` leyendo `symbol`.
+    fn right_moves(&self, state: usize, symbol: char) -> BTreeSet<usize> {
+        self.transitions
+            .get(&(state, symbol))
+            .into_iter()
+            .flatten()
+            .filter(|(_, direction)| *direction == Direction::Right)
+            .map(|&(to, _)| to)
+            .collect()
+    }
+
+    /**
+     Estados recorridos sobre la última celda: desde `state`, cada movimiento a la izquierda
+     vuelve a la celda según `returns`.
+        # Returns
+        Retorna los estados alcanzables sin salir por la derecha, incluyendo `state`.
+    */
+    fn visits(
+        &self,
+        state: usize,
+        symbol: char,
+        returns: &BTreeSet<(usize, usize)>,
+    ) -> BTreeSet<usize> {
+        let mut seen = BTreeSet::from([state]);
+        let mut pending = vec![state];
+
+        while let Some(current) = pending.pop() {
+            let lefts = self
+                .transitions
+                .get(&(current, symbol))
+                .into_iter()
+                .flatten()
+                .filter(|(_, direction)| *direction == Direction::Left);
+            for &(to, _) in lefts {
+                for &(_, back) in returns.range((to, 0)..=(to, usize::MAX)) {
+                    if seen.insert(back) {
+                        pending.push(back);
+                    }
+                }
+            }
+        }
+
+        seen
+    }
+
+    // Estados con los que se sale por la derecha entrando en `state` a la celda con `symbol`.
+    fn exits(
+        &self,
+        state: usize,
+        symbol: char,
+        returns: &BTreeSet<(usize, usize)>,
+    ) -> BTreeSet<usize> {
+        self.visits(state, symbol, returns)
+            .into_iter()
+            .flat_map(|visited| self.right_moves(visited, symbol))
+            .collect()
+    }
+
+    // Indica si, tras el prefijo, la cabeza puede llegar a `⊣` y alcanzar un estado de aceptación.
+    fn accepts_at_end(&self, behaviour: &Behaviour) -> bool {
+        behaviour.first.iter().any(|&state| {
+            self.visits(state, RIGHT_MARKER, &behaviour.returns)
+                .iter()
+                .any(|&visited| self.accept[visited])
+        })
+    }
+
+    // Nombre de un estado de la construcción, como `{q1} | {q0→q1, q1→q1}`.
+    fn behaviour_name(&self, behaviour: &Behaviour) -> String {
+        let first: Vec<&str> = behaviour
+            .first
+            .iter()
+            .map(|&q| self.states[q].as_str())
+            .collect();
+        let returns: Vec<String> = behaviour
+            .returns
+            .iter()
+            .map(|&(p, q)| format!("{}→{}", self.states[p], self.states[q]))
+            .collect();
+        format!("{{{}}} | {{{}}}", first.join(", "), returns.join(", "))
+    }
+}
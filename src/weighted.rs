@@ -0,0 +1,593 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::description::{
+    classify, declared, parse_set, parse_symbol, split_state_symbol, unknown_state, Line,
+};
+use crate::error::ParseError;
+use crate::nfa::{epsilon_closure, Symbol, NFA};
+
+/**
+ Semianillo `(K, ⊕, ⊗, 0, 1)` en el que se calculan los pesos.
+    El peso de un camino es el producto `⊗` de sus transiciones y el de una palabra
+    es la suma `⊕` de los pesos de todos sus caminos de aceptación.
+*/
+pub trait Semiring: Clone + PartialEq + fmt::Debug + fmt::Display {
+    // Elemento neutro de `⊕` (camino inexistente).
+    fn zero() -> Self;
+    // Elemento neutro de `⊗` (camino vacío).
+    fn one() -> Self;
+    fn plus(&self, other: &Self) -> Self;
+    fn times(&self, other: &Self) -> Self;
+    // Interpreta un peso escrito en el formato de descripción.
+    fn parse(text: &str) -> Result<Self, String>;
+}
+
+/**
+ Semianillos con un orden natural entre pesos, necesario para buscar los n mejores caminos.
+    La búsqueda supone que extender un camino nunca lo mejora (por ejemplo, pesos tropicales
+    no negativos o probabilidades menores o iguales a 1).
+*/
+pub trait NaturalOrder: Semiring {
+    // Indica si `self` es estrictamente mejor que `other`.
+    fn is_better(&self, other: &Self) -> bool;
+}
+
+// Semianillo tropical `(ℝ ∪ {∞}, min, +, ∞, 0)`: costo del camino más corto.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Tropical(pub f64);
+
+// Semianillo logarítmico `(ℝ ∪ {∞}, ⊕log, +, ∞, 0)` con `a ⊕log b = -ln(e^-a + e^-b)`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Log(pub f64);
+
+// Semianillo de probabilidades `([0, ∞), +, ×, 0, 1)`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Probability(pub f64);
+
+// Semianillo booleano `({false, true}, ∨, ∧, false, true)`: aceptación clásica.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Boolean(pub bool);
+
+// Semianillo de conteo `(ℕ, +, ×, 0, 1)`: número de caminos de aceptación.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Counting(pub u64);
+
+// Interpreta un número real finito.
+fn parse_real(text: &str) -> Result<f64, String> {
+    match text.trim().parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err(format!(
+            "El peso \"{}\" no es un número válido.",
+            text.trim()
+        )),
+    }
+}
+
+// Interpreta un número real finito o `∞`/`inf`, el cero de los semianillos tropical y logarítmico.
+fn parse_extended_real(text: &str) -> Result<f64, String> {
+    match text.trim() {
+        "∞" | "inf" => Ok(f64::INFINITY),
+        number => parse_real(number),
+    }
+}
+
+impl Semiring for Tropical {
+    fn zero() -> Self {
+        Tropical(f64::INFINITY)
+    }
+    fn one() -> Self {
+        Tropical(0.0)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        Tropical(self.0.min(other.0))
+    }
+    fn times(&self, other: &Self) -> Self {
+        Tropical(self.0 + other.0)
+    }
+    fn parse(text: &str) -> Result<Self, String> {
+        parse_extended_real(text).map(Tropical)
+    }
+}
+
+impl NaturalOrder for Tropical {
+    fn is_better(&self, other: &Self) -> bool {
+        self.0 < other.0
+    }
+}
+
+impl Semiring for Log {
+    fn zero() -> Self {
+        Log(f64::INFINITY)
+    }
+    fn one() -> Self {
+        Log(0.0)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        let (low, high) = if self.0 <= other.0 {
+            (self.0, other.0)
+        } else {
+            (other.0, self.0)
+        };
+        if high == f64::INFINITY {
+            return Log(low);
+        }
+        Log(low - (-(high - low)).exp().ln_1p())
+    }
+    fn times(&self, other: &Self) -> Self {
+        Log(self.0 + other.0)
+    }
+    fn parse(text: &str) -> Result<Self, String> {
+        parse_extended_real(text).map(Log)
+    }
+}
+
+impl NaturalOrder for Log {
+    fn is_better(&self, other: &Self) -> bool {
+        self.0 < other.0
+    }
+}
+
+impl Semiring for Probability {
+    fn zero() -> Self {
+        Probability(0.0)
+    }
+    fn one() -> Self {
+        Probability(1.0)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        Probability(self.0 + other.0)
+    }
+    fn times(&self, other: &Self) -> Self {
+        Probability(self.0 * other.0)
+    }
+    fn parse(text: &str) -> Result<Self, String> {
+        let value = parse_real(text)?;
+        if value < 0.0 {
+            return Err(format!("La probabilidad \"{}\" es negativa.", text.trim()));
+        }
+        Ok(Probability(value))
+    }
+}
+
+impl NaturalOrder for Probability {
+    fn is_better(&self, other: &Self) -> bool {
+        self.0 > other.0
+    }
+}
+
+impl Semiring for Boolean {
+    fn zero() -> Self {
+        Boolean(false)
+    }
+    fn one() -> Self {
+        Boolean(true)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        Boolean(self.0 || other.0)
+    }
+    fn times(&self, other: &Self) -> Self {
+        Boolean(self.0 && other.0)
+    }
+    fn parse(text: &str) -> Result<Self, String> {
+        match text.trim() {
+            "1" | "true" => Ok(Boolean(true)),
+            "0" | "false" => Ok(Boolean(false)),
+            other => Err(format!("El peso \"{}\" no es booleano.", other)),
+        }
+    }
+}
+
+impl NaturalOrder for Boolean {
+    fn is_better(&self, other: &Self) -> bool {
+        self.0 && !other.0
+    }
+}
+
+impl Semiring for Counting {
+    fn zero() -> Self {
+        Counting(0)
+    }
+    fn one() -> Self {
+        Counting(1)
+    }
+    fn plus(&self, other: &Self) -> Self {
+        Counting(self.0.saturating_add(other.0))
+    }
+    fn times(&self, other: &Self) -> Self {
+        Counting(self.0.saturating_mul(other.0))
+    }
+    fn parse(text: &str) -> Result<Self, String> {
+        text.trim()
+            .parse()
+            .map(Counting)
+            .map_err(|_| format!("El peso \"{}\" no es un número natural.", text.trim()))
+    }
+}
+
+impl fmt::Display for Tropical {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Log {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Probability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Display for Boolean {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", if self.0 { 1 } else { 0 })
+    }
+}
+
+impl fmt::Display for Counting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/**
+ Camino de aceptación encontrado por `WeightedNFA::n_best`.
+    `states` incluye el estado inicial, así que tiene un elemento más que `word`.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path<S, W> {
+    pub states: Vec<String>,
+    pub word: Vec<S>,
+    pub weight: W,
+}
+
+/**
+ Autómata con pesos sobre un semianillo `W`.
+    Cada transición y cada estado de aceptación (peso final) llevan un peso; en el formato
+    de descripción se escriben después de `:`, como en `(q0, a)->{q1:0.5}` o `F={q2:0.3}`.
+    Un peso omitido vale `W::one()`.
+*/
+pub struct WeightedNFA<W: Semiring, S: Symbol = char> {
+    pub(crate) states: Vec<String>,
+    pub(crate) alphabet: BTreeSet<S>,
+    pub(crate) start_state: usize,
+    pub(crate) finals: Vec<W>,
+    pub(crate) transitions: Vec<Vec<(S, W, usize)>>,
+}
+
+impl<W: Semiring, S: Symbol> WeightedNFA<W, S> {
+    // Crea un autómata con un único estado, que es el inicial y no es de aceptación.
+    pub fn new(start_state: &str) -> Self {
+        WeightedNFA {
+            states: vec![start_state.to_string()],
+            alphabet: BTreeSet::new(),
+            start_state: 0,
+            finals: vec![W::zero()],
+            transitions: vec![Vec::new()],
+        }
+    }
+
+    // Agrega un estado (o actualiza su peso final) y retorna su posición.
+    pub fn add_state(&mut self, name: &str, final_weight: W) -> usize {
+        match self.position(name) {
+            Some(index) => {
+                self.finals[index] = final_weight;
+                index
+            }
+            None => {
+                self.states.push(name.to_string());
+                self.finals.push(final_weight);
+                self.transitions.push(Vec::new());
+                self.states.len() - 1
+            }
+        }
+    }
+
+    // Agrega la transición `from --symbol/weight--> to`.
+    pub fn add_transition(
+        &mut self,
+        from: &str,
+        symbol: S,
+        to: &str,
+        weight: W,
+    ) -> Result<(), ParseError> {
+        let from = self.position(from).ok_or_else(|| unknown_state(from))?;
+        let to = self.position(to).ok_or_else(|| unknown_state(to))?;
+
+        self.alphabet.insert(symbol.clone());
+        self.transitions[from].push((symbol, weight, to));
+        Ok(())
+    }
+
+    /**
+     Crea un autómata con pesos a partir de un `NFA`, con peso `W::one()` en cada transición
+     y en cada estado de aceptación. Las transiciones ε se eliminan antes.
+        Con el semianillo `Boolean`, `weight` coincide con `NFA::run`.
+        # Arguments
+        * `nfa` - El autómata sin pesos.
+    */
+    pub fn from_nfa(nfa: &NFA<S>) -> Self {
+        let table = nfa.transition_table();
+        let epsilon = nfa.epsilon_table();
+
+        let mut transitions = Vec::new();
+        let mut finals = Vec::new();
+        for state in 0..nfa.states.len() {
+            let closure = epsilon_closure(&epsilon, [state]);
+
+            let mut targets: BTreeMap<S, BTreeSet<usize>> = BTreeMap::new();
+            for &p in &closure {
+                for (symbol, next) in &table[p] {
+                    targets
+                        .entry(symbol.clone())
+                        .or_default()
+                        .extend(epsilon_closure(&epsilon, next.iter().copied()));
+                }
+            }
+            transitions.push(
+                targets
+                    .into_iter()
+                    .flat_map(|(symbol, next)| {
+                        next.into_iter()
+                            .map(move |to| (symbol.clone(), W::one(), to))
+                    })
+                    .collect(),
+            );
+
+            let is_accept = closure.iter().any(|&p| nfa.states[p].borrow().is_accept);
+            finals.push(if is_accept { W::one() } else { W::zero() });
+        }
+
+        WeightedNFA {
+            states: nfa
+                .states
+                .iter()
+                .map(|state| state.borrow().state.clone())
+                .collect(),
+            alphabet: nfa.alphabet.clone(),
+            start_state: nfa.start_index(),
+            finals,
+            transitions,
+        }
+    }
+
+    // Retorna los nombres de los estados
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    // Retorna el alfabeto
+    pub fn alphabet(&self) -> &BTreeSet<S> {
+        &self.alphabet
+    }
+
+    // Retorna el nombre del estado inicial
+    pub fn start_state(&self) -> &str {
+        &self.states[self.start_state]
+    }
+
+    /**
+     Calcula el peso de una palabra: la suma `⊕` de los pesos de todos sus caminos de aceptación.
+        # Arguments
+        * `input` - La palabra a evaluar.
+        # Returns
+        Retorna `W::zero()` si la palabra no tiene caminos de aceptación.
+    */
+    pub fn weight<I: IntoIterator<Item = S>>(&self, input: I) -> W {
+        let mut current = vec![W::zero(); self.states.len()];
+        current[self.start_state] = W::one();
+
+        for symbol in input {
+            let mut next = vec![W::zero(); self.states.len()];
+            for (state, weight) in current.iter().enumerate() {
+                if *weight == W::zero() {
+                    continue;
+                }
+                for (label, w, to) in &self.transitions[state] {
+                    if *label == symbol {
+                        next[*to] = next[*to].plus(&weight.times(w));
+                    }
+                }
+            }
+            current = next;
+        }
+
+        current
+            .iter()
+            .zip(&self.finals)
+            .fold(W::zero(), |total, (weight, last)| {
+                total.plus(&weight.times(last))
+            })
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.states.iter().position(|state| state == name)
+    }
+}
+
+impl<W: NaturalOrder, S: Symbol> WeightedNFA<W, S> {
+    /**
+     Busca los `n` mejores caminos de aceptación del autómata según el orden natural de `W`.
+        Cada estado se expande a lo más `n` veces, lo que es correcto cuando extender un
+        camino nunca lo mejora.
+        # Arguments
+        * `n` - Cantidad máxima de caminos.
+        # Returns
+        Retorna los caminos ordenados del mejor al peor, incluyendo el peso final.
+    */
+    pub fn n_best(&self, n: usize) -> Vec<Path<S, W>> {
+        // Cada elemento es (peso, estados visitados, palabra, terminado)
+        let mut queue: Vec<(W, Vec<usize>, Vec<S>, bool)> =
+            vec![(W::one(), vec![self.start_state], Vec::new(), false)];
+        let mut expanded = vec![0; self.states.len()];
+        let mut paths = Vec::new();
+
+        while paths.len() < n {
+            let best = match (0..queue.len()).reduce(|best, i| {
+                if queue[i].0.is_better(&queue[best].0) {
+                    i
+                } else {
+                    best
+                }
+            }) {
+                Some(best) => best,
+                None => break,
+            };
+            let (weight, visited, word, finished) = queue.swap_remove(best);
+
+            if finished {
+                paths.push(Path {
+                    states: visited.iter().map(|&s| self.states[s].clone()).collect(),
+                    word,
+                    weight,
+                });
+                continue;
+            }
+
+            let state = *visited.last().unwrap();
+            if expanded[state] >= n {
+                continue;
+            }
+            expanded[state] += 1;
+
+            if self.finals[state] != W::zero() {
+                let total = weight.times(&self.finals[state]);
+                queue.push((total, visited.clone(), word.clone(), true));
+            }
+            for (symbol, w, to) in &self.transitions[state] {
+                let total = weight.times(w);
+                if total == W::zero() {
+                    continue;
+                }
+                let mut visited = visited.clone();
+                visited.push(*to);
+                let mut word = word.clone();
+                word.push(symbol.clone());
+                queue.push((total, visited, word, false));
+            }
+        }
+
+        paths
+    }
+}
+
+impl<W: Semiring, S: Symbol + fmt::Display> WeightedNFA<W, S> {
+    // Imprime las transiciones con sus pesos
+    pub fn print_transitions(&self) {
+        for (state, row) in self.states.iter().zip(&self.transitions) {
+            let mut grouped: BTreeMap<&S, Vec<String>> = BTreeMap::new();
+            for (symbol, weight, to) in row {
+                grouped
+                    .entry(symbol)
+                    .or_default()
+                    .push(format!("{}:{}", self.states[*to], weight));
+            }
+            for (symbol, targets) in grouped {
+                println!("δ({}, {}) = {{{}}}", state, symbol, targets.join(", "));
+            }
+        }
+    }
+
+    // Imprime los estados de aceptación con su peso final
+    pub fn print_accept_states(&self) {
+        let finals: Vec<String> = self
+            .states
+            .iter()
+            .zip(&self.finals)
+            .filter(|(_, weight)| **weight != W::zero())
+            .map(|(state, weight)| format!("{}:{}", state, weight))
+            .collect();
+        print!("{{{}}}", finals.join(", "));
+    }
+}
+
+impl<W: Semiring> WeightedNFA<W, char> {
+    /**
+     Crea un autómata con pesos desde el formato de descripción.
+        # Arguments
+        * `description` - Texto con transiciones como `(q0, a)->{q1:0.5, q2}` y `F={q2:0.3}`.
+        # Returns
+        Retorna el autómata o el primer error encontrado.
+    */
+    pub fn from_string(description: &str) -> Result<Self, ParseError> {
+        let mut automaton: Option<WeightedNFA<W, char>> = None;
+        let mut alphabet = BTreeSet::new();
+
+        for (number, line) in description.lines().enumerate() {
+            let number = number + 1;
+            let error = |message: String| ParseError::new(number, message);
+            let with_line = |mut e: ParseError| {
+                e.line = number;
+                e
+            };
+
+            match classify(line.trim()).map_err(error)? {
+                Line::Empty => {}
+                Line::Alphabet(list) => {
+                    for symbol in parse_set(list).map_err(error)? {
+                        alphabet.insert(parse_symbol(symbol).map_err(error)?);
+                    }
+                }
+                Line::States(list) => {
+                    for name in parse_set(list).map_err(error)? {
+                        match &mut automaton {
+                            Some(automaton) => {
+                                automaton.add_state(name, W::zero());
+                            }
+                            None => automaton = Some(WeightedNFA::new(name)),
+                        }
+                    }
+                }
+                Line::Start(name) => {
+                    let automaton = declared(&mut automaton).map_err(error)?;
+                    automaton.start_state = automaton
+                        .position(name)
+                        .ok_or_else(|| with_line(unknown_state(name)))?;
+                }
+                Line::Final(list) => {
+                    let automaton = declared(&mut automaton).map_err(error)?;
+                    for entry in parse_set(list).map_err(error)? {
+                        let (name, weight) = split_weight::<W>(entry).map_err(error)?;
+                        let index = automaton
+                            .position(name)
+                            .ok_or_else(|| with_line(unknown_state(name)))?;
+                        automaton.finals[index] = weight;
+                    }
+                }
+                Line::Transition(left, right) => {
+                    let automaton = declared(&mut automaton).map_err(error)?;
+                    let (state, symbol) = split_state_symbol(left).map_err(error)?;
+                    if symbol == 'ε' {
+                        return Err(error(
+                            "Los autómatas con pesos no admiten transiciones ε.".to_string(),
+                        ));
+                    }
+                    for entry in parse_set(right).map_err(error)? {
+                        let (target, weight) = split_weight::<W>(entry).map_err(error)?;
+                        automaton
+                            .add_transition(state, symbol, target, weight)
+                            .map_err(with_line)?;
+                    }
+                }
+            }
+        }
+
+        let mut automaton =
+            automaton.ok_or_else(|| ParseError::new(0, "El autómata no tiene estados."))?;
+        automaton.alphabet.extend(alphabet);
+        Ok(automaton)
+    }
+}
+
+// Separa `estado:peso`; sin peso se usa `W::one()`.
+fn split_weight<W: Semiring>(entry: &str) -> Result<(&str, W), String> {
+    match entry.split_once(':') {
+        Some((name, weight)) => Ok((name.trim(), W::parse(weight)?)),
+        None => Ok((entry.trim(), W::one())),
+    }
+}
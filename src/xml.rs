@@ -0,0 +1,17 @@
+//! Utilidades compartidas por los formatos que generan XML: SVG, JFLAP y JUnit.
+
+// Escapa los caracteres especiales de XML, tanto para texto como para atributos.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
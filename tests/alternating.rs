@@ -0,0 +1,173 @@
+use automata::alternating::{AFA, MAX_DEPTH};
+use automata::random::Rng;
+
+const ALPHABET: [char; 2] = ['a', 'b'];
+
+// Cantidad de semillas que recorre cada propiedad.
+const CASES: u64 = 150;
+
+// Todas las palabras sobre `ALPHABET` de largo a lo más `max_length`.
+fn all_words(max_length: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word| ALPHABET.iter().map(move |c| format!("{}{}", word, c)))
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+// Fórmula aleatoria sobre `states` estados, escrita en el formato de descripción.
+fn random_formula(rng: &mut Rng, states: usize, depth: usize) -> String {
+    if depth == 0 || rng.chance(0.4) {
+        return match rng.below(12) {
+            0 => "true".to_string(),
+            1 => "false".to_string(),
+            _ => format!("q{}", rng.below(states)),
+        };
+    }
+    let operator = if rng.chance(0.5) { "&" } else { "|" };
+    format!(
+        "({} {} {})",
+        random_formula(rng, states, depth - 1),
+        operator,
+        random_formula(rng, states, depth - 1)
+    )
+}
+
+// Autómata alternante aleatorio reproducible; algunas transiciones quedan sin definir.
+fn random_afa(seed: u64) -> AFA {
+    let mut rng = Rng::new(seed);
+    let states = 1 + rng.below(3);
+    let names: Vec<String> = (0..states).map(|i| format!("q{}", i)).collect();
+    let finals: Vec<&str> = names
+        .iter()
+        .filter(|_| rng.chance(0.4))
+        .map(String::as_str)
+        .collect();
+    let mut description = format!(
+        "alphabet={{a, b}}\nstate={{{}}}\nstart_state=q0\nF={{{}}}\n",
+        names.join(", "),
+        finals.join(", ")
+    );
+    for name in &names {
+        for symbol in ALPHABET {
+            if rng.chance(0.8) {
+                let formula = random_formula(&mut rng, states, 2);
+                description.push_str(&format!("({}, {})->{}\n", name, symbol, formula));
+            }
+        }
+    }
+    AFA::from_string(&description).unwrap()
+}
+
+#[test]
+fn complement_accepts_exactly_the_rejected_words() {
+    for seed in 0..CASES {
+        let afa = random_afa(seed);
+        let complement = afa.complement();
+        for word in all_words(6) {
+            assert_ne!(
+                afa.run(word.chars()),
+                complement.run(word.chars()),
+                "semilla {}: \"{}\"",
+                seed,
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn conversion_to_nfa_preserves_the_language() {
+    for seed in 0..CASES {
+        let afa = random_afa(seed);
+        let nfa = afa.to_nfa();
+        let complement = afa.complement().to_nfa();
+        for word in all_words(6) {
+            assert_eq!(
+                afa.run(word.chars()),
+                nfa.run(word.chars()),
+                "semilla {}: \"{}\"",
+                seed,
+                word
+            );
+            assert_ne!(nfa.run(word.chars()), complement.run(word.chars()));
+        }
+    }
+}
+
+#[test]
+fn conjunction_requires_every_branch() {
+    // Palabras que contienen una `a` y terminan en `b`
+    let afa = AFA::from_string(
+        "alphabet={a, b}\nstate={s, a0, a1, b0, b1}\nstart_state=s\nF={a1, b1}\n\
+         (s, a)->a1 & b0\n(s, b)->a0 & b1\n\
+         (a0, a)->a1\n(a0, b)->a0\n(a1, a)->a1\n(a1, b)->a1\n\
+         (b0, a)->b0\n(b0, b)->b1\n(b1, a)->b0\n(b1, b)->b1\n",
+    )
+    .unwrap();
+    for word in all_words(6) {
+        let expected = word.contains('a') && word.ends_with('b');
+        assert_eq!(afa.run(word.chars()), expected, "\"{}\"", word);
+    }
+}
+
+#[test]
+fn parse_errors_report_their_line() {
+    for (text, line) in [
+        ("state={p}\nstart_state=q\n", 2),
+        ("state={p}\nstart_state=p\nF={p, q}\n", 3),
+        ("state={p}\nstart_state=p\n(q, a)->p\n", 3),
+        ("state={p}\nstart_state=p\n(p, a)->p & q\n", 3),
+        ("state={p}\nstart_state=p\n(p, a)->{p, q}\n", 3),
+        ("state={p}\nF={p}\n", 0),
+        ("start_state=p\n", 1),
+    ] {
+        let error = AFA::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+    }
+}
+
+#[test]
+fn repeated_declarations_keep_a_single_state() {
+    let afa = AFA::from_string("state={p, q}\nstate={q, p, r}\nstart_state=q\n").unwrap();
+    assert_eq!(afa.states(), ["p", "q", "r"]);
+    assert_eq!(afa.start_state(), "q");
+    assert!(afa.transition("x", 'a').is_none());
+}
+
+#[test]
+fn deep_formulas_are_rejected_without_overflowing() {
+    let with_target = |target: String| {
+        AFA::from_string(&format!(
+            "state={{p}}\nstart_state=p\nF={{p}}\n(p, a)->{}\n",
+            target
+        ))
+    };
+    let chain = |length: usize| vec!["p"; length].join(" & ");
+    let nested = |levels: usize| format!("{}p{}", "(".repeat(levels), ")".repeat(levels));
+
+    // Las fórmulas en el límite se pueden usar normalmente
+    for target in [chain(MAX_DEPTH), nested(MAX_DEPTH)] {
+        let afa = with_target(target).unwrap();
+        assert!(afa.run("aa".chars()));
+        assert!(!afa.complement().run("aa".chars()));
+        assert!(afa.to_nfa().run("aa".chars()));
+    }
+
+    for target in [
+        chain(MAX_DEPTH + 1),
+        nested(MAX_DEPTH + 1),
+        chain(50_000),
+        nested(100_000),
+        vec!["p"; 50_000].join(" | "),
+    ] {
+        let error = with_target(target).err().unwrap();
+        assert_eq!(error.line, 4, "{}", error);
+        assert!(error.message.contains(&MAX_DEPTH.to_string()), "{}", error);
+    }
+}
@@ -0,0 +1,76 @@
+use automata::batch::{parse_cases, run_cases, to_junit, TestCase};
+use automata::nfa::NFA;
+
+// Palabras sobre {a, b} que terminan en `a`.
+const ENDS_IN_A: &str = "
+    alphabet={a, b}
+    state={p, q}
+    start_state=p
+    F={q}
+    (p, a)->{p, q}
+    (p, b)->{p}
+";
+
+fn case(line: usize, word: &str, expected: bool) -> TestCase {
+    TestCase {
+        line,
+        word: word.to_string(),
+        expected,
+    }
+}
+
+#[test]
+fn blank_words_comments_and_empty_lines() {
+    let cases = parse_cases(
+        "# casos de prueba\n\naccept: a\nreject:\n  reject: ε  \n   # otro comentario\naccept:ba\n",
+    )
+    .unwrap();
+    assert_eq!(
+        cases,
+        vec![
+            case(3, "a", true),
+            case(4, "", false),
+            case(5, "", false),
+            case(7, "ba", true),
+        ]
+    );
+}
+
+#[test]
+fn bad_prefixes_report_their_line() {
+    for (text, line) in [
+        ("accept: a\nacept: b\n", 2),
+        ("\n\nmaybe: a\n", 3),
+        ("accept: a\nreject a\n", 2),
+        ("ACCEPT: a\n", 1),
+    ] {
+        let error = parse_cases(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}", text);
+    }
+}
+
+#[test]
+fn junit_counts_failures_and_escapes_xml() {
+    let nfa = NFA::try_from_string(ENDS_IN_A).unwrap();
+    let cases = parse_cases("accept: ba\nreject: a\nreject: a<&\"'>\naccept: ε\n").unwrap();
+    let results = run_cases(&nfa, &cases);
+    let xml = to_junit("casos <\"&'>.txt", &results);
+
+    assert!(xml.contains("<testsuites tests=\"4\" failures=\"2\">"));
+    assert!(xml.contains("name=\"casos &lt;&quot;&amp;&apos;&gt;.txt\""));
+    assert!(xml.contains("name=\"línea 3: reject a&lt;&amp;&quot;&apos;&gt;\"/>"));
+    assert!(xml.contains("name=\"línea 4: accept ε\">"));
+    assert_eq!(xml.matches("<failure ").count(), 2);
+    // Fuera de las entidades no queda ningún carácter especial suelto
+    for line in xml.lines().skip(1) {
+        let inner = line.trim().trim_start_matches('<').trim_end_matches('>');
+        let inner = inner.trim_end_matches('/');
+        assert!(!inner.contains(['<', '>', '\'']), "{}", line);
+        assert_eq!(
+            inner.matches('&').count(),
+            inner.matches(';').count(),
+            "{}",
+            line
+        );
+    }
+}
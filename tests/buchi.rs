@@ -0,0 +1,135 @@
+use automata::buchi::{Buchi, Lasso};
+
+// Palabras con infinitas `a`.
+const INFINITELY_MANY_A: &str = "
+    alphabet={a, b}
+    state={p, q}
+    start_state=p
+    F={q}
+    (p, a)->{q}
+    (p, b)->{p}
+    (q, a)->{q}
+    (q, b)->{p}
+";
+
+#[test]
+fn lasso_notation_feeds_acceptance() {
+    let buchi = Buchi::from_string(INFINITELY_MANY_A).unwrap();
+    for (text, expected) in [
+        ("(a)^ω", true),
+        ("bbb(ab)^ω", true),
+        ("aaa(b)^ω", false),
+        ("(b)^w", false),
+        ("b a ( b b a )^ω", true),
+    ] {
+        let lasso = Lasso::parse(text).unwrap();
+        assert_eq!(buchi.accepts(&lasso), expected, "{}", text);
+    }
+}
+
+#[test]
+fn lasso_display_is_parsed_back() {
+    let buchi = Buchi::from_string(INFINITELY_MANY_A).unwrap();
+    let lasso = buchi.find_accepting_lasso().unwrap();
+    assert_eq!(Lasso::parse(&lasso.to_string()), Ok(lasso));
+}
+
+#[test]
+fn malformed_lassos_are_rejected() {
+    for text in [
+        "",
+        "ab",
+        "ab()^ω",
+        "a(b)",
+        "a(b)^ω(c)^ω",
+        "a)b(c)^ω",
+        "(a(b))^ω",
+    ] {
+        assert!(Lasso::parse(text).is_err(), "{}", text);
+    }
+}
+
+#[test]
+fn malformed_description_is_an_error() {
+    let error = Buchi::from_string("state={p}\nstart_state=q\n")
+        .err()
+        .unwrap();
+    assert_eq!(error.line, 2);
+}
+
+// Palabras con infinitas `b`.
+const INFINITELY_MANY_B: &str = "
+    alphabet={a, b}
+    state={p, q}
+    start_state=p
+    F={q}
+    (p, a)->{p}
+    (p, b)->{q}
+    (q, a)->{p}
+    (q, b)->{q}
+";
+
+// Palabras que desde algún punto solo tienen `b`.
+const EVENTUALLY_ONLY_B: &str = "
+    alphabet={a, b}
+    state={p, q}
+    start_state=p
+    F={q}
+    (p, a)->{p}
+    (p, b)->{p, q}
+    (q, b)->{q}
+";
+
+// Todas las palabras sobre {a, b} con largo entre `min_length` y `max_length`.
+fn words(min_length: usize, max_length: usize) -> Vec<Vec<char>> {
+    let mut words = vec![Vec::new()];
+    let mut last = vec![Vec::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word: &Vec<char>| {
+                ['a', 'b'].map(|c| {
+                    let mut word = word.clone();
+                    word.push(c);
+                    word
+                })
+            })
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words.retain(|word| word.len() >= min_length);
+    words
+}
+
+#[test]
+fn intersection_accepts_the_lassos_of_both() {
+    let many_a = Buchi::from_string(INFINITELY_MANY_A).unwrap();
+    let many_b = Buchi::from_string(INFINITELY_MANY_B).unwrap();
+    let both = many_a.intersection(&many_b);
+
+    for prefix in words(0, 2) {
+        for cycle in words(1, 4) {
+            assert_eq!(
+                both.accepts_lasso(&prefix, &cycle),
+                many_a.accepts_lasso(&prefix, &cycle) && many_b.accepts_lasso(&prefix, &cycle),
+                "{:?}({:?})^ω",
+                prefix,
+                cycle
+            );
+        }
+    }
+
+    // Ningún estado acepta en ambos a la vez: solo la copia permite aceptar (ab)^ω
+    let lasso = Lasso::parse("(ab)^ω").unwrap();
+    assert!(both.accepts(&lasso));
+    let found = both.find_accepting_lasso().unwrap();
+    assert!(many_a.accepts(&found) && many_b.accepts(&found));
+}
+
+#[test]
+fn intersection_of_disjoint_languages_is_empty() {
+    let many_a = Buchi::from_string(INFINITELY_MANY_A).unwrap();
+    let only_b = Buchi::from_string(EVENTUALLY_ONLY_B).unwrap();
+    assert!(!many_a.is_empty() && !only_b.is_empty());
+    assert!(many_a.intersection(&only_b).is_empty());
+}
@@ -0,0 +1,89 @@
+//! Utilidades compartidas por las pruebas: autómatas, expresiones y palabras aleatorias.
+
+#![allow(dead_code)]
+
+use automata::generator::{generate, GeneratorOptions};
+use automata::nfa::NFA;
+use automata::random::Rng;
+use automata::regex::Regex;
+
+pub const ALPHABET: [char; 2] = ['a', 'b'];
+
+// Cantidad de semillas que recorre cada propiedad.
+pub const CASES: u64 = 150;
+
+// Autómata aleatorio reproducible a partir de una semilla.
+pub fn random_nfa(seed: u64, deterministic: bool) -> NFA<char> {
+    let mut rng = Rng::new(seed);
+    let options = GeneratorOptions {
+        states: 1 + rng.below(5),
+        alphabet: ALPHABET.to_vec(),
+        density: 0.1 + rng.next_f64() * 0.4,
+        accept_ratio: 0.2 + rng.next_f64() * 0.4,
+        deterministic,
+        trim: false,
+        nonempty: false,
+        ..GeneratorOptions::default()
+    };
+    generate(&mut rng, &options).expect("las opciones de prueba siempre se pueden cumplir")
+}
+
+// Expresión regular aleatoria de profundidad acotada sobre `ALPHABET`.
+pub fn random_regex(rng: &mut Rng, depth: usize) -> Regex {
+    if depth == 0 || rng.chance(0.25) {
+        return match rng.below(10) {
+            0 => Regex::Epsilon,
+            1 => Regex::Empty,
+            i => Regex::Symbol(ALPHABET[i % ALPHABET.len()]),
+        };
+    }
+    match rng.below(4) {
+        0 => Regex::Concat(
+            Box::new(random_regex(rng, depth - 1)),
+            Box::new(random_regex(rng, depth - 1)),
+        ),
+        1 => Regex::Union(
+            Box::new(random_regex(rng, depth - 1)),
+            Box::new(random_regex(rng, depth - 1)),
+        ),
+        2 => Regex::Star(Box::new(random_regex(rng, depth - 1))),
+        _ => Regex::Plus(Box::new(random_regex(rng, depth - 1))),
+    }
+}
+
+// Todas las palabras sobre `ALPHABET` de largo a lo más `max_length`, en orden por largo.
+pub fn all_words(max_length: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word| ALPHABET.iter().map(move |c| format!("{}{}", word, c)))
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+// Palabra aleatoria sobre `ALPHABET` de largo a lo más `max_length`.
+pub fn random_word(rng: &mut Rng, max_length: usize) -> String {
+    let length = rng.below(max_length + 1);
+    (0..length)
+        .map(|_| ALPHABET[rng.below(ALPHABET.len())])
+        .collect()
+}
+
+// Verifica que ambos autómatas coinciden en todas las palabras cortas.
+pub fn assert_same_language(left: &NFA<char>, right: &NFA<char>, context: &str) {
+    for word in all_words(6) {
+        assert_eq!(
+            left.run(word.chars()),
+            right.run(word.chars()),
+            "{}: difieren en \"{}\"\n{}\n{}",
+            context,
+            word,
+            left.to_description(),
+            right.to_description()
+        );
+    }
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use automata::jflap::JflapAutomaton;
+use automata::nfa::NFA;
+use automata::random::Rng;
+use common::{assert_same_language, random_nfa, random_regex, CASES};
+
+#[test]
+fn description_round_trips() {
+    for seed in 0..CASES {
+        let nfa = random_nfa(seed, false);
+        let text = nfa.to_description();
+        let parsed = NFA::try_from_string(&text).unwrap();
+        assert_eq!(parsed.to_description(), text, "semilla {}", seed);
+        assert_same_language(&nfa, &parsed, &format!("semilla {}", seed));
+    }
+}
+
+#[test]
+fn description_with_epsilon_transitions_round_trips() {
+    let mut rng = Rng::new(7);
+    for case in 0..CASES {
+        let nfa = random_regex(&mut rng, 3).to_nfa();
+        let text = nfa.to_description();
+        let parsed = NFA::try_from_string(&text).unwrap();
+        assert_eq!(parsed.to_description(), text, "caso {}", case);
+    }
+}
+
+#[test]
+fn jflap_round_trips() {
+    for seed in 0..CASES {
+        let nfa = random_nfa(seed, false);
+        let parsed = JflapAutomaton::from_jff(&nfa.to_jff()).unwrap().nfa;
+        assert_same_language(&nfa, &parsed, &format!("semilla {}", seed));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_and_yaml_round_trip() {
+    for seed in 0..CASES {
+        let nfa = random_nfa(seed, false);
+        let text = nfa.to_description();
+        let json = NFA::from_json(&nfa.to_json()).unwrap();
+        let yaml = NFA::from_yaml(&nfa.to_yaml()).unwrap();
+        assert_eq!(json.to_description(), text, "semilla {}", seed);
+        assert_eq!(yaml.to_description(), text, "semilla {}", seed);
+    }
+}
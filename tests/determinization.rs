@@ -0,0 +1,62 @@
+mod common;
+
+use automata::random::Rng;
+use common::{all_words, assert_same_language, random_nfa, random_regex, ALPHABET, CASES};
+
+#[test]
+fn run_agrees_with_determinized_automaton() {
+    for seed in 0..CASES {
+        let nfa = random_nfa(seed, false);
+        assert_same_language(&nfa, &nfa.determinize(), &format!("semilla {}", seed));
+    }
+}
+
+#[test]
+fn determinization_handles_epsilon_transitions() {
+    let mut rng = Rng::new(48);
+    for case in 0..CASES {
+        let nfa = random_regex(&mut rng, 4).to_nfa();
+        let dfa = nfa.determinize();
+        assert!(!dfa.has_epsilon());
+        assert_same_language(&nfa, &dfa, &format!("caso {}", case));
+    }
+}
+
+#[test]
+fn prepared_runner_agrees_with_run() {
+    let mut rng = Rng::new(26);
+    for case in 0..CASES {
+        let nfa = random_regex(&mut rng, 4).to_nfa();
+        let runner = nfa.runner();
+        for word in all_words(5) {
+            assert_eq!(
+                runner.run(word.chars()),
+                nfa.run(word.chars()),
+                "caso {}: \"{}\"",
+                case,
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn determinized_automaton_is_deterministic_and_complete() {
+    for seed in 0..CASES {
+        let dfa = random_nfa(seed, false).determinize();
+        assert!(dfa.table_filling().is_ok(), "semilla {}", seed);
+    }
+}
+
+#[test]
+fn explanation_has_one_step_per_subset_and_symbol() {
+    for seed in 0..CASES {
+        let explanation = random_nfa(seed, false).explain_determinization();
+        assert_eq!(
+            explanation.steps.len(),
+            explanation.dfa.states().len() * ALPHABET.len()
+        );
+        let new_subsets = explanation.steps.iter().filter(|step| step.is_new).count();
+        assert_eq!(new_subsets + 1, explanation.dfa.states().len());
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::BTreeSet;
+
+use automata::nfa::{Node, NFA};
+
+// Estados con llaves, comas y comillas; tres transiciones paralelas (una con `:`) y una ε.
+fn awkward() -> NFA<char> {
+    let subset = Node::new("{q0, q1}", false);
+    let quoted = Node::new("say \"hi\": <x>", true);
+    for symbol in ['a', ':', 'b'] {
+        Node::add_transition(&subset, symbol, quoted.clone());
+    }
+    Node::add_transition(&quoted, 'a', quoted.clone());
+    Node::add_epsilon_transition(&quoted, subset.clone());
+    NFA::from_parts(
+        vec![subset.clone(), quoted],
+        BTreeSet::from(['a', 'b', ':']),
+        subset,
+    )
+}
+
+#[test]
+fn mermaid_escapes_names_and_merges_parallel_edges() {
+    assert_eq!(
+        awkward().to_mermaid(),
+        r##"stateDiagram-v2
+    direction LR
+    classDef accepting stroke-width:4px
+    state "#123;q0, q1#125;" as s0
+    state "say #quot;hi#quot;#58; #60;x#62;" as s1
+    [*] --> s0
+    s0 --> s1 : #58;, a, b
+    s1 --> s0 : ε
+    s1 --> s1 : a
+    s1 --> [*]
+    class s1 accepting
+"##
+    );
+}
+
+#[test]
+fn plantuml_escapes_names_and_merges_parallel_edges() {
+    assert_eq!(
+        awkward().to_plantuml(),
+        r#"@startuml
+hide empty description
+left to right direction
+state "{q0, q1}" as s0
+state "say <U+0022>hi<U+0022><U+003A> <U+003C>x<U+003E>" as s1 ##[bold]
+[*] --> s0
+s0 --> s1 : <U+003A>, a, b
+s1 --> s0 : ε
+s1 --> s1 : a
+s1 --> [*]
+@enduml
+"#
+    );
+}
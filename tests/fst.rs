@@ -0,0 +1,147 @@
+use std::collections::BTreeSet;
+
+use automata::fst::{Label, NFST};
+use automata::nfa::{Node, StateRef, NFA};
+
+// Transductor `a:x` seguido de una transición ε hacia el estado final.
+fn with_epsilon() -> NFST {
+    let p: StateRef<Label<char, char>> = Node::new("p", false);
+    let q = Node::new("q", false);
+    let r = Node::new("r", true);
+    let label = Label::new(vec!['a'], vec!['x']);
+    Node::add_transition(&p, label.clone(), q.clone());
+    Node::add_epsilon_transition(&q, r.clone());
+    NFST::from_nfa(NFA::from_parts(
+        vec![p.clone(), q, r],
+        BTreeSet::from([label]),
+        p,
+    ))
+}
+
+#[test]
+fn epsilon_transitions_are_followed() {
+    let fst = with_epsilon();
+    assert_eq!(
+        fst.transduce("a".chars()),
+        Some(BTreeSet::from([vec!['x']]))
+    );
+    assert!(fst.input_projection().run("a".chars()));
+    assert!(fst.output_projection().run("x".chars()));
+}
+
+#[test]
+fn inversion_keeps_epsilon_transitions() {
+    let inverse = with_epsilon().invert();
+    assert_eq!(
+        inverse.transduce("x".chars()),
+        Some(BTreeSet::from([vec!['a']]))
+    );
+}
+
+// Traduce cada `a` a `x` o a `yy`, de forma no determinista, y borra las `b`.
+const CHOICE: &str = "
+    state={p}
+    start_state=p
+    F={p}
+    (p, a:x)->{p}
+    (p, a:yy)->{p}
+    (p, b:ε)->{p}
+";
+
+// Traduce `x` a `1` e `y` a `2`.
+const DIGITS: &str = "
+    state={s}
+    start_state=s
+    F={s}
+    (s, x:1)->{s}
+    (s, y:2)->{s}
+";
+
+// Conjunto de salidas escrito como cadenas.
+fn outputs(words: &[&str]) -> Option<BTreeSet<Vec<char>>> {
+    Some(words.iter().map(|word| word.chars().collect()).collect())
+}
+
+#[test]
+fn transduce_collects_every_output() {
+    let fst = NFST::from_string(CHOICE).unwrap();
+    assert_eq!(fst.transduce("".chars()), outputs(&[""]));
+    assert_eq!(fst.transduce("ab".chars()), outputs(&["x", "yy"]));
+    assert_eq!(
+        fst.transduce("aba".chars()),
+        outputs(&["xx", "xyy", "yyx", "yyyy"])
+    );
+    assert_eq!(fst.transduce("c".chars()), outputs(&[]));
+
+    // Un ciclo ε que escribe produce infinitas salidas
+    let endless = NFST::from_string("state={p}\nstart_state=p\nF={p}\n(p, ε:z)->{p}\n").unwrap();
+    assert_eq!(endless.transduce("".chars()), None);
+}
+
+#[test]
+fn compose_chains_both_translations() {
+    let first = NFST::from_string(CHOICE).unwrap();
+    let second = NFST::from_string(DIGITS).unwrap();
+    let composed = first.compose(&second);
+
+    for word in ["", "a", "ab", "aa", "bab", "aba"] {
+        let expected: BTreeSet<Vec<char>> = first
+            .transduce(word.chars())
+            .unwrap()
+            .iter()
+            .flat_map(|middle| second.transduce(middle.iter().copied()).unwrap())
+            .collect();
+        assert_eq!(
+            composed.transduce(word.chars()),
+            Some(expected),
+            "\"{}\"",
+            word
+        );
+    }
+    assert_eq!(
+        composed.transduce("aa".chars()),
+        outputs(&["11", "122", "221", "2222"])
+    );
+}
+
+#[test]
+fn apply_maps_a_language_through_the_transducer() {
+    // a*b con la traducción de `CHOICE`: (x | yy)*
+    let nfa = NFA::try_from_string(
+        "alphabet={a, b}\nstate={p, q}\nstart_state=p\nF={q}\n(p, a)->{p}\n(p, b)->{q}\n",
+    )
+    .unwrap();
+    let image = NFST::from_string(CHOICE).unwrap().apply(&nfa);
+
+    for (word, expected) in [
+        ("", true),
+        ("x", true),
+        ("yy", true),
+        ("xyyx", true),
+        ("y", false),
+        ("xy", false),
+        ("b", false),
+    ] {
+        assert_eq!(image.run(word.chars()), expected, "\"{}\"", word);
+    }
+}
+
+#[test]
+fn parse_errors_report_their_line() {
+    for (text, line) in [
+        ("state={p}\nstart_state=q\n", 2),
+        ("state={p}\nstart_state=p\nF={p, q}\n", 3),
+        ("state={p}\nstart_state=p\n(q, a:x)->{p}\n", 3),
+        ("state={p}\nstart_state=p\n(p, a:x)->{p, q}\n", 3),
+        ("state={p}\nstart_state=p\n(p, ax)->{p}\n", 3),
+        ("state={p}\nF={p}\n", 0),
+        ("start_state=p\n", 1),
+    ] {
+        let error = NFST::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+    }
+
+    let fst = NFST::from_string("state={p, q}\nstate={q, p}\nstart_state=q\n").unwrap();
+    assert_eq!(fst.as_nfa().states().len(), 2);
+    assert_eq!(fst.as_nfa().start_state().borrow().state, "q");
+}
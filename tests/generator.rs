@@ -0,0 +1,87 @@
+mod common;
+
+use automata::generator::{generate, GeneratorOptions};
+use automata::nfa::NFA;
+use automata::random::Rng;
+use common::{all_words, random_word, CASES};
+
+#[test]
+fn same_seed_produces_the_same_automaton() {
+    let options = GeneratorOptions::default();
+    for seed in 0..CASES {
+        let first = generate(&mut Rng::new(seed), &options).unwrap();
+        let second = generate(&mut Rng::new(seed), &options).unwrap();
+        assert_eq!(first.to_description(), second.to_description());
+    }
+}
+
+#[test]
+fn generated_automata_are_connected_trim_and_nonempty() {
+    for seed in 0..CASES {
+        let options = GeneratorOptions {
+            states: 2 + (seed % 6) as usize,
+            ..GeneratorOptions::default()
+        };
+        let nfa = generate(&mut Rng::new(seed), &options).unwrap();
+        let n = nfa.states().len();
+        assert_eq!(n, options.states);
+        let words = all_words(n);
+        assert!(words.iter().any(|word| nfa.run(word.chars())));
+
+        let description = nfa.to_description();
+        let line = |prefix: &str| {
+            description
+                .lines()
+                .find(|line| line.starts_with(prefix))
+                .unwrap()
+                .to_string()
+        };
+        for state in nfa.states() {
+            let name = &state.borrow().state;
+            // Alcanzable: aceptando solo en `name` alguna palabra es aceptada
+            let reach = description.replace(&line("F="), &format!("F={{{}}}", name));
+            let reach = NFA::try_from_string(&reach).unwrap();
+            assert!(words.iter().any(|word| reach.run(word.chars())), "{}", name);
+            // Útil: empezando en `name` alguna palabra es aceptada
+            let useful =
+                description.replace(&line("start_state="), &format!("start_state={}", name));
+            let useful = NFA::try_from_string(&useful).unwrap();
+            assert!(
+                words.iter().any(|word| useful.run(word.chars())),
+                "{}",
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn generated_dfa_is_deterministic() {
+    for seed in 0..CASES {
+        let options = GeneratorOptions {
+            deterministic: true,
+            complete: true,
+            ..GeneratorOptions::default()
+        };
+        let dfa = generate(&mut Rng::new(seed), &options).unwrap();
+        assert!(dfa.table_filling().is_ok());
+    }
+}
+
+#[test]
+fn pumped_words_stay_in_the_language() {
+    let mut rng = Rng::new(46);
+    for seed in 0..CASES {
+        let nfa = generate(&mut Rng::new(seed), &GeneratorOptions::default()).unwrap();
+        let n = nfa.states().len();
+        for _ in 0..20 {
+            let word: Vec<char> = random_word(&mut rng, 2 * n).chars().collect();
+            if word.len() < n || !nfa.run(word.iter().copied()) {
+                continue;
+            }
+            let pumping = nfa.pump(&word, &[0, 1, 2, 3]).unwrap();
+            assert!(!pumping.y.is_empty() && pumping.x.len() + pumping.y.len() <= n);
+            assert!(pumping.examples.iter().all(|example| example.accepted));
+        }
+    }
+}
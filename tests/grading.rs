@@ -0,0 +1,40 @@
+use automata::grading::{grade, GradingOptions, MAX_NON_EQUIVALENT_SCORE};
+use automata::nfa::NFA;
+
+// Acepta las palabras sobre {a} de largo menor que `n`.
+fn shorter_than(n: usize) -> String {
+    let states: Vec<String> = (0..=n).map(|i| format!("q{}", i)).collect();
+    let mut description = format!(
+        "alphabet={{a}}\nstate={{{}}}\nstart_state=q0\nF={{{}}}\n",
+        states.join(", "),
+        states[..n].join(", ")
+    );
+    for i in 0..n {
+        description.push_str(&format!("(q{}, a)->{{q{}}}\n", i, i + 1));
+    }
+    description.push_str(&format!("(q{}, a)->{{q{}}}\n", n, n));
+    description
+}
+
+#[test]
+fn differences_beyond_max_length_cap_the_score() {
+    let reference = NFA::try_from_string(&shorter_than(20)).unwrap();
+    let options = GradingOptions::default();
+    let report = grade(&reference, &shorter_than(options.max_length + 1), &options);
+
+    assert!(!report.equivalent);
+    assert_eq!(report.agreement, 1.0);
+    assert_eq!(report.score, MAX_NON_EQUIVALENT_SCORE);
+    assert_eq!(
+        report.wrongly_rejected,
+        vec!["a".repeat(options.max_length + 1)]
+    );
+}
+
+#[test]
+fn equivalent_submission_gets_full_score() {
+    let reference = NFA::try_from_string(&shorter_than(3)).unwrap();
+    let report = grade(&reference, &shorter_than(3), &GradingOptions::default());
+    assert!(report.equivalent);
+    assert_eq!(report.score, 100.0);
+}
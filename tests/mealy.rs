@@ -0,0 +1,129 @@
+use automata::mealy::{Mealy, Moore};
+
+// Emite `1` cuando el bit leído es distinto del anterior (el primero se compara con `0`).
+const EDGE: &str = "
+    alphabet={0, 1}
+    state={z, u}
+    start_state=z
+    (z, 0)->{z}/0
+    (z, 1)->{u}/1
+    (u, 0)->{z}/1
+    (u, 1)->{u}/0
+";
+
+// Emite la paridad de la cantidad de unos leídos hasta el momento.
+const PARITY: &str = "
+    alphabet={0, 1}
+    state={even/p, odd/i}
+    start_state=even
+    (even, 0)->{even}
+    (even, 1)->{odd}
+    (odd, 0)->{odd}
+    (odd, 1)->{even}
+";
+
+// Todas las palabras sobre {0, 1} de largo a lo más `max_length`.
+fn all_words(max_length: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word| ["0", "1"].map(|c| format!("{}{}", word, c)))
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+#[test]
+fn mealy_emits_one_output_per_symbol() {
+    let mealy = Mealy::from_string(EDGE).unwrap();
+    assert!(mealy.transduce("".chars()).is_empty());
+    assert_eq!(mealy.transduce("0110".chars()), ["0", "1", "0", "1"]);
+    assert_eq!(mealy.transduce("1111".chars()), ["1", "0", "0", "0"]);
+
+    // Sin transición para `2` se detiene con lo producido hasta ahí
+    assert_eq!(mealy.transduce("1021".chars()), ["1", "1"]);
+}
+
+#[test]
+fn moore_emits_the_start_output_first() {
+    let moore = Moore::from_string(PARITY).unwrap();
+    assert_eq!(moore.transduce("".chars()), ["p"]);
+    assert_eq!(moore.transduce("1101".chars()), ["p", "i", "p", "p", "i"]);
+    assert_eq!(moore.output("odd"), Some(&"i".to_string()));
+}
+
+#[test]
+fn conversions_preserve_the_translation() {
+    let mealy = Mealy::from_string(EDGE).unwrap();
+    let moore = mealy.to_moore("-".to_string());
+    let back = moore.to_mealy();
+    let parity = Moore::from_string(PARITY).unwrap();
+    let parity_mealy = parity.to_mealy();
+    let parity_again = parity_mealy.to_moore("p".to_string());
+
+    for word in all_words(6) {
+        let expected = mealy.transduce(word.chars());
+        let mut with_start = vec!["-".to_string()];
+        with_start.extend(expected.iter().cloned());
+        assert_eq!(moore.transduce(word.chars()), with_start, "\"{}\"", word);
+        assert_eq!(back.transduce(word.chars()), expected, "\"{}\"", word);
+
+        let expected = parity.transduce(word.chars());
+        assert_eq!(
+            parity_mealy.transduce(word.chars()),
+            expected[1..],
+            "\"{}\"",
+            word
+        );
+        assert_eq!(
+            parity_again.transduce(word.chars()),
+            expected,
+            "\"{}\"",
+            word
+        );
+    }
+    assert!(moore.is_complete() && back.is_complete());
+}
+
+#[test]
+fn mealy_parse_errors_report_their_line() {
+    for (text, line, fragment) in [
+        ("state={q}\nstart_state=r\n", 2, "\"r\" no existe"),
+        ("state={q}\n(q, a)->{r}/x\n", 2, "\"r\" no existe"),
+        ("state={q}\n(r, a)->{q}/x\n", 2, "\"r\" no existe"),
+        ("state={q}\n(q, a)->{q}\n", 2, "salida"),
+        ("state={q}\n(q, a)->{q}/\n", 2, "salida"),
+        (
+            "state={q}\n(q, a)->{q}/x\n(q, a)->{q}/y\n",
+            3,
+            "determinista",
+        ),
+        ("state={q}\nF={q}\n", 2, "aceptación"),
+        ("(q, a)->{q}/x\n", 1, "state="),
+        ("alphabet={a}\n", 0, "no tiene estados"),
+    ] {
+        let error = Mealy::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+        assert!(error.message.contains(fragment), "{:?}: {}", text, error);
+    }
+}
+
+#[test]
+fn moore_parse_errors_report_their_line() {
+    for (text, line, fragment) in [
+        ("state={q/x}\nstart_state=r\n", 2, "\"r\" no existe"),
+        ("state={q/x}\n(q, a)->{r}\n", 2, "\"r\" no existe"),
+        ("state={q/x, r}\n", 1, "salida"),
+        ("state={q/}\n", 1, "salida"),
+        ("state={q/x}\n(q, a)->{q, q}\n", 2, "exactamente un estado"),
+        ("state={q/x}\n(q, a)->{q}\n(q, a)->{q}\n", 3, "determinista"),
+        ("state={q/x}\nF={q}\n", 2, "aceptación"),
+    ] {
+        let error = Moore::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+        assert!(error.message.contains(fragment), "{:?}: {}", text, error);
+    }
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use automata::generator::{generate, GeneratorOptions};
+use automata::nfa::NFA;
+use automata::random::Rng;
+use common::{assert_same_language, random_nfa, ALPHABET, CASES};
+
+#[test]
+fn minimization_preserves_the_language() {
+    for seed in 0..CASES {
+        let nfa = random_nfa(seed, seed % 2 == 0);
+        assert_same_language(&nfa, &nfa.minimize(), &format!("semilla {}", seed));
+    }
+}
+
+#[test]
+fn minimization_is_idempotent() {
+    for seed in 0..CASES {
+        let minimal = random_nfa(seed, false).minimize();
+        let again = minimal.minimize();
+        assert_eq!(
+            minimal.to_description(),
+            again.to_description(),
+            "semilla {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn minimal_automaton_is_no_larger_than_the_determinized_one() {
+    for seed in 0..CASES {
+        let nfa = random_nfa(seed, false);
+        assert!(nfa.minimize().states().len() <= nfa.determinize().states().len());
+    }
+}
+
+#[test]
+fn minimal_automaton_has_no_equivalent_states() {
+    for seed in 0..CASES {
+        let table = random_nfa(seed, false).minimize().table_filling().unwrap();
+        assert_eq!(table.equivalence_classes().len(), table.states.len());
+    }
+}
+
+#[test]
+fn generated_minimal_dfa_is_already_minimal() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let options = GeneratorOptions {
+            states: 1 + (seed % 5) as usize,
+            deterministic: true,
+            complete: true,
+            minimal: true,
+            ..GeneratorOptions::default()
+        };
+        let dfa = generate(&mut rng, &options).unwrap();
+        assert_eq!(dfa.minimize().states().len(), dfa.states().len());
+    }
+}
+
+#[test]
+fn distinguishing_words_separate_the_marked_pairs() {
+    for seed in 0..CASES {
+        let options = GeneratorOptions {
+            states: 1 + (seed % 6) as usize,
+            deterministic: true,
+            complete: true,
+            trim: false,
+            ..GeneratorOptions::default()
+        };
+        let dfa = generate(&mut Rng::new(seed), &options).unwrap();
+        let table = dfa.table_filling().unwrap();
+        let description = dfa.to_description();
+        let start_line = format!("start_state={}", dfa.start_state().borrow().state);
+        // El mismo autómata empezando en cada estado
+        let from = |state: &str| {
+            NFA::try_from_string(
+                &description.replace(&start_line, &format!("start_state={}", state)),
+            )
+            .unwrap()
+        };
+
+        for (&(p, q), mark) in &table.marks {
+            let Some(mark) = mark else { continue };
+            let word: String = mark.word.iter().collect();
+            assert_ne!(
+                from(&table.states[p]).run(word.chars()),
+                from(&table.states[q]).run(word.chars()),
+                "semilla {}: \"{}\" no distingue ({}, {})",
+                seed,
+                word,
+                table.states[p],
+                table.states[q]
+            );
+            assert_eq!(mark.word.len(), mark.round);
+        }
+    }
+}
+
+#[test]
+fn table_filling_rejects_nondeterministic_automata() {
+    let nfa = NFA::try_from_string(&format!(
+        "alphabet={{{}}}\nstate={{p, q}}\nstart_state=p\nF={{q}}\n(p, a)->{{p, q}}\n",
+        ALPHABET.map(String::from).join(", ")
+    ))
+    .unwrap();
+    assert!(nfa.table_filling().is_err());
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use automata::symbolic::SymbolicNFA;
+use common::{all_words, assert_same_language, random_nfa, CASES};
+
+#[test]
+fn union_and_intersection_agree_with_run() {
+    for seed in 0..CASES {
+        let (a, b) = (random_nfa(seed, false), random_nfa(seed + CASES, false));
+        let (union, intersection) = (a.union(&b), a.intersection(&b));
+        for word in all_words(6) {
+            let (in_a, in_b) = (a.run(word.chars()), b.run(word.chars()));
+            assert_eq!(union.run(word.chars()), in_a || in_b);
+            assert_eq!(intersection.run(word.chars()), in_a && in_b);
+        }
+    }
+}
+
+#[test]
+fn complement_of_union_is_intersection_of_complements() {
+    for seed in 0..CASES {
+        let (a, b) = (random_nfa(seed, false), random_nfa(seed + CASES, true));
+        assert_same_language(
+            &a.union(&b).complement(),
+            &a.complement().intersection(&b.complement()),
+            &format!("semilla {}", seed),
+        );
+    }
+}
+
+#[test]
+fn complement_of_intersection_is_union_of_complements() {
+    for seed in 0..CASES {
+        let (a, b) = (random_nfa(seed, true), random_nfa(seed + CASES, false));
+        assert_same_language(
+            &a.intersection(&b).complement(),
+            &a.complement().union(&b.complement()),
+            &format!("semilla {}", seed),
+        );
+    }
+}
+
+#[test]
+fn double_complement_is_the_identity() {
+    for seed in 0..CASES {
+        let nfa = random_nfa(seed, false);
+        assert_same_language(
+            &nfa,
+            &nfa.complement().complement(),
+            &format!("semilla {}", seed),
+        );
+    }
+}
+
+#[test]
+fn symbolic_operations_satisfy_de_morgan() {
+    for seed in 0..CASES {
+        let [a, b] = [seed, seed + CASES].map(|seed| {
+            let text = random_nfa(seed, false)
+                .to_description()
+                .replace(", a)", ", [a])")
+                .replace(", b)", ", [b])");
+            SymbolicNFA::from_string(&text).unwrap()
+        });
+        let left = a.intersection(&b).complement();
+        let (not_a, not_b) = (a.complement(), b.complement());
+        for word in all_words(6) {
+            assert_eq!(
+                left.run(word.chars()),
+                not_a.run(word.chars()) || not_b.run(word.chars()),
+                "semilla {}: \"{}\"",
+                seed,
+                word
+            );
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use automata::pda::{Outcome, PDA};
+
+// aⁿbⁿ con n ≥ 1: apila una `A` por cada `a` y desapila una por cada `b`.
+const ANBN: &str = "
+    alphabet={a, b}
+    state={p, q, f}
+    start_state=p
+    start_stack=Z
+    (p, a, Z)->{(p, AZ)}
+    (p, a, A)->{(p, AA)}
+    (p, b, A)->{(q, ε)}
+    (q, b, A)->{(q, ε)}
+";
+
+// Todas las palabras sobre {a, b} de largo a lo más `max_length`.
+fn all_words(max_length: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word| ["a", "b"].map(|c| format!("{}{}", word, c)))
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+fn is_anbn(word: &str) -> bool {
+    let n = word.len() / 2;
+    n > 0 && word == format!("{}{}", "a".repeat(n), "b".repeat(n))
+}
+
+#[test]
+fn anbn_by_empty_stack() {
+    let pda = PDA::from_string(&format!(
+        "{}(q, ε, Z)->{{(f, ε)}}\nacceptance=empty\n",
+        ANBN
+    ))
+    .unwrap();
+    for word in all_words(8) {
+        assert_eq!(pda.run(&word), is_anbn(&word), "\"{}\"", word);
+    }
+}
+
+#[test]
+fn anbn_by_final_state() {
+    let pda = PDA::from_string(&format!("{}(q, ε, Z)->{{(f, Z)}}\nF={{f}}\n", ANBN)).unwrap();
+    for word in all_words(8) {
+        assert_eq!(pda.run(&word), is_anbn(&word), "\"{}\"", word);
+    }
+}
+
+#[test]
+fn epsilon_cycle_keeping_the_stack_terminates() {
+    let pda = PDA::from_string(
+        "alphabet={a}\nstate={p, q}\nstart_state=p\nstart_stack=Z\nF={q}\n\
+         (p, ε, Z)->{(p, Z)}\n(p, ε, ε)->{(p, ε)}\n",
+    )
+    .unwrap();
+    assert_eq!(pda.search("a", 1_000), Outcome::Rejected);
+}
+
+#[test]
+fn epsilon_cycle_growing_the_stack_hits_the_limit() {
+    let pda = PDA::from_string(
+        "alphabet={a}\nstate={p, q}\nstart_state=p\nstart_stack=Z\nF={q}\n(p, ε, ε)->{(p, Z)}\n",
+    )
+    .unwrap();
+    assert_eq!(pda.search("a", 1_000), Outcome::LimitReached);
+}
+
+#[test]
+fn parse_errors_report_their_line() {
+    for (text, line) in [
+        ("state={p}\nstart_state=q\n", 2),
+        ("state={p}\nstart_state=p\nF={p, q}\n", 3),
+        ("state={p}\nstart_state=p\n(p, a, ε)->{(q, ε)}\n", 3),
+        ("state={p}\nstart_state=p\n(p, a)->{(p, ε)}\n", 3),
+        ("state={p}\nacceptance=maybe\n", 2),
+        ("state={p}\nF={p}\n", 0),
+        ("start_state=p\n", 1),
+    ] {
+        let error = PDA::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+    }
+}
+
+#[test]
+fn repeated_declarations_keep_a_single_state() {
+    let pda = PDA::from_string("state={p, q}\nstate={q, p, r}\nstart_state=q\n").unwrap();
+    assert_eq!(pda.states(), ["p", "q", "r"]);
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use automata::probabilistic::PFA;
+use automata::random::Rng;
+
+// Desde `p` se lee `a` (quedándose o pasando a `q`) o `b`; `q` acepta y termina el recorrido.
+const CHAIN: &str = "
+    alphabet={a, b}
+    state={p, q}
+    start_state=p
+    F={q}
+    (p, a)->{p:0.25, q:0.25}
+    (p, b)->{q:0.5}
+";
+
+// Todas las palabras sobre {a, b} de largo a lo más `max_length`.
+fn all_words(max_length: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word| ["a", "b"].map(|c| format!("{}{}", word, c)))
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+#[test]
+fn check_requires_each_state_to_sum_one_over_the_alphabet() {
+    for (text, line) in [
+        // Cada símbolo por separado suma 1, pero el estado suma 2
+        (
+            "state={p}\nstart_state=p\n(p, a)->{p:1}\n(p, b)->{p:1}\n",
+            3,
+        ),
+        (
+            "state={p, q}\nstart_state=p\n(p, a)->{p:0.3}\n(p, b)->{q:0.3}\n",
+            3,
+        ),
+        (
+            "state={p}\nstart_state=p\n(p, a)->{p:0.5}\n(p, b)->{p:1.5}\n",
+            4,
+        ),
+        ("state={p}\nstart_state=p\nF={p:0.5}\n(p, a)->{p}\n", 3),
+    ] {
+        let error = PFA::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+    }
+
+    // La suma se compara con tolerancia y un estado sin transiciones es válido
+    let pfa = PFA::from_string(
+        "state={p, q}\nstart_state=p\nF={q}\n(p, a)->{p:0.1, q:0.2}\n(p, b)->{q:0.7}\n",
+    );
+    assert!(pfa.is_ok());
+}
+
+#[test]
+fn word_probability_multiplies_along_each_path() {
+    let pfa = PFA::from_string(CHAIN).unwrap();
+    for (word, expected) in [
+        ("", 0.0),
+        ("a", 0.25),
+        ("b", 0.5),
+        ("aa", 0.0625),
+        ("ab", 0.125),
+        ("ba", 0.0),
+    ] {
+        let probability = pfa.acceptance_probability(word.chars());
+        assert!(
+            (probability - expected).abs() < 1e-12,
+            "\"{}\": {}",
+            word,
+            probability
+        );
+    }
+
+    // Las palabras forman una distribución: su probabilidad total tiende a 1
+    let total: f64 = all_words(12)
+        .iter()
+        .map(|word| pfa.acceptance_probability(word.chars()))
+        .sum();
+    assert!((total - 1.0).abs() < 1e-6, "{}", total);
+}
+
+#[test]
+fn sampling_frequencies_follow_the_word_probabilities() {
+    let pfa = PFA::from_string(CHAIN).unwrap();
+    let mut rng = Rng::new(31);
+    let samples = 20_000;
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for _ in 0..samples {
+        let (word, accepted) = pfa.sample(&mut rng, 0.5, 100);
+        assert!(accepted);
+        *counts.entry(word.into_iter().collect()).or_default() += 1;
+    }
+
+    for word in ["a", "b", "aa", "ab", "aab"] {
+        let frequency = counts.get(word).copied().unwrap_or(0) as f64 / samples as f64;
+        let expected = pfa.acceptance_probability(word.chars());
+        assert!(
+            (frequency - expected).abs() < 0.015,
+            "\"{}\": {} y se esperaba {}",
+            word,
+            frequency,
+            expected
+        );
+    }
+
+    // La misma semilla genera las mismas palabras
+    let [first, second] = [7, 7].map(|seed| {
+        let mut rng = Rng::new(seed);
+        (0..20)
+            .map(|_| pfa.sample(&mut rng, 0.5, 100).0)
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(first, second);
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use automata::nfa::NFA;
+use automata::random::Rng;
+use automata::regex::Regex;
+use common::{all_words, assert_same_language, random_regex, CASES};
+
+#[test]
+fn thompson_automaton_agrees_with_derivatives() {
+    let mut rng = Rng::new(2024);
+    for _ in 0..CASES {
+        let regex = random_regex(&mut rng, 4);
+        let nfa = regex.to_nfa();
+        for word in all_words(6) {
+            assert_eq!(
+                nfa.run(word.chars()),
+                regex.matches(&word),
+                "{} con \"{}\"",
+                regex,
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn display_round_trips_through_the_parser() {
+    let mut rng = Rng::new(99);
+    for _ in 0..CASES {
+        let regex = random_regex(&mut rng, 4);
+        let text = regex.to_string();
+        let parsed = Regex::parse(&text).unwrap();
+        assert_same_language(&regex.to_nfa(), &parsed.to_nfa(), &text);
+    }
+}
+
+#[test]
+fn invalid_expressions_are_reported() {
+    for text in ["(ab", "a)", "*a", "a|*", "\\"] {
+        assert!(NFA::from_regex(text).is_err(), "{}", text);
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::BTreeSet;
+
+use automata::buchi::Buchi;
+use automata::nfa::{Node, StateRef, NFA};
+use automata::regex::{Regex, MAX_DEPTH};
+
+// Descripciones mal formadas y la línea del error. Todas declaran sus estados y el
+// estado inicial, para que el error sea el de la línea que se quiere probar.
+const MALFORMED: [(&str, usize); 10] = [
+    ("state={q0}\nstart_state=q0\n(q9, 0)->{q0}", 3),
+    ("state={q0}\nstart_state=q0\n(q0, 0)", 3),
+    ("state={q0}\nstart_state=q0\n(q0)->{q0}", 3),
+    ("state={q0}\nstart_state=q0\n(q0, )->{q0}", 3),
+    ("state={q0}\nstart_state=q0\n(q0, 0)->q0", 3),
+    ("state={q0}\nstart_state=q0\nq0, 0)->{q0}", 3),
+    ("state={é}\nstart_state=é\n(é, ñ)->{é}\n(é, ñé)->{é}", 4),
+    ("state={q0}\nstart_state=q1", 2),
+    ("state={q0}\nstart_state=q0\nF={q0, q1}", 3),
+    ("alphabet={ab}\nstate={q0}\nstart_state=q0", 1),
+];
+
+#[test]
+fn try_from_string_reports_malformed_input() {
+    for (text, line) in MALFORMED {
+        let error = NFA::try_from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{}: {}", text, error);
+    }
+    // Sin `state=` los estados de las transiciones no existen
+    let error = NFA::try_from_string(include_str!("../src/ejemplo.txt")).err();
+    assert_eq!(error.map(|error| error.line), Some(1));
+}
+
+#[test]
+fn deeply_nested_expressions_are_rejected() {
+    let text = format!("{}a{}", "(".repeat(10_000), ")".repeat(10_000));
+    assert!(Regex::parse(&text).is_err());
+    let text = format!("{}a{}", "(".repeat(100), ")".repeat(100));
+    assert!(Regex::parse(&text).is_ok());
+}
+
+#[test]
+fn start_state_missing_from_states_is_added() {
+    let start: StateRef = Node::new("q0", true);
+    let nfa = NFA::from_parts(Vec::new(), BTreeSet::new(), start);
+    assert_eq!(nfa.states().len(), 1);
+    assert!(nfa.run("".chars()));
+    assert_eq!(nfa.determinize().states().len(), 1);
+    assert_eq!(nfa.minimize().states().len(), 1);
+    assert!(nfa.to_svg().contains("q0"));
+    assert!(nfa.to_tikz().contains("q0"));
+    assert!(nfa.pump(&[], &[]).is_err());
+    // Sin ciclos no hay palabras infinitas aceptadas
+    assert!(Buchi::from_nfa(nfa).is_empty());
+}
+
+#[test]
+fn long_chains_are_rejected_instead_of_overflowing() {
+    let word = "a".repeat(100_000);
+    assert!(Regex::parse(&word).is_err());
+    assert!(Regex::parse(&format!("a{}", "*".repeat(100_000))).is_err());
+    assert!(Regex::parse(&format!("{}a", "a|".repeat(50_000))).is_err());
+    assert!(Regex::parse(&format!("a{}", "?".repeat(100_000))).is_err());
+}
+
+#[test]
+fn deepest_accepted_expressions_can_be_used() {
+    for text in [
+        "a".repeat(MAX_DEPTH),
+        format!("a{}", "*".repeat(MAX_DEPTH - 1)),
+        format!(
+            "{}a{}",
+            "(".repeat(MAX_DEPTH / 2),
+            ")".repeat(MAX_DEPTH / 2)
+        ),
+    ] {
+        let regex = Regex::parse(&text).unwrap();
+        let nfa = regex.to_nfa();
+        for word in ["", "a"] {
+            assert_eq!(nfa.run(word.chars()), regex.matches(word));
+        }
+        assert!(Regex::parse(&regex.to_string()).is_ok());
+    }
+    assert!(Regex::parse(&"a".repeat(MAX_DEPTH + 1)).is_err());
+}
+
+#[test]
+fn display_of_accepted_expressions_is_accepted() {
+    // Cada `?*` agrega un nivel de paréntesis a la expresión escrita
+    for repetitions in [100, MAX_DEPTH / 2 - 1, MAX_DEPTH / 2, 300] {
+        let text = format!("a{}", "?*".repeat(repetitions));
+        if let Ok(regex) = Regex::parse(&text) {
+            let written = regex.to_string();
+            assert!(
+                Regex::parse(&written).is_ok(),
+                "{} repeticiones",
+                repetitions
+            );
+        }
+    }
+    assert!(Regex::parse(&format!("a{}", "?*".repeat(MAX_DEPTH / 2 - 1))).is_ok());
+}
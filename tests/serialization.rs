@@ -0,0 +1,130 @@
+#![cfg(feature = "serde")]
+
+use automata::nfa::NFA;
+use automata::serialization::{AutomatonDocument, SchemaError};
+
+// Documento con tres transiciones; `transition` reemplaza la tercera.
+fn document(transition: &str) -> String {
+    format!(
+        r#"{{
+  "states": ["q0", "q1"],
+  "alphabet": ["0", "1"],
+  "start": "q0",
+  "accepting": ["q1"],
+  "transitions": [
+    {{ "from": "q0", "symbol": "0", "to": ["q0"] }},
+    {{ "from": "q0", "symbol": "1", "to": ["q0", "q1"] }},
+    {transition}
+  ]
+}}"#
+    )
+}
+
+// Mismo documento en YAML.
+fn yaml_document(transition: &str) -> String {
+    format!(
+        "states: [q0, q1]\nalphabet: ['0', '1']\nstart: q0\naccepting: [q1]\ntransitions:\n\
+         - {{ from: q0, symbol: '0', to: [q0] }}\n\
+         - {{ from: q0, symbol: '1', to: [q0, q1] }}\n\
+         - {}\n",
+        transition
+    )
+}
+
+// Ruta del error al leer el documento como JSON y como YAML.
+fn error_paths(json: &str, yaml: &str) -> (SchemaError, SchemaError) {
+    (
+        NFA::from_json(json).err().unwrap(),
+        NFA::from_yaml(yaml).err().unwrap(),
+    )
+}
+
+#[test]
+fn documents_round_trip() {
+    let nfa = NFA::from_json(&document(r#"{ "from": "q1", "to": ["q0"] }"#)).unwrap();
+    for text in [nfa.to_json(), nfa.to_yaml()] {
+        let document = AutomatonDocument::from_json(&text)
+            .or_else(|_| AutomatonDocument::from_yaml(&text))
+            .unwrap();
+        assert_eq!(document, AutomatonDocument::from_nfa(&nfa));
+    }
+    assert!(nfa.run("01".chars()));
+    assert!(nfa.run("1".chars()));
+    assert!(!nfa.run("10".chars()));
+}
+
+#[test]
+fn wrong_types_report_the_field_path() {
+    let (json, yaml) = error_paths(
+        &document(r#"{ "from": "q1", "symbol": "0", "to": "q0" }"#),
+        &yaml_document("{ from: q1, symbol: '0', to: q0 }"),
+    );
+    assert_eq!(json.path, "transitions[2].to");
+    assert_eq!(yaml.path, "transitions[2].to");
+    assert!(
+        json.to_string().starts_with("transitions[2].to: "),
+        "{}",
+        json
+    );
+
+    let (json, yaml) = error_paths(
+        &document(r#"{ "from": "q1", "symbol": 0, "to": ["q0"] }"#),
+        &yaml_document("{ from: q1, symbol: [0], to: [q0] }"),
+    );
+    assert_eq!(json.path, "transitions[2].symbol");
+    assert_eq!(yaml.path, "transitions[2].symbol");
+}
+
+#[test]
+fn unknown_fields_are_rejected() {
+    let (json, yaml) = error_paths(
+        &document(r#"{ "from": "q1", "symbol": "0", "to": ["q0"], "weight": 2 }"#),
+        &yaml_document("{ from: q1, symbol: '0', to: [q0], weight: 2 }"),
+    );
+    for error in [&json, &yaml] {
+        assert_eq!(error.path, "transitions[2].weight", "{}", error);
+        assert!(
+            error.message.contains("unknown field `weight`"),
+            "{}",
+            error
+        );
+    }
+
+    let text =
+        document(r#"{ "from": "q1", "to": ["q0"] }"#).replacen('{', r#"{ "initial": "q0","#, 1);
+    let error = NFA::from_json(&text).err().unwrap();
+    assert_eq!(error.path, "initial");
+    assert!(
+        error.message.contains("unknown field `initial`"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn invalid_references_report_the_field_path() {
+    for (transition, path) in [
+        (
+            r#"{ "from": "q1", "symbol": "0", "to": ["q0", "q2"] }"#,
+            "transitions[2].to[1]",
+        ),
+        (r#"{ "from": "q2", "to": ["q0"] }"#, "transitions[2].from"),
+        (
+            r#"{ "from": "q1", "symbol": "2", "to": ["q0"] }"#,
+            "transitions[2].symbol",
+        ),
+        (
+            r#"{ "from": "q1", "symbol": "01", "to": ["q0"] }"#,
+            "transitions[2].symbol",
+        ),
+    ] {
+        let error = NFA::from_json(&document(transition)).err().unwrap();
+        assert_eq!(error.path, path, "{}", error);
+    }
+
+    let repeated = document(r#"{ "from": "q1", "to": ["q0"] }"#).replace(
+        r#""states": ["q0", "q1"]"#,
+        r#""states": ["q0", "q1", "q0"]"#,
+    );
+    assert_eq!(NFA::from_json(&repeated).err().unwrap().path, "states[2]");
+}
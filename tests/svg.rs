@@ -0,0 +1,149 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use automata::layout::Layout;
+use automata::nfa::{Node, NFA};
+use automata::svg::SvgOptions;
+use common::{random_nfa, CASES};
+
+// Bucle en `p` e ida y vuelta entre `p` y `q`.
+const LOOP_AND_BACK: &str = "
+    alphabet={a, b}
+    state={p, q}
+    start_state=p
+    F={q}
+    (p, a)->{p}
+    (p, b)->{q}
+    (q, a)->{p}
+";
+
+// Valor numérico de un atributo de un elemento escrito en una sola línea.
+fn attribute(element: &str, name: &str) -> f64 {
+    let start = element.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+    let end = start + element[start..].find('"').unwrap();
+    element[start..end].parse().unwrap()
+}
+
+// Líneas del documento que empiezan con `prefix`, sin la sangría.
+fn elements<'a>(svg: &'a str, prefix: &str) -> Vec<&'a str> {
+    svg.lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with(prefix))
+        .collect()
+}
+
+#[test]
+fn states_never_overlap() {
+    for seed in 0..CASES {
+        let nfa = random_nfa(seed, false);
+        for layout in [Layout::Layered, Layout::Circular] {
+            let svg = nfa.to_svg_with(&SvgOptions {
+                layout,
+                ..SvgOptions::default()
+            });
+            let header = elements(&svg, "<svg")[0];
+            let (width, height) = (attribute(header, "width"), attribute(header, "height"));
+
+            // Los círculos exteriores son los que tienen relleno blanco
+            let circles: Vec<(f64, f64, f64)> = elements(&svg, "<circle")
+                .into_iter()
+                .filter(|circle| circle.contains("fill=\"white\""))
+                .map(|c| (attribute(c, "cx"), attribute(c, "cy"), attribute(c, "r")))
+                .collect();
+            assert_eq!(circles.len(), nfa.states().len());
+
+            for (i, &(x, y, r)) in circles.iter().enumerate() {
+                assert!(x - r >= 0.0 && x + r <= width, "semilla {}", seed);
+                assert!(y - r >= 0.0 && y + r <= height, "semilla {}", seed);
+                for &(other_x, other_y, other_r) in &circles[i + 1..] {
+                    let distance = ((x - other_x).powi(2) + (y - other_y).powi(2)).sqrt();
+                    assert!(distance >= r + other_r, "semilla {} {:?}", seed, layout);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn loops_and_opposite_edges_are_drawn_apart() {
+    let svg = NFA::try_from_string(LOOP_AND_BACK).unwrap().to_svg();
+    let paths = elements(&svg, "<path d=\"M");
+
+    // El bucle es la única curva cúbica
+    let loops: Vec<&str> = paths
+        .iter()
+        .copied()
+        .filter(|p| p.contains(" C "))
+        .collect();
+    assert_eq!(loops.len(), 1, "{}", svg);
+
+    // Las aristas de ida y vuelta se curvan hacia lados opuestos
+    let control = |path: &str| {
+        let points: Vec<f64> = path
+            .split(['"', ' ', ','])
+            .filter_map(|token| token.parse().ok())
+            .collect();
+        (points[2], points[3])
+    };
+    let curves: Vec<(f64, f64)> = paths
+        .iter()
+        .filter(|p| p.contains(" Q "))
+        .map(|p| control(p))
+        .collect();
+    assert_eq!(curves.len(), 2, "{}", svg);
+    assert!(
+        (curves[0].0 - curves[1].0).abs() + (curves[0].1 - curves[1].1).abs() > 40.0,
+        "{}",
+        svg
+    );
+
+    // Cada arista tiene su propia etiqueta
+    let labels: BTreeSet<(String, String)> = elements(&svg, "<text")
+        .iter()
+        .map(|text| {
+            let x = text.split("x=\"").nth(1).unwrap();
+            let y = text.split("y=\"").nth(1).unwrap();
+            (
+                x[..x.find('"').unwrap()].to_string(),
+                y[..y.find('"').unwrap()].to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(labels.len(), 5, "{}", svg);
+}
+
+#[test]
+fn names_and_labels_are_escaped() {
+    let first = Node::new("a<b & \"c\"", false);
+    let second = Node::new("d'e>", true);
+    Node::add_transition(&first, '<', second.clone());
+    Node::add_transition(&first, '&', second.clone());
+    let nfa = NFA::from_parts(
+        vec![first.clone(), second],
+        BTreeSet::from(['<', '&']),
+        first,
+    );
+
+    let svg = nfa.to_svg();
+    assert!(
+        svg.contains(">a&lt;b &amp; &quot;c&quot;</text>"),
+        "{}",
+        svg
+    );
+    assert!(svg.contains(">d&apos;e&gt;</text>"), "{}", svg);
+    assert!(svg.contains(">&amp;, &lt;</text>"), "{}", svg);
+
+    // Fuera de las etiquetas no queda ningún `<` ni `&` sin escapar
+    for text in elements(&svg, "<text") {
+        let content = &text[text.find('>').unwrap() + 1..text.rfind("</text>").unwrap()];
+        assert!(!content.contains('<'), "{}", text);
+        let unescaped = content
+            .replace("&lt;", "")
+            .replace("&gt;", "")
+            .replace("&amp;", "")
+            .replace("&quot;", "")
+            .replace("&apos;", "");
+        assert!(!unescaped.contains('&'), "{}", text);
+    }
+}
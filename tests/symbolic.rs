@@ -0,0 +1,129 @@
+use automata::symbolic::{minterms, CharClass, SymbolicNFA};
+
+// Palabras que empiezan con una letra entre `a` y `m`.
+const STARTS_LOW: &str = "
+    state={p, q}
+    start_state=p
+    F={q}
+    (p, [a-m])->{q}
+    (q, .)->{q}
+";
+
+// Palabras que terminan con una letra entre `h` y `z`.
+const ENDS_HIGH: &str = "
+    state={p, q}
+    start_state=p
+    F={q}
+    (p, .)->{p}
+    (p, [h-z])->{q}
+";
+
+// Palabras de largo a lo más `max_length` sobre caracteres en los bordes de las clases.
+fn sample_words(max_length: usize) -> Vec<String> {
+    let symbols = ['a', 'g', 'h', 'k', 'm', 'n', 'z', '0', '\u{10FFFF}'];
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word| symbols.iter().map(move |c| format!("{}{}", word, c)))
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+#[test]
+fn parse_errors_report_their_line() {
+    for (text, line) in [
+        ("state={p}\nstart_state=q\n", 2),
+        ("state={p}\nstart_state=p\nF={p, q}\n", 3),
+        ("state={p}\nstart_state=p\n(p, [a-z])->{q}\n", 3),
+        ("state={p}\nF={p}\n", 0),
+        ("start_state=p\n", 1),
+    ] {
+        let error = SymbolicNFA::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+    }
+
+    let error = SymbolicNFA::from_string("state={p, q}\n(p, a)->{q}\n")
+        .err()
+        .unwrap();
+    assert!(error.message.contains("start_state="), "{}", error);
+}
+
+#[test]
+fn minterms_split_overlapping_ranges() {
+    let classes = [
+        CharClass::range('a', 'm'),
+        CharClass::range('h', 'z'),
+        CharClass::single('k'),
+    ];
+    let parts = minterms(&classes);
+
+    // [h-j] y [l-m] pertenecen a las mismas clases, así que forman un solo mintérmino
+    assert_eq!(parts.len(), 5);
+    for expected in [
+        CharClass::range('a', 'g'),
+        CharClass::range('h', 'j').union(&CharClass::range('l', 'm')),
+        CharClass::single('k'),
+        CharClass::range('n', 'z'),
+        CharClass::range('a', 'z').complement(),
+    ] {
+        assert!(parts.contains(&expected), "falta {}", expected);
+    }
+
+    let mut union = CharClass::empty();
+    for (i, part) in parts.iter().enumerate() {
+        for other in &parts[i + 1..] {
+            assert!(!part.intersects(other), "{} y {}", part, other);
+        }
+        for class in &classes {
+            let common = part.intersection(class);
+            assert!(common.is_empty() || common == *part, "{} y {}", part, class);
+        }
+        union = union.union(part);
+    }
+    assert_eq!(union, CharClass::any());
+}
+
+#[test]
+fn complement_covers_the_whole_char_range() {
+    assert_eq!(CharClass::empty().complement(), CharClass::any());
+    assert!(CharClass::any().complement().is_empty());
+
+    let class = CharClass::range('b', 'y');
+    let complement = class.complement();
+    for c in ['\0', 'a', 'z', '\u{D7FF}', '\u{E000}', char::MAX] {
+        assert!(complement.contains(c), "{:?}", c);
+    }
+    assert!(!complement.contains('b') && !complement.contains('y'));
+    assert_eq!(complement.complement(), class);
+    assert_eq!(class.union(&complement), CharClass::any());
+
+    let lower = SymbolicNFA::from_string("state={p}\nstart_state=p\nF={p}\n(p, [a-z])->{p}\n")
+        .unwrap()
+        .complement();
+    assert!(!lower.run("".chars()));
+    assert!(!lower.run("abc".chars()));
+    assert!(lower.run("\u{10FFFF}".chars()));
+    assert!(lower.run("ab\0".chars()));
+}
+
+#[test]
+fn intersection_accepts_the_words_of_both() {
+    let starts = SymbolicNFA::from_string(STARTS_LOW).unwrap();
+    let ends = SymbolicNFA::from_string(ENDS_HIGH).unwrap();
+    let both = starts.intersection(&ends);
+
+    for word in sample_words(3) {
+        assert_eq!(
+            both.run(word.chars()),
+            starts.run(word.chars()) && ends.run(word.chars()),
+            "\"{}\"",
+            word
+        );
+    }
+    assert!(both.run("h".chars()));
+    assert!(!both.run("g".chars()));
+}
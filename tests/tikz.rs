@@ -0,0 +1,106 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use automata::layout::Layout;
+use automata::nfa::{Node, NFA};
+use automata::tikz::TikzOptions;
+
+// Bucle en `q0`, ida y vuelta entre `q0` y `q1`, y dos transiciones de `q1` a `q2`.
+const SHAPES: &str = "
+    alphabet={a, b}
+    state={q0, q1, q2}
+    start_state=q0
+    F={q2}
+    (q0, a)->{q0}
+    (q0, b)->{q1}
+    (q1, a)->{q0, q2}
+    (q1, b)->{q2}
+    (q2, ε)->{q1}
+";
+
+#[test]
+fn latex_table_lists_every_transition() {
+    let nfa = NFA::try_from_string(
+        "alphabet={0, 1}\nstate={q_0, q1}\nstart_state=q_0\nF={q1}\n\
+         (q_0, 0)->{q_0, q1}\n(q1, ε)->{q_0}\n",
+    )
+    .unwrap();
+    assert_eq!(
+        nfa.to_latex_table(),
+        "\\begin{tabular}{r|ccc}\n\
+         \x20 $\\delta$ & 0 & 1 & $\\varepsilon$ \\\\\n\
+         \x20 \\hline\n\
+         \x20 $\\rightarrow$ q\\_0 & \\{q\\_0, q1\\} & $\\emptyset$ & $\\emptyset$ \\\\\n\
+         \x20 $*$ q1 & $\\emptyset$ & $\\emptyset$ & \\{q\\_0\\} \\\\\n\
+         \\end{tabular}\n"
+    );
+}
+
+#[test]
+fn state_names_are_escaped() {
+    let special = Node::new("a{b}_#$%&~^\\", true);
+    let greek = Node::new("ε∅", false);
+    Node::add_transition(&special, 'x', greek.clone());
+    let nfa = NFA::from_parts(vec![special.clone(), greek], BTreeSet::from(['x']), special);
+
+    let tikz = nfa.to_tikz();
+    assert!(
+        tikz.contains(
+            "{a\\{b\\}\\_\\#\\$\\%\\&\\textasciitilde{}\\textasciicircum{}\\textbackslash{}};"
+        ),
+        "{}",
+        tikz
+    );
+    assert!(tikz.contains("{$\\varepsilon$$\\emptyset$};"), "{}", tikz);
+    assert!(nfa
+        .to_latex_table()
+        .contains("$\\rightarrow$$*$ a\\{b\\}\\_\\#"));
+}
+
+#[test]
+fn user_positions_override_the_layout() {
+    let nfa = NFA::try_from_string(SHAPES).unwrap();
+    let options = TikzOptions {
+        layout: Layout::Circular,
+        spacing: 2.0,
+        positions: BTreeMap::from([
+            ("q1".to_string(), (1.0, -2.5)),
+            ("missing".to_string(), (9.0, 9.0)),
+        ]),
+    };
+    let tikz = nfa.to_tikz_with(&options);
+
+    assert!(
+        tikz.contains("\\node[state] (s1) at (1.00, -2.50) {q1};"),
+        "{}",
+        tikz
+    );
+    assert!(!tikz.contains("9.00"), "{}", tikz);
+    assert!(
+        tikz.contains("\\node[state, initial] (s0) at ("),
+        "{}",
+        tikz
+    );
+    assert!(
+        tikz.contains("\\node[state, accepting] (s2) at ("),
+        "{}",
+        tikz
+    );
+}
+
+#[test]
+fn loops_and_opposite_edges_get_their_own_shape() {
+    let tikz = NFA::try_from_string(SHAPES).unwrap().to_tikz();
+    for edge in [
+        "(s0) edge [loop above] node {a} (s0)",
+        "(s0) edge [bend left] node {b} (s1)",
+        "(s1) edge [bend left] node {a} (s0)",
+        "(s1) edge [bend left] node {a, b} (s2)",
+        "(s2) edge [bend left] node {$\\varepsilon$} (s1)",
+    ] {
+        assert!(tikz.contains(edge), "falta {}:\n{}", edge, tikz);
+    }
+
+    // Sin arista de vuelta la transición es recta
+    let one_way = NFA::try_from_string("state={p, q}\nstart_state=p\n(p, a)->{q}\n").unwrap();
+    assert!(one_way.to_tikz().contains("(s0) edge node {a} (s1);"));
+}
@@ -0,0 +1,102 @@
+use automata::turing::{Outcome, TuringMachine};
+
+// Acepta las palabras formadas por una cantidad par de `a`.
+const EVEN: &str = "
+    alphabet={a, b}
+    state={e, o, acc}
+    start_state=e
+    F={acc}
+    (e, a)->(o, a, R)
+    (o, a)->(e, a, R)
+    (e, _)->(acc, _, S)
+";
+
+// Todas las palabras sobre {a, b} de largo a lo más `max_length`.
+fn all_words(max_length: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word| ["a", "b"].map(|c| format!("{}{}", word, c)))
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+#[test]
+fn tape_count_after_transitions_is_rejected() {
+    let error = TuringMachine::from_string(
+        "state={q0, q1}\nstart_state=q0\nF={q1}\n(q0, a)->(q1, a, R)\ntapes=2\n",
+    )
+    .err()
+    .unwrap();
+    assert_eq!(error.line, 5);
+
+    let machine = TuringMachine::from_string(
+        "tapes=2\nstate={q0, q1}\nstart_state=q0\nF={q1}\n(q0, a, _)->(q1, a, a, R, R)\n",
+    )
+    .unwrap();
+    assert_eq!(machine.tapes(), 2);
+}
+
+#[test]
+fn missing_transition_rejects() {
+    let machine = TuringMachine::from_string(EVEN).unwrap();
+    for word in all_words(6) {
+        let expected = if !word.contains('b') && word.len() % 2 == 0 {
+            Outcome::Accept
+        } else {
+            Outcome::Reject
+        };
+        assert_eq!(machine.run(&word, 100), expected, "\"{}\"", word);
+    }
+}
+
+#[test]
+fn step_limit_is_a_timeout_not_a_rejection() {
+    let machine = TuringMachine::from_string(EVEN).unwrap();
+    assert_eq!(machine.run("aa", 3), Outcome::Accept);
+    assert_eq!(machine.run("aa", 2), Outcome::Timeout);
+    assert_eq!(machine.run("aaa", 2), Outcome::Timeout);
+    assert_eq!(machine.run("aaa", 3), Outcome::Reject);
+
+    // Recorre la cinta hacia la derecha sin detenerse
+    let endless =
+        TuringMachine::from_string("state={q0}\nstart_state=q0\n(q0, _)->(q0, _, R)\n").unwrap();
+    assert_eq!(endless.run("", 1_000), Outcome::Timeout);
+}
+
+#[test]
+fn reject_states_stop_the_machine() {
+    let machine = TuringMachine::from_string(
+        "state={q0, r}\nstart_state=q0\nreject={r}\n(q0, a)->(r, a, R)\n(r, _)->(r, _, R)\n",
+    )
+    .unwrap();
+    assert_eq!(machine.run("a", 1_000), Outcome::Reject);
+    assert_eq!(machine.trace("a", 1_000).1.len(), 2);
+}
+
+#[test]
+fn parse_errors_report_their_line() {
+    for (text, line) in [
+        ("state={q0}\nstart_state=q1\n", 2),
+        ("state={q0}\nstart_state=q0\nF={q0, q1}\n", 3),
+        ("state={q0}\nstart_state=q0\nreject={q1}\n", 3),
+        ("state={q0}\nstart_state=q0\n(q1, a)->(q0, a, R)\n", 3),
+        ("state={q0}\nstart_state=q0\n(q0, a)->(q1, a, R)\n", 3),
+        ("state={q0}\nF={q0}\n", 0),
+        ("start_state=q0\n", 1),
+    ] {
+        let error = TuringMachine::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+    }
+}
+
+#[test]
+fn repeated_declarations_keep_a_single_state() {
+    let machine =
+        TuringMachine::from_string("state={q0, q1}\nstate={q1, q0, q2}\nstart_state=q1\n").unwrap();
+    assert_eq!(machine.states(), ["q0", "q1", "q2"]);
+}
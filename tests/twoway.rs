@@ -0,0 +1,142 @@
+use automata::random::Rng;
+use automata::twoway::{Outcome, TwoWayNFA, LEFT_MARKER, RIGHT_MARKER};
+
+const ALPHABET: [char; 2] = ['a', 'b'];
+
+// Cantidad de semillas que recorre cada propiedad.
+const CASES: u64 = 150;
+
+// Todas las palabras sobre `ALPHABET` de largo a lo más `max_length`.
+fn all_words(max_length: usize) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut last = vec![String::new()];
+    for _ in 0..max_length {
+        last = last
+            .iter()
+            .flat_map(|word| ALPHABET.iter().map(move |c| format!("{}{}", word, c)))
+            .collect();
+        words.extend(last.iter().cloned());
+    }
+    words
+}
+
+// Autómata de dos sentidos aleatorio que nunca sale de la cinta.
+fn random_two_way(seed: u64) -> TwoWayNFA {
+    let mut rng = Rng::new(seed);
+    let states = 1 + rng.below(3);
+    let names: Vec<String> = (0..states).map(|i| format!("q{}", i)).collect();
+    let finals: Vec<&str> = names
+        .iter()
+        .filter(|_| rng.chance(0.4))
+        .map(String::as_str)
+        .collect();
+    let mut description = format!(
+        "alphabet={{a, b}}\nstate={{{}}}\nstart_state=q0\nF={{{}}}\n",
+        names.join(", "),
+        finals.join(", ")
+    );
+
+    let symbols = ALPHABET.iter().chain(&[LEFT_MARKER, RIGHT_MARKER]);
+    for name in &names {
+        for &symbol in symbols.clone() {
+            let mut moves = Vec::new();
+            for target in &names {
+                if !rng.chance(0.3) {
+                    continue;
+                }
+                let direction = match symbol {
+                    LEFT_MARKER => 'R',
+                    RIGHT_MARKER => 'L',
+                    _ if rng.chance(0.5) => 'L',
+                    _ => 'R',
+                };
+                moves.push(format!("({}, {})", target, direction));
+            }
+            if !moves.is_empty() {
+                description.push_str(&format!(
+                    "({}, {})->{{{}}}\n",
+                    name,
+                    symbol,
+                    moves.join(", ")
+                ));
+            }
+        }
+    }
+    TwoWayNFA::from_string(&description).unwrap()
+}
+
+#[test]
+fn conversion_to_nfa_preserves_the_language() {
+    for seed in 0..CASES {
+        let automaton = random_two_way(seed);
+        let nfa = automaton.to_nfa();
+        for word in all_words(6) {
+            assert_eq!(
+                automaton.run(word.chars()) == Outcome::Accept,
+                nfa.run(word.chars()),
+                "semilla {}: \"{}\"",
+                seed,
+                word
+            );
+        }
+    }
+}
+
+#[test]
+fn two_way_dfa_checks_first_and_last_symbol() {
+    // Recorre hasta `⊣`, vuelve un paso y compara el último símbolo con el primero
+    let automaton = TwoWayNFA::from_string(
+        "alphabet={a, b}\nstate={s, ra, rb, ca, cb, f}\nstart_state=s\nF={f}\n\
+         (s, ⊢)->{(s, R)}\n(s, a)->{(ra, R)}\n(s, b)->{(rb, R)}\n\
+         (ra, a)->{(ra, R)}\n(ra, b)->{(ra, R)}\n(ra, ⊣)->{(ca, L)}\n\
+         (rb, a)->{(rb, R)}\n(rb, b)->{(rb, R)}\n(rb, ⊣)->{(cb, L)}\n\
+         (ca, a)->{(f, R)}\n(cb, b)->{(f, R)}\n",
+    )
+    .unwrap();
+    assert!(automaton.is_deterministic());
+    let nfa = automaton.to_nfa();
+    for word in all_words(6) {
+        let expected = !word.is_empty() && word.chars().next() == word.chars().last();
+        assert_eq!(
+            automaton.run(word.chars()) == Outcome::Accept,
+            expected,
+            "\"{}\"",
+            word
+        );
+        assert_eq!(nfa.run(word.chars()), expected, "\"{}\"", word);
+    }
+}
+
+#[test]
+fn repeated_configuration_is_reported_as_a_loop() {
+    let automaton = TwoWayNFA::from_string(
+        "alphabet={a}\nstate={p, q}\nstart_state=p\n\
+         (p, ⊢)->{(p, R)}\n(p, a)->{(q, L)}\n(q, ⊢)->{(p, R)}\n",
+    )
+    .unwrap();
+    assert_eq!(automaton.run("a".chars()), Outcome::Loop);
+    assert_eq!(automaton.run("".chars()), Outcome::Reject);
+}
+
+#[test]
+fn parse_errors_report_their_line() {
+    for (text, line) in [
+        ("state={p}\nstart_state=q\n", 2),
+        ("state={p}\nstart_state=p\nF={p, q}\n", 3),
+        ("state={p}\nstart_state=p\n(q, a)->{(p, R)}\n", 3),
+        ("state={p}\nstart_state=p\n(p, a)->{(p, R), (q, L)}\n", 3),
+        ("state={p}\nF={p}\n", 0),
+        ("start_state=p\n", 1),
+    ] {
+        let error = TwoWayNFA::from_string(text).err().unwrap();
+        assert_eq!(error.line, line, "{:?}: {}", text, error);
+    }
+}
+
+#[test]
+fn repeated_declarations_keep_a_single_state() {
+    let automaton =
+        TwoWayNFA::from_string("state={p, q}\nstate={q, p, r}\nstart_state=q\n").unwrap();
+    assert_eq!(automaton.states(), ["p", "q", "r"]);
+    assert_eq!(automaton.start_state(), "q");
+}
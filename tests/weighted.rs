@@ -0,0 +1,90 @@
+mod common;
+
+use automata::random::Rng;
+use automata::weighted::{Boolean, Log, Probability, Semiring, Tropical, WeightedNFA};
+use common::{all_words, random_nfa, random_regex, CASES};
+
+// Dos caminos para `aa` con distinto costo y un ciclo en el estado final.
+const ROUTES: &str = "
+    alphabet={a, b}
+    state={s, m, n, t}
+    start_state=s
+    F={t:0.5}
+    (s, a)->{m:1, n:3}
+    (m, a)->{t:4}
+    (n, a)->{t:1}
+    (t, a)->{t:2}
+    (s, b)->{t:inf}
+";
+
+#[test]
+fn weights_must_be_finite_unless_written_as_infinity() {
+    for text in ["NaN", "nan", "infinity", "-inf", "+inf", "1e400", "-1e400"] {
+        assert!(Tropical::parse(text).is_err(), "{}", text);
+        assert!(Log::parse(text).is_err(), "{}", text);
+        assert!(Probability::parse(text).is_err(), "{}", text);
+    }
+    assert_eq!(Tropical::parse("∞"), Ok(Tropical::zero()));
+    assert_eq!(Tropical::parse(" inf "), Ok(Tropical::zero()));
+    assert_eq!(Tropical::parse("2.5"), Ok(Tropical(2.5)));
+    assert_eq!(Log::parse("∞"), Ok(Log::zero()));
+
+    // El infinito solo es el cero de los semianillos tropical y logarítmico
+    for text in ["∞", "inf"] {
+        assert!(Probability::parse(text).is_err(), "{}", text);
+    }
+}
+
+#[test]
+fn epsilon_transitions_are_rejected() {
+    let error =
+        WeightedNFA::<Tropical>::from_string("state={p, q}\nstart_state=p\n(p, ε)->{q:1}\n")
+            .err()
+            .unwrap();
+    assert_eq!(error.line, 3);
+}
+
+#[test]
+fn boolean_weight_agrees_with_run() {
+    let mut rng = Rng::new(30);
+    for seed in 0..CASES {
+        // Los autómatas de expresiones regulares tienen transiciones ε
+        for nfa in [random_nfa(seed, false), random_regex(&mut rng, 4).to_nfa()] {
+            let weighted = WeightedNFA::<Boolean>::from_nfa(&nfa);
+            for word in all_words(5) {
+                assert_eq!(
+                    weighted.weight(word.chars()),
+                    Boolean(nfa.run(word.chars())),
+                    "semilla {}: \"{}\"",
+                    seed,
+                    word
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn tropical_weight_is_the_shortest_path() {
+    let automaton = WeightedNFA::<Tropical>::from_string(ROUTES).unwrap();
+    assert_eq!(automaton.weight("aa".chars()), Tropical(4.5));
+    assert_eq!(automaton.weight("aaa".chars()), Tropical(6.5));
+    assert_eq!(automaton.weight("a".chars()), Tropical::zero());
+    assert_eq!(automaton.weight("b".chars()), Tropical::zero());
+}
+
+#[test]
+fn n_best_returns_paths_from_best_to_worst() {
+    let automaton = WeightedNFA::<Tropical>::from_string(ROUTES).unwrap();
+    let paths = automaton.n_best(4);
+
+    let weights: Vec<f64> = paths.iter().map(|path| path.weight.0).collect();
+    assert_eq!(weights, [4.5, 5.5, 6.5, 7.5]);
+    assert_eq!(paths[0].states, ["s", "n", "t"]);
+    assert_eq!(paths[1].states, ["s", "m", "t"]);
+    for path in &paths {
+        // El peso de la palabra es el mínimo entre todos sus caminos
+        assert!(automaton.weight(path.word.iter().copied()).0 <= path.weight.0);
+        assert_eq!(path.states.len(), path.word.len() + 1);
+    }
+}